@@ -0,0 +1,181 @@
+// aura_os/aura_cli/src/main.rs
+
+//! The `aura` command-line tool.
+//!
+//! Until AuraOS has a service or network layer for reaching running nodes, the
+//! `param` commands operate on a `ParameterManager` owned by this process. Pass
+//! `--journal <FILE>` to persist it between invocations: the journal is replayed at
+//! startup and every change is appended to it.
+//!
+//! ```text
+//! aura param --journal robot.params set camera.fps 30
+//! aura param --journal robot.params load defaults.json
+//! aura param --journal robot.params list camera.
+//! aura param --journal robot.params get camera.fps
+//! ```
+
+use aura_core::param::{ParamSource, ParamValue, ParameterManager};
+use aura_core::Result as AuraResult;
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Parameter scope used by the CLI's own `ParameterManager`.
+const CLI_SCOPE: &str = "/aura_cli";
+
+#[derive(Debug, Parser)]
+#[command(name = "aura", version, about = "Command-line tools for AuraOS")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Inspect and change parameters.
+    Param {
+        /// Journal file to restore parameters from and record changes to.
+        #[arg(long, global = true)]
+        journal: Option<PathBuf>,
+        #[command(subcommand)]
+        command: ParamCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ParamCommand {
+    /// List parameters, optionally only those whose names start with PREFIX.
+    List { prefix: Option<String> },
+    /// Print the value of a parameter.
+    Get { name: String },
+    /// Set a parameter. The type is inferred from VALUE (int, float, bool, otherwise string).
+    Set { name: String, value: String },
+    /// Set every parameter in a JSON, YAML or TOML file. Nested tables become dotted names.
+    Load { file: PathBuf },
+}
+
+fn main() -> ExitCode {
+    report(run(Cli::parse()))
+}
+
+/// Runs a parsed command line and returns what to print.
+fn run(cli: Cli) -> AuraResult<String> {
+    match cli.command {
+        Command::Param { journal, command } => {
+            open_parameters(journal.as_deref()).and_then(|params| run_param(command, &params))
+        }
+    }
+}
+
+/// Prints the outcome of [`run`] and turns it into the process exit code.
+fn report(result: AuraResult<String>) -> ExitCode {
+    match result {
+        Ok(output) => {
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("aura: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Creates the parameter manager the commands act on, restoring it from `journal` if given.
+fn open_parameters(journal: Option<&Path>) -> AuraResult<ParameterManager> {
+    let params = ParameterManager::new(CLI_SCOPE);
+    if let Some(path) = journal {
+        if path.exists() {
+            params.recover_from_journal(path)?;
+        }
+        params.enable_journal(path)?;
+    }
+    Ok(params)
+}
+
+/// Runs one `param` subcommand against `params` and returns what to print.
+fn run_param(command: ParamCommand, params: &ParameterManager) -> AuraResult<String> {
+    match command {
+        ParamCommand::List { prefix } => {
+            let listed = params.list_parameters(prefix.as_deref().unwrap_or(""))?;
+            Ok(listed
+                .iter()
+                .map(|(name, value)| format!("{} = {}", name, format_value(value)))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        ParamCommand::Get { name } => Ok(format_value(&params.get_parameter(&name)?)),
+        ParamCommand::Set { name, value } => {
+            let value = ParamValue::parse_inferred(&value);
+            params.set_parameter(&name, value.clone())?;
+            Ok(format!("{} = {}", name, format_value(&value)))
+        }
+        ParamCommand::Load { file } => {
+            // An explicit `load` is a runtime change like `set`, so it replaces journaled values.
+            let count = params.load_from_file_as(&file, ParamSource::Runtime)?;
+            Ok(format!("Loaded {} parameter(s) from '{}'", count, file.display()))
+        }
+    }
+}
+
+/// Formats a value for display. Apart from `Null`, `set` parses the output back to the same value.
+fn format_value(value: &ParamValue) -> String {
+    match value {
+        ParamValue::String(s) => s.clone(),
+        ParamValue::Int(i) => i.to_string(),
+        // `{:?}` keeps the decimal point on whole numbers, so the value reads back as a float.
+        ParamValue::Float(f) => format!("{:?}", f),
+        ParamValue::Bool(b) => b.to_string(),
+        ParamValue::Null => "null".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aura_core::AuraError;
+
+    /// Parses and runs `aura param --journal <journal> <args>`.
+    fn param(journal: &Path, args: &[&str]) -> AuraResult<String> {
+        let mut argv = vec!["aura", "param", "--journal", journal.to_str().unwrap()];
+        argv.extend_from_slice(args);
+        run(Cli::try_parse_from(argv).unwrap())
+    }
+
+    #[test]
+    fn param_commands_persist_through_the_journal() {
+        let dir = std::env::temp_dir();
+        let journal = dir.join(format!("aura_cli_params_{}.jsonl", std::process::id()));
+        let file = dir.join(format!("aura_cli_load_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&journal);
+        std::fs::write(&file, r#"{"camera": {"name": "front", "exposure": 0.5}}"#).unwrap();
+
+        assert_eq!(param(&journal, &["set", "camera.fps", "30"]).unwrap(), "camera.fps = 30");
+        assert_eq!(param(&journal, &["get", "camera.fps"]).unwrap(), "30");
+        let loaded = param(&journal, &["load", file.to_str().unwrap()]).unwrap();
+        assert!(loaded.starts_with("Loaded 2 parameter(s)"), "{}", loaded);
+        assert_eq!(
+            param(&journal, &["list", "camera."]).unwrap(),
+            "camera.exposure = 0.5\ncamera.fps = 30\ncamera.name = front"
+        );
+        assert_eq!(param(&journal, &["list", "gripper."]).unwrap(), "");
+
+        let _ = std::fs::remove_file(&journal);
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[test]
+    fn unknown_parameter_exits_with_failure() {
+        let journal = std::env::temp_dir().join(format!("aura_cli_unknown_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&journal);
+
+        let result = param(&journal, &["get", "missing"]);
+        assert!(matches!(result, Err(AuraError::ParameterNotFound(_))), "{:?}", result);
+        assert_eq!(report(result), ExitCode::FAILURE);
+        assert_eq!(report(Ok(String::new())), ExitCode::SUCCESS);
+
+        let _ = std::fs::remove_file(&journal);
+    }
+}
@@ -0,0 +1,201 @@
+// aura_os/aura_core/src/comm/callback.rs
+
+//! Subscriptions that hand each message, converted to a type, to a callback.
+//!
+//! [`Node::create_subscriber_typed`](crate::node::Node::create_subscriber_typed) starts a
+//! [`TypedSubscription`]: a background thread that receives from a subscriber, converts
+//! each message with [`FromAuraMessage`] and calls the callback with the value and its
+//! [`MessageInfo`]. A message that does not convert is passed to the error callback set
+//! with [`TypedSubscription::on_error`] (or logged) and the thread carries on.
+//!
+//! Example:
+//! ```ignore
+//! #[derive(Clone, Deserialize)]
+//! struct Pose { x: f64, y: f64 }
+//! impl AuraMessageTrait for Pose {}
+//!
+//! let _poses = node.create_subscriber_typed::<Pose, _>("pose", |pose, info| {
+//!     println!("#{} from {:?}: ({}, {})", info.seq, info.sender_node, pose.x, pose.y);
+//! })?;
+//! ```
+
+use super::{compression, FromAuraMessage, Subscriber};
+use crate::error::{AuraError, Result};
+use crate::{aura_log, AuraMessage}; // Import from lib.rs
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// How long the receiving thread blocks before re-checking whether it should stop.
+const RECV_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+type ErrorCallback = Box<dyn Fn(AuraError, MessageInfo) + Send>;
+
+/// Metadata of a received message, taken from its header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageInfo {
+    /// The topic the message was published on.
+    pub topic: String,
+    /// Position of the message in its publisher's output; see `AuraMessage::seq`.
+    pub seq: u64,
+    /// When the message was published (wall clock).
+    pub stamp: SystemTime,
+    /// Fully qualified name of the node whose publisher sent it, if any.
+    pub sender_node: Option<String>,
+}
+
+impl From<&AuraMessage> for MessageInfo {
+    fn from(message: &AuraMessage) -> Self {
+        Self {
+            topic: message.topic.clone(),
+            seq: message.seq,
+            stamp: SystemTime::UNIX_EPOCH + Duration::from_nanos(message.stamp_ns),
+            sender_node: message.sender_node.clone(),
+        }
+    }
+}
+
+/// A running callback subscription, created with `Node::create_subscriber_typed`.
+///
+/// Callbacks run on the subscription's own thread, one message at a time. Dropping the
+/// handle (or stopping the owning node) stops the thread; a drop waits for a running
+/// callback to return.
+#[must_use = "the subscription stops as soon as the handle is dropped"]
+pub struct TypedSubscription {
+    topic_name: String,
+    on_error: Arc<Mutex<Option<ErrorCallback>>>,
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TypedSubscription {
+    /// Starts receiving from `subscriber` on a new thread, calling `callback` for each
+    /// message that converts to `M`.
+    pub(crate) fn start<M, F>(subscriber: Subscriber, mut callback: F) -> Result<Self>
+    where
+        M: FromAuraMessage,
+        F: FnMut(M, MessageInfo) + Send + 'static,
+    {
+        let topic_name = subscriber.topic_name().to_string();
+        let on_error: Arc<Mutex<Option<ErrorCallback>>> = Arc::default();
+        let (stop, stop_rx) = mpsc::channel::<()>();
+
+        let errors = Arc::clone(&on_error);
+        let thread = thread::Builder::new()
+            .name(format!("aura-callback{}", topic_name))
+            .spawn(move || {
+                while let Err(mpsc::TryRecvError::Empty) = stop_rx.try_recv() {
+                    let message = match subscriber.recv_timeout_raw(RECV_POLL_INTERVAL) {
+                        Ok(message) => message,
+                        Err(AuraError::TimeoutError(_)) => continue,
+                        Err(_) => break, // Disconnected or stopped.
+                    };
+                    let info = MessageInfo::from(&*message);
+                    let value = compression::decompress_message(message).and_then(|message| M::from_aura_message(&message));
+                    match value {
+                        Ok(value) => callback(value, info),
+                        Err(e) => match errors.lock().unwrap_or_else(PoisonError::into_inner).as_ref() {
+                            Some(on_error) => on_error(e, info),
+                            None => {
+                                aura_log!(warn, "Skipping message {} on '{}' that does not convert: {}", info.seq, info.topic, e);
+                            }
+                        },
+                    }
+                }
+                aura_log!(debug, "Callback subscription on '{}' exiting.", subscriber.topic_name());
+            })?;
+
+        Ok(Self {
+            topic_name,
+            on_error,
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
+    /// Calls `on_error` with the error and the message's metadata whenever a message
+    /// does not convert (or decompress), replacing any previous error callback. Without
+    /// one, such messages are logged. Either way the subscription carries on.
+    pub fn on_error<F>(&self, on_error: F)
+    where
+        F: Fn(AuraError, MessageInfo) + Send + 'static,
+    {
+        *self.on_error.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(on_error));
+    }
+
+    /// Returns the topic this subscription receives from.
+    pub fn topic_name(&self) -> &str {
+        &self.topic_name
+    }
+}
+
+impl std::fmt::Debug for TypedSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedSubscription")
+            .field("topic_name", &self.topic_name)
+            .finish()
+    }
+}
+
+impl Drop for TypedSubscription {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            // A callback dropping its own subscription must not wait for itself.
+            if thread.thread().id() != thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::AuraMessageTrait;
+    use crate::node::NodeBuilder;
+    use crate::Context;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Reading(i64);
+
+    impl AuraMessageTrait for Reading {}
+
+    impl FromAuraMessage for Reading {
+        fn from_aura_message(message: &AuraMessage) -> Result<Self> {
+            message
+                .data
+                .parse()
+                .map(Reading)
+                .map_err(|_| AuraError::SerializationError(format!("'{}' is not a reading", message.data)))
+        }
+    }
+
+    #[test]
+    fn callback_gets_decoded_values_and_errors_go_to_on_error() {
+        let node = NodeBuilder::new("sensor").context(Context::new()).build().unwrap();
+        let (values_tx, values) = mpsc::channel();
+        let subscription = node
+            .create_subscriber_typed("readings", move |reading: Reading, info| {
+                values_tx.send((reading, info.seq, info.sender_node)).unwrap();
+            })
+            .unwrap();
+        let (errors_tx, errors) = mpsc::channel();
+        subscription.on_error(move |error, info| {
+            errors_tx.send((error, info.seq)).unwrap();
+        });
+
+        let publisher = node.create_publisher("readings").unwrap();
+        for data in ["7", "seven", "9"] {
+            publisher.publish(data.to_string()).unwrap();
+        }
+
+        let timeout = Duration::from_secs(2);
+        let sender = Some("/sensor".to_string());
+        assert_eq!(values.recv_timeout(timeout).unwrap(), (Reading(7), 0, sender.clone()));
+        assert_eq!(values.recv_timeout(timeout).unwrap(), (Reading(9), 2, sender));
+        let (error, seq) = errors.recv_timeout(timeout).unwrap();
+        assert!(matches!(error, AuraError::SerializationError(_)));
+        assert_eq!(seq, 1);
+    }
+}
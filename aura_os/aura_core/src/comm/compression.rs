@@ -0,0 +1,208 @@
+// aura_os/aura_core/src/comm/compression.rs
+
+//! Optional compression of message payloads.
+//!
+//! A publisher whose QoS sets [`Compression::Lz4`] or [`Compression::Zstd`] compresses
+//! each message's `data` before it is delivered, and subscribers decompress it before
+//! returning the message. On the in-process bus this only costs time, but the compressed
+//! form is what the recorder writes to bags and what the TCP transport sends, so large,
+//! repetitive payloads (maps, point clouds encoded as text) get much smaller there.
+//!
+//! Since `data` is a `String`, a compressed payload is a header character naming the
+//! algorithm (`'\u{1}'` for LZ4, `'\u{2}'` for Zstandard) followed by the compressed
+//! bytes in base64. Uncompressed payloads carry no header, so plain data must not start
+//! with either control character. Each algorithm needs its cargo feature (`lz4` or
+//! `zstd`), on the publishing side to compress and on the receiving side to decompress.
+//!
+//! Interceptors ([`middleware`](super::middleware)) see the compressed payload, so an
+//! encrypting interceptor encrypts data that has already been compressed.
+
+use crate::error::{AuraError, Result};
+use crate::AuraMessage; // Import from lib.rs
+use std::sync::Arc;
+
+/// Header character of an LZ4-compressed payload.
+const LZ4_HEADER: char = '\u{1}';
+/// Header character of a Zstandard-compressed payload.
+const ZSTD_HEADER: char = '\u{2}';
+
+/// Zstandard level used for compression; the library's default speed/ratio trade-off.
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression applied to a publisher's message payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compression {
+    /// Payloads are sent as they are.
+    #[default]
+    None,
+    /// LZ4: very fast, moderate ratio. Needs the `lz4` feature.
+    Lz4,
+    /// Zstandard: better ratio, slower. Needs the `zstd` feature.
+    Zstd,
+}
+
+impl Compression {
+    /// Returns `true` if this build can compress with this algorithm.
+    pub fn is_available(self) -> bool {
+        match self {
+            Compression::None => true,
+            Compression::Lz4 => cfg!(feature = "lz4"),
+            Compression::Zstd => cfg!(feature = "zstd"),
+        }
+    }
+
+    /// Compresses `data` into a headed payload; `Compression::None` returns it unchanged.
+    ///
+    /// # Returns
+    /// The payload, or `AuraError::SerializationError` if the algorithm's feature is not
+    /// enabled or compression fails.
+    pub fn compress(self, data: String) -> Result<String> {
+        match self {
+            Compression::None => Ok(data),
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => Ok(headed(LZ4_HEADER, &lz4_flex::compress_prepend_size(data.as_bytes()))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => {
+                let bytes = zstd::encode_all(data.as_bytes(), ZSTD_LEVEL)
+                    .map_err(|e| AuraError::SerializationError(format!("Zstandard compression failed: {}", e)))?;
+                Ok(headed(ZSTD_HEADER, &bytes))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(unavailable(self)),
+        }
+    }
+}
+
+/// Decompresses a payload produced by [`Compression::compress`]. Payloads without a
+/// compression header are returned unchanged.
+///
+/// # Returns
+/// The original data, or `AuraError::SerializationError` if the payload is corrupt, does
+/// not decompress to UTF-8, or needs an algorithm whose feature is not enabled.
+pub fn decompress(data: String) -> Result<String> {
+    let mut chars = data.chars();
+    let compression = match chars.next() {
+        Some(LZ4_HEADER) => Compression::Lz4,
+        Some(ZSTD_HEADER) => Compression::Zstd,
+        _ => return Ok(data),
+    };
+    let decompressed = decompress_bytes(compression, chars.as_str())?;
+    String::from_utf8(decompressed)
+        .map_err(|e| AuraError::SerializationError(format!("Decompressed payload is not UTF-8: {}", e)))
+}
+
+/// Decompresses a received message's payload, leaving uncompressed messages shared.
+pub(crate) fn decompress_message(message: Arc<AuraMessage>) -> Result<Arc<AuraMessage>> {
+    if !is_compressed(&message.data) {
+        return Ok(message);
+    }
+    let mut message = Arc::unwrap_or_clone(message);
+    message.data = decompress(std::mem::take(&mut message.data))?;
+    Ok(Arc::new(message))
+}
+
+/// Returns `true` if `data` starts with a compression header.
+fn is_compressed(data: &str) -> bool {
+    data.starts_with([LZ4_HEADER, ZSTD_HEADER])
+}
+
+/// Decodes and decompresses the base64 body of a payload compressed with `compression`.
+#[cfg_attr(not(any(feature = "lz4", feature = "zstd")), allow(unused_variables))]
+fn decompress_bytes(compression: Compression, encoded: &str) -> Result<Vec<u8>> {
+    match compression {
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => lz4_flex::decompress_size_prepended(&decode_base64(encoded)?)
+            .map_err(|e| AuraError::SerializationError(format!("LZ4 decompression failed: {}", e))),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::decode_all(decode_base64(encoded)?.as_slice())
+            .map_err(|e| AuraError::SerializationError(format!("Zstandard decompression failed: {}", e))),
+        _ => Err(unavailable(compression)),
+    }
+}
+
+fn unavailable(compression: Compression) -> AuraError {
+    let feature = match compression {
+        Compression::Zstd => "zstd",
+        _ => "lz4",
+    };
+    AuraError::SerializationError(format!(
+        "{:?} compression needs aura_core to be built with the `{}` feature",
+        compression, feature
+    ))
+}
+
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn headed(header: char, bytes: &[u8]) -> String {
+    use base64::Engine;
+    let mut payload = String::from(header);
+    base64::engine::general_purpose::STANDARD.encode_string(bytes, &mut payload);
+    payload
+}
+
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| AuraError::SerializationError(format!("Compressed payload is not valid base64: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile};
+    use crate::Context;
+
+    /// A long, repetitive payload that compresses well.
+    #[cfg(any(feature = "lz4", feature = "zstd"))]
+    fn map_payload() -> String {
+        "0 0 0 1 1 1 0 0 0 ".repeat(200)
+    }
+
+    /// Publishes `data` with `compression` and returns what a subscriber receives.
+    #[cfg(any(feature = "lz4", feature = "zstd"))]
+    fn publish_and_receive(compression: Compression, topic: &str, data: &str) -> String {
+        use crate::comm::Subscriber;
+
+        let context = Context::new();
+        let qos = QosProfile::default().compression(compression);
+        let publisher = Publisher::new_in_context(Arc::clone(&context), topic, qos).unwrap();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+        publisher.publish(data.to_string()).unwrap();
+        subscriber.try_recv().unwrap().unwrap().data
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn lz4_payload_round_trips() {
+        let compressed = Compression::Lz4.compress(map_payload()).unwrap();
+        assert!(compressed.starts_with(LZ4_HEADER));
+        assert!(compressed.len() < map_payload().len());
+        assert_eq!(decompress(compressed).unwrap(), map_payload());
+        assert_eq!(publish_and_receive(Compression::Lz4, "/compressed/lz4", &map_payload()), map_payload());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_payload_round_trips() {
+        let compressed = Compression::Zstd.compress(map_payload()).unwrap();
+        assert!(compressed.starts_with(ZSTD_HEADER));
+        assert!(compressed.len() < map_payload().len());
+        assert_eq!(decompress(compressed).unwrap(), map_payload());
+        assert_eq!(publish_and_receive(Compression::Zstd, "/compressed/zstd", &map_payload()), map_payload());
+    }
+
+    #[test]
+    fn publisher_rejects_unavailable_compression() {
+        for compression in [Compression::Lz4, Compression::Zstd] {
+            let qos = QosProfile::default().compression(compression);
+            let created = Publisher::new_in_context(Context::new(), "/compressed/unavailable", qos);
+            if compression.is_available() {
+                assert!(created.is_ok());
+            } else {
+                assert!(matches!(created, Err(AuraError::CommunicationError(_))), "{:?}", created);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// aura_os/aura_core/src/comm/convert.rs
+
+//! Conversions between application types and `AuraMessage`.
+//!
+//! [`IntoAuraMessage`] and [`FromAuraMessage`] let publishers and subscribers work with
+//! typed values through [`Publisher::publish_as`](super::Publisher::publish_as) and
+//! [`Subscriber::recv_as`](super::Subscriber::recv_as) instead of raw strings.
+//!
+//! With the `json` feature, every `T: Serialize` converts into a message and every
+//! `T: DeserializeOwned` converts back. The message `data` is then the value encoded
+//! as compact JSON (so a `String` value travels as a quoted JSON string, not as
+//! plain text). Types that are not serde-based, such as protobuf messages, implement
+//! the traits themselves, e.g. by base64-encoding their wire bytes.
+//!
+//! Example:
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Pose { x: f64, y: f64 }
+//!
+//! publisher.publish_as(Pose { x: 1.0, y: 2.0 })?;   // data: {"x":1.0,"y":2.0}
+//! let pose: Pose = subscriber.recv_as()?;
+//! ```
+
+use crate::error::Result;
+use crate::AuraMessage;
+
+/// Converts a value into the message published on `topic`.
+pub trait IntoAuraMessage {
+    /// Builds the message. `seq` and `stamp_ns` may be left at zero; the publisher
+    /// fills them in.
+    ///
+    /// # Returns
+    /// The message, or `AuraError::SerializationError` if the value cannot be encoded.
+    fn into_aura_message(self, topic: &str) -> Result<AuraMessage>;
+}
+
+/// Converts a received message back into a value.
+pub trait FromAuraMessage: Sized {
+    /// Decodes the value.
+    ///
+    /// # Returns
+    /// The value, or `AuraError::SerializationError` if the data does not decode.
+    fn from_aura_message(message: &AuraMessage) -> Result<Self>;
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::Serialize> IntoAuraMessage for T {
+    fn into_aura_message(self, topic: &str) -> Result<AuraMessage> {
+        Ok(AuraMessage {
+            topic: topic.to_string(),
+            data: serde_json::to_string(&self)?,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> FromAuraMessage for T {
+    fn from_aura_message(message: &AuraMessage) -> Result<Self> {
+        Ok(serde_json::from_str(&message.data)?)
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile, Subscriber};
+    use crate::error::AuraError;
+    use crate::Context;
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Pose {
+        x: f64,
+        y: f64,
+        frame: String,
+    }
+
+    #[test]
+    fn serde_values_convert_to_json_messages_and_back() {
+        let pose = Pose { x: 1.0, y: -2.5, frame: "map".to_string() };
+        let message = pose.clone().into_aura_message("/pose").unwrap();
+        assert_eq!(message.topic, "/pose");
+        assert_eq!(message.data, r#"{"x":1.0,"y":-2.5,"frame":"map"}"#);
+        assert_eq!(Pose::from_aura_message(&message).unwrap(), pose);
+
+        let text = "hello".to_string().into_aura_message("/chatter").unwrap();
+        assert_eq!(text.data, r#""hello""#);
+        assert_eq!(String::from_aura_message(&text).unwrap(), "hello");
+
+        let value = serde_json::json!({ "ids": [1, 2], "ok": true });
+        let message = value.clone().into_aura_message("/status").unwrap();
+        assert_eq!(serde_json::Value::from_aura_message(&message).unwrap(), value);
+    }
+
+    #[test]
+    fn publish_as_and_recv_as_round_trip() {
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/pose", QosProfile::default()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/pose", QosProfile::default()).unwrap();
+        publisher.publish_as(Pose { x: 3.0, y: 4.0, frame: "odom".to_string() }).unwrap();
+        let pose: Pose = subscriber.recv_as().unwrap();
+        assert_eq!(pose, Pose { x: 3.0, y: 4.0, frame: "odom".to_string() });
+    }
+
+    #[test]
+    fn undecodable_data_is_a_serialization_error() {
+        let message = AuraMessage {
+            topic: "/pose".to_string(),
+            data: r#"{"x":1.0}"#.to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(Pose::from_aura_message(&message), Err(AuraError::SerializationError(_))));
+        let message = AuraMessage { data: "not json".to_string(), ..message };
+        assert!(matches!(i64::from_aura_message(&message), Err(AuraError::SerializationError(_))));
+    }
+}
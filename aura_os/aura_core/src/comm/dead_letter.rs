@@ -0,0 +1,101 @@
+// aura_os/aura_core/src/comm/dead_letter.rs
+
+//! Capture of messages that could not be delivered.
+//!
+//! By default a message dropped because a subscriber's queue was full, or published to
+//! a topic whose subscribers have all gone away, is only logged. With a handler
+//! installed through [`set_dead_letter_handler`], each such message is also handed to
+//! the handler as a [`DeadLetter`], which helps track down flaky graphs.
+//!
+//! Example:
+//! ```ignore
+//! aura_core::comm::set_dead_letter_handler(Arc::new(|letter: DeadLetter| {
+//!     eprintln!("lost a message on {}: {:?}", letter.topic, letter.reason);
+//! }));
+//! ```
+
+use crate::AuraMessage; // Import from lib.rs
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Callback receiving undeliverable messages.
+pub type DeadLetterHandler = Arc<dyn Fn(DeadLetter) + Send + Sync>;
+
+/// Set while a handler is installed, so publishers can skip collecting dead letters
+/// without taking the lock.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static::lazy_static! {
+    static ref HANDLER: Mutex<Option<DeadLetterHandler>> = Mutex::new(None);
+}
+
+/// Why a message could not be delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// The topic had subscribers, but every one of them had been dropped.
+    AllSubscribersDisconnected,
+    /// A subscriber's queue was full and its overflow policy (or `max_backlog`, or the
+    /// publish timeout) discarded the message. Reported once per affected subscriber.
+    QueueOverflow,
+    /// `Publisher::publish_as` could not convert the value into a message.
+    SerializationFailed,
+}
+
+/// A message that could not be delivered, as passed to the dead-letter handler.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    /// The topic the message was published on.
+    pub topic: String,
+    /// The message, or `None` for `SerializationFailed`, where no message was built.
+    pub message: Option<Arc<AuraMessage>>,
+    /// Why the message was lost.
+    pub reason: DeadLetterReason,
+}
+
+/// Installs `handler` to receive every message that could not be delivered, replacing
+/// any previous handler.
+///
+/// The handler runs on the publishing thread after the message bus has been released,
+/// so it may publish itself (though not on a topic whose messages it would then see
+/// dropped again), but it should be quick.
+pub fn set_dead_letter_handler(handler: DeadLetterHandler) {
+    *HANDLER.lock().unwrap_or_else(PoisonError::into_inner) = Some(handler);
+    INSTALLED.store(true, Ordering::Release);
+}
+
+/// Removes the dead-letter handler; undeliverable messages are then only logged again.
+pub fn clear_dead_letter_handler() {
+    INSTALLED.store(false, Ordering::Release);
+    *HANDLER.lock().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Returns `true` if a dead-letter handler is installed.
+pub(crate) fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Acquire)
+}
+
+/// Hands `letters` to the installed handler, if any.
+pub(crate) fn report(letters: Vec<DeadLetter>) {
+    if letters.is_empty() {
+        return;
+    }
+    // Clone the handler out so it runs without the lock held.
+    let handler = HANDLER.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    if let Some(handler) = handler {
+        for letter in letters {
+            handler(letter);
+        }
+    }
+}
+
+/// Builds the dead letters for `messages`, all lost for the same `reason`.
+pub(crate) fn letters(messages: &[Arc<AuraMessage>], reason: DeadLetterReason) -> Vec<DeadLetter> {
+    messages
+        .iter()
+        .map(|message| DeadLetter {
+            topic: message.topic.clone(),
+            message: Some(Arc::clone(message)),
+            reason,
+        })
+        .collect()
+}
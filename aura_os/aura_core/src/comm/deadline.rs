@@ -0,0 +1,150 @@
+// aura_os/aura_core/src/comm/deadline.rs
+
+//! Watching subscribers for missed deadlines.
+//!
+//! A subscriber whose QoS sets a [`deadline`](super::QosProfile::deadline) can register a
+//! callback with [`Subscriber::on_deadline_event`](super::Subscriber::on_deadline_event).
+//! A background watchdog then reports a [`DeadlineEvent::Missed`] for every deadline
+//! window that passes without a message arriving in the subscriber's queue, and a
+//! [`DeadlineEvent::Recovered`] when messages resume.
+//!
+//! Example:
+//! ```ignore
+//! let mut imu = Subscriber::new_with_qos("/imu", QosProfile::default().deadline(Duration::from_millis(20)))?;
+//! imu.on_deadline_event(|event| match event {
+//!     DeadlineEvent::Missed { topic, elapsed } => eprintln!("{} silent for {:?}", topic, elapsed),
+//!     DeadlineEvent::Recovered { topic } => eprintln!("{} is back", topic),
+//! })?;
+//! ```
+
+use crate::aura_log;
+use crate::error::Result;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// What a deadline watchdog reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadlineEvent {
+    /// A deadline window passed without a message. Reported once per window, so a topic
+    /// that stays silent is reported again each time another deadline elapses.
+    Missed {
+        topic: String,
+        /// Time since the last message (or since the watchdog started).
+        elapsed: Duration,
+    },
+    /// A message arrived after at least one missed deadline.
+    Recovered { topic: String },
+}
+
+/// The background thread checking one subscriber's deadline. Dropping it stops the thread.
+#[derive(Debug)]
+pub(crate) struct Watchdog {
+    /// Dropping the sender wakes the thread and tells it to exit.
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    /// Starts watching `arrivals`, the count of messages delivered to a subscriber of
+    /// `topic`, and calls `callback` on the watchdog thread for every event.
+    pub(crate) fn start<F>(topic: &str, deadline: Duration, arrivals: Arc<AtomicU64>, callback: F) -> Result<Self>
+    where
+        F: Fn(DeadlineEvent) + Send + 'static,
+    {
+        let (stop, stop_rx) = mpsc::channel::<()>();
+        // Check often enough that a miss is reported within a tenth of the deadline.
+        let tick = (deadline / 10).max(Duration::from_millis(1));
+        let topic = topic.to_string();
+
+        let thread = thread::Builder::new()
+            .name(format!("aura-deadline{}", topic))
+            .spawn(move || {
+                let mut seen = arrivals.load(Ordering::SeqCst);
+                let mut last_arrival = Instant::now();
+                let mut next_deadline = last_arrival + deadline;
+                let mut missed = false;
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stop_rx.recv_timeout(tick) {
+                    let now = Instant::now();
+                    let count = arrivals.load(Ordering::SeqCst);
+                    if count != seen {
+                        seen = count;
+                        last_arrival = now;
+                        next_deadline = now + deadline;
+                        if missed {
+                            missed = false;
+                            callback(DeadlineEvent::Recovered { topic: topic.clone() });
+                        }
+                    } else if now >= next_deadline {
+                        missed = true;
+                        next_deadline += deadline;
+                        let elapsed = now - last_arrival;
+                        aura_log!(warn, "Deadline of {:?} missed on '{}' (no message for {:?})", deadline, topic, elapsed);
+                        callback(DeadlineEvent::Missed {
+                            topic: topic.clone(),
+                            elapsed,
+                        });
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile, Subscriber};
+    use crate::Context;
+
+    #[test]
+    fn silence_past_the_deadline_is_reported_once_per_window() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/watched", QosProfile::default()).unwrap();
+        let qos = QosProfile::default().deadline(Duration::from_millis(20));
+        let mut subscriber = Subscriber::new_in_context(Arc::clone(&context), "/watched", qos).unwrap();
+        let (events, event_rx) = mpsc::channel();
+        subscriber
+            .on_deadline_event(move |event| {
+                let _ = events.send(event);
+            })
+            .unwrap();
+
+        // Publishing well within the deadline: nothing is reported.
+        for _ in 0..50 {
+            publisher.publish("tick".to_string()).unwrap();
+            thread::sleep(Duration::from_millis(2));
+        }
+        assert_eq!(event_rx.try_recv(), Err(mpsc::TryRecvError::Empty));
+
+        // Going quiet: the first window passes and is reported, then messages resume
+        // before the second one ends.
+        match event_rx.recv_timeout(Duration::from_secs(1)).unwrap() {
+            DeadlineEvent::Missed { topic, elapsed } => {
+                assert_eq!(topic, "/watched");
+                assert!(elapsed >= Duration::from_millis(20), "reported after {:?}", elapsed);
+            }
+            other => panic!("expected a missed deadline, got {:?}", other),
+        }
+        publisher.publish("back".to_string()).unwrap();
+        assert_eq!(
+            event_rx.recv_timeout(Duration::from_secs(1)).unwrap(),
+            DeadlineEvent::Recovered { topic: "/watched".to_string() }
+        );
+        assert_eq!(event_rx.try_recv(), Err(mpsc::TryRecvError::Empty));
+    }
+}
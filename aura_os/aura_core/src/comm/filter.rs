@@ -0,0 +1,283 @@
+// aura_os/aura_core/src/comm/filter.rs
+
+//! Wrappers that thin out the messages a subscriber delivers.
+//!
+//! [`ThrottleSubscriber`] drops messages arriving faster than a minimum period and
+//! [`FilterSubscriber`] drops messages failing a predicate. Both wrap any
+//! [`MessageSource`], including each other, so they compose:
+//!
+//! ```ignore
+//! use aura_core::comm::filter::MessageSource;
+//!
+//! // 100Hz sensor down to at most 10Hz, then only non-empty readings.
+//! let readings = Subscriber::new("/sensor")?
+//!     .throttle(Duration::from_millis(100))
+//!     .filter(|msg| !msg.data.is_empty());
+//! let msg = readings.recv_timeout(Duration::from_secs(1))?;
+//! ```
+//!
+//! Rejected messages are consumed and discarded.
+
+use super::Subscriber;
+use crate::error::{AuraError, Result};
+use crate::AuraMessage;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The receiving surface shared by `Subscriber` and the wrappers in this module.
+pub trait MessageSource {
+    /// Returns the topic the messages come from.
+    fn topic_name(&self) -> &str;
+
+    /// Receives the next accepted message, waiting at most `timeout`.
+    ///
+    /// # Returns
+    /// The message, `AuraError::TimeoutError` if none was accepted in time, or
+    /// `AuraError::ChannelDisconnected` if the channel has been disconnected.
+    fn recv_timeout(&self, timeout: Duration) -> Result<AuraMessage>;
+
+    /// Receives the next accepted message without blocking.
+    ///
+    /// # Returns
+    /// `Ok(Some(message))`, `Ok(None)` if no queued message was accepted, or
+    /// `AuraError::ChannelDisconnected` if the channel has been disconnected.
+    fn try_recv(&self) -> Result<Option<AuraMessage>>;
+
+    /// Wraps this source in a [`ThrottleSubscriber`] with the given minimum period.
+    fn throttle(self, min_period: Duration) -> ThrottleSubscriber<Self>
+    where
+        Self: Sized,
+    {
+        ThrottleSubscriber::new(self, min_period)
+    }
+
+    /// Wraps this source in a [`FilterSubscriber`] with the given predicate.
+    fn filter<F>(self, predicate: F) -> FilterSubscriber<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&AuraMessage) -> bool,
+    {
+        FilterSubscriber::new(self, predicate)
+    }
+}
+
+impl MessageSource for Subscriber {
+    fn topic_name(&self) -> &str {
+        Subscriber::topic_name(self)
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<AuraMessage> {
+        Subscriber::recv_timeout(self, timeout)
+    }
+
+    fn try_recv(&self) -> Result<Option<AuraMessage>> {
+        Subscriber::try_recv(self)
+    }
+}
+
+/// Receives from `inner` until `accept` passes a message or `timeout` elapses.
+fn recv_accepted<S, A>(inner: &S, timeout: Duration, mut accept: A) -> Result<AuraMessage>
+where
+    S: MessageSource + ?Sized,
+    A: FnMut(&AuraMessage) -> bool,
+{
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let message = match inner.recv_timeout(remaining) {
+            Err(AuraError::TimeoutError(_)) => {
+                return Err(AuraError::TimeoutError(format!(
+                    "No message accepted on topic '{}' within {:?}",
+                    inner.topic_name(),
+                    timeout
+                )))
+            }
+            other => other?,
+        };
+        if accept(&message) {
+            return Ok(message);
+        }
+    }
+}
+
+/// Takes queued messages from `inner` until `accept` passes one or the queue is empty.
+fn try_recv_accepted<S, A>(inner: &S, mut accept: A) -> Result<Option<AuraMessage>>
+where
+    S: MessageSource + ?Sized,
+    A: FnMut(&AuraMessage) -> bool,
+{
+    while let Some(message) = inner.try_recv()? {
+        if accept(&message) {
+            return Ok(Some(message));
+        }
+    }
+    Ok(None)
+}
+
+/// Passes at most one message per `min_period`, dropping the ones in between.
+///
+/// The first message after construction always passes. Timing is measured when
+/// messages are received, not when they were published.
+#[derive(Debug)]
+pub struct ThrottleSubscriber<S> {
+    inner: S,
+    min_period: Duration,
+    last_passed: Mutex<Option<Instant>>,
+}
+
+impl<S: MessageSource> ThrottleSubscriber<S> {
+    /// Wraps `inner`, passing at most one message per `min_period`.
+    pub fn new(inner: S, min_period: Duration) -> Self {
+        Self {
+            inner,
+            min_period,
+            last_passed: Mutex::new(None),
+        }
+    }
+
+    /// Returns the wrapped source.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn admit(&self, _message: &AuraMessage) -> bool {
+        let mut last_passed = self.last_passed.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        match *last_passed {
+            Some(last) if now.duration_since(last) < self.min_period => false,
+            _ => {
+                *last_passed = Some(now);
+                true
+            }
+        }
+    }
+}
+
+impl<S: MessageSource> MessageSource for ThrottleSubscriber<S> {
+    fn topic_name(&self) -> &str {
+        self.inner.topic_name()
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<AuraMessage> {
+        recv_accepted(&self.inner, timeout, |message| self.admit(message))
+    }
+
+    fn try_recv(&self) -> Result<Option<AuraMessage>> {
+        try_recv_accepted(&self.inner, |message| self.admit(message))
+    }
+}
+
+/// Passes only the messages for which `predicate` returns `true`.
+pub struct FilterSubscriber<S, F> {
+    inner: S,
+    predicate: F,
+}
+
+impl<S, F> FilterSubscriber<S, F>
+where
+    S: MessageSource,
+    F: Fn(&AuraMessage) -> bool,
+{
+    /// Wraps `inner`, passing only messages accepted by `predicate`.
+    pub fn new(inner: S, predicate: F) -> Self {
+        Self { inner, predicate }
+    }
+
+    /// Returns the wrapped source.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, F> MessageSource for FilterSubscriber<S, F>
+where
+    S: MessageSource,
+    F: Fn(&AuraMessage) -> bool,
+{
+    fn topic_name(&self) -> &str {
+        self.inner.topic_name()
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<AuraMessage> {
+        recv_accepted(&self.inner, timeout, &self.predicate)
+    }
+
+    fn try_recv(&self) -> Result<Option<AuraMessage>> {
+        try_recv_accepted(&self.inner, &self.predicate)
+    }
+}
+
+impl<S: std::fmt::Debug, F> std::fmt::Debug for FilterSubscriber<S, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilterSubscriber").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile};
+    use crate::Context;
+    use std::sync::Arc;
+    use std::thread;
+
+    fn endpoints(topic: &str) -> (Publisher, Subscriber) {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+        let subscriber = Subscriber::new_in_context(context, topic, QosProfile::default()).unwrap();
+        (publisher, subscriber)
+    }
+
+    #[test]
+    fn throttle_passes_at_most_one_message_per_period() {
+        let (publisher, subscriber) = endpoints("/sensor/throttled");
+        let throttled = subscriber.throttle(Duration::from_millis(50));
+        assert_eq!(throttled.topic_name(), "/sensor/throttled");
+
+        // A burst: the first message passes, the rest are dropped.
+        for value in 0..10 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        assert_eq!(throttled.try_recv().unwrap().unwrap().data, "0");
+        assert!(throttled.try_recv().unwrap().is_none());
+
+        // A steady 200 Hz stream for 300 ms yields at most one message per 50 ms window.
+        let started = Instant::now();
+        let mut passed = 0;
+        while started.elapsed() < Duration::from_millis(300) {
+            publisher.publish("tick".to_string()).unwrap();
+            if throttled.try_recv().unwrap().is_some() {
+                passed += 1;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert!((1..=6).contains(&passed), "{} messages passed", passed);
+    }
+
+    #[test]
+    fn filter_drops_messages_failing_the_predicate() {
+        let (publisher, subscriber) = endpoints("/sensor/filtered");
+        let even = subscriber.filter(|message| message.data.parse::<u32>().is_ok_and(|value| value % 2 == 0));
+        for value in 1..=6 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+
+        let mut passed = Vec::new();
+        while let Some(message) = even.try_recv().unwrap() {
+            passed.push(message.data);
+        }
+        assert_eq!(passed, ["2", "4", "6"]);
+        assert!(matches!(even.recv_timeout(Duration::from_millis(20)), Err(AuraError::TimeoutError(_))));
+    }
+
+    #[test]
+    fn throttle_and_filter_compose() {
+        let (publisher, subscriber) = endpoints("/sensor/composed");
+        let readings = subscriber.filter(|message| !message.data.is_empty()).throttle(Duration::from_secs(60));
+        for data in ["", "first", "second"] {
+            publisher.publish(data.to_string()).unwrap();
+        }
+        assert_eq!(readings.try_recv().unwrap().unwrap().data, "first");
+        assert!(readings.try_recv().unwrap().is_none());
+    }
+}
@@ -0,0 +1,212 @@
+// aura_os/aura_core/src/comm/graph.rs
+
+use crate::{lock_shard, prune_disconnected, Context, MessageBus, SubscriberEntry}; // Import from lib.rs
+use std::fmt::Write;
+
+/// A point-in-time description of a topic known to the message bus.
+///
+/// This is the building block for runtime introspection tooling such as a future
+/// `aura topic list` CLI command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicInfo {
+    /// The fully qualified topic name (e.g., "/examples/chatter").
+    pub name: String,
+    /// The number of live subscribers registered on the topic.
+    pub subscriber_count: usize,
+    /// The message type declared by the topic's typed endpoints, if any.
+    pub message_type: Option<&'static str>,
+}
+
+/// Lists every topic registered on the global context's message bus along with its live
+/// subscriber count.
+///
+/// Registrations belonging to dropped subscribers are pruned before counting. A topic
+/// stays listed (with a count of zero) once all of its subscribers are gone, since the
+/// bus keeps its entry. Results are sorted by topic name for stable output.
+///
+/// In this sketch only the in-process bus is inspected; a distributed AuraComm layer
+/// would instead query its discovery service.
+pub fn list_topics() -> Vec<TopicInfo> {
+    Context::global().list_topics()
+}
+
+/// Lists the topics on `bus`; see [`list_topics`].
+pub(crate) fn list_topics_on(bus: &MessageBus) -> Vec<TopicInfo> {
+    let mut topics = Vec::new();
+
+    // Each shard is locked in turn, so the listing is not an atomic snapshot of the
+    // whole bus, but no single lock is held for the entire walk.
+    for shard in bus.shards() {
+        let mut shard_guard = lock_shard(shard);
+
+        topics.extend(shard_guard.iter_mut().map(|(name, topic)| {
+            prune_disconnected(&mut topic.subscribers);
+            TopicInfo {
+                name: name.clone(),
+                subscriber_count: topic.subscribers.len(),
+                message_type: topic.message_type,
+            }
+        }));
+    }
+
+    topics.sort_by(|a, b| a.name.cmp(&b.name));
+    topics
+}
+
+/// The publishers and subscribers of one topic in a [`GraphSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TopicGraph {
+    /// The fully qualified topic name (e.g., "/examples/chatter").
+    pub name: String,
+    /// The number of live publishers on the topic.
+    pub publisher_count: usize,
+    /// The number of live subscribers registered on the topic itself (prefix
+    /// subscribers are listed separately).
+    pub subscriber_count: usize,
+    /// The message type declared by the topic's typed endpoints, if any.
+    pub message_type: Option<String>,
+    /// Fully qualified names of the nodes publishing on the topic, sorted. Publishers
+    /// created without a node are only counted.
+    pub publisher_nodes: Vec<String>,
+    /// Fully qualified names of the nodes subscribed to the topic, sorted.
+    pub subscriber_nodes: Vec<String>,
+}
+
+/// The subscribers of one namespace in a [`GraphSnapshot`]; see `Subscriber::new_prefix`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrefixGraph {
+    /// The namespace covered (e.g., "/sensors", or "/" for every topic).
+    pub prefix: String,
+    /// The number of live prefix subscribers on the namespace.
+    pub subscriber_count: usize,
+    /// Fully qualified names of the nodes owning them, sorted.
+    pub subscriber_nodes: Vec<String>,
+}
+
+/// The whole communication graph of a context at one instant.
+///
+/// Taken with every bus lock held at once, so no publisher or subscriber can come or go
+/// halfway through. With the `serde` feature it serializes (e.g., to JSON); see
+/// [`GraphSnapshot::to_dot`] for Graphviz output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphSnapshot {
+    /// Every topic on the bus, sorted by name.
+    pub topics: Vec<TopicGraph>,
+    /// Every namespace with prefix subscribers, sorted.
+    pub prefixes: Vec<PrefixGraph>,
+}
+
+impl GraphSnapshot {
+    /// Renders the graph in Graphviz DOT format, e.g. for `dot -Tsvg`.
+    ///
+    /// Nodes are boxes and topics ellipses (labelled with their message type, if any);
+    /// edges run from publishing nodes to topics and from topics to subscribing nodes.
+    /// A prefix subscription appears as a dashed edge from a `prefix/*` ellipse.
+    /// Endpoints created without a node only show up in the counts.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph aura {\n    rankdir=LR;\n");
+        let mut nodes: Vec<&str> = self
+            .topics
+            .iter()
+            .flat_map(|topic| topic.publisher_nodes.iter().chain(&topic.subscriber_nodes))
+            .chain(self.prefixes.iter().flat_map(|prefix| &prefix.subscriber_nodes))
+            .map(String::as_str)
+            .collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        for node in nodes {
+            let _ = writeln!(dot, "    \"node:{}\" [label=\"{}\", shape=box];", node, node);
+        }
+        for topic in &self.topics {
+            let label = match &topic.message_type {
+                Some(message_type) => format!("{}\\n{}", topic.name, dot_escape(message_type)),
+                None => topic.name.clone(),
+            };
+            let _ = writeln!(dot, "    \"topic:{}\" [label=\"{}\", shape=ellipse];", topic.name, label);
+            for node in &topic.publisher_nodes {
+                let _ = writeln!(dot, "    \"node:{}\" -> \"topic:{}\";", node, topic.name);
+            }
+            for node in &topic.subscriber_nodes {
+                let _ = writeln!(dot, "    \"topic:{}\" -> \"node:{}\";", topic.name, node);
+            }
+        }
+        for prefix in &self.prefixes {
+            let pattern = format!("{}/*", prefix.prefix.trim_end_matches('/'));
+            let _ = writeln!(dot, "    \"prefix:{}\" [label=\"{}\", shape=ellipse, style=dashed];", prefix.prefix, pattern);
+            for node in &prefix.subscriber_nodes {
+                let _ = writeln!(dot, "    \"prefix:{}\" -> \"node:{}\" [style=dashed];", prefix.prefix, node);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes `"` and `\` for use inside a quoted DOT string. Topic and node names
+/// cannot contain either, but message type names are free-form.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Takes a [`GraphSnapshot`] of the global context.
+pub fn snapshot() -> GraphSnapshot {
+    Context::global().graph_snapshot()
+}
+
+/// Takes a [`GraphSnapshot`] of `bus`; see [`snapshot`].
+pub(crate) fn snapshot_of(bus: &MessageBus) -> GraphSnapshot {
+    // The prefix lock is never taken while a shard is held, so it goes first. No other
+    // code holds two shards, so locking all of them in order cannot deadlock.
+    let mut prefix_guard = bus.lock_prefixes();
+    let mut shard_guards: Vec<_> = bus.shards().map(lock_shard).collect();
+
+    let mut graph = GraphSnapshot::default();
+    for shard_guard in &mut shard_guards {
+        for (name, topic) in shard_guard.iter_mut() {
+            prune_disconnected(&mut topic.subscribers);
+            let publishers: Vec<_> = topic.publishers.iter().filter_map(|publisher| publisher.upgrade()).collect();
+            topic.publishers.retain(|publisher| publisher.strong_count() > 0);
+            graph.topics.push(TopicGraph {
+                name: name.clone(),
+                publisher_count: publishers.len(),
+                subscriber_count: topic.subscribers.len(),
+                message_type: topic.message_type.map(str::to_string),
+                publisher_nodes: sorted_names(publishers.iter().filter_map(|publisher| publisher.node.get())),
+                subscriber_nodes: subscriber_nodes(&topic.subscribers),
+            });
+        }
+    }
+    drop(shard_guards);
+    for (prefix, entries) in prefix_guard.iter_mut() {
+        prune_disconnected(entries);
+        if entries.is_empty() {
+            continue;
+        }
+        graph.prefixes.push(PrefixGraph {
+            prefix: prefix.clone(),
+            subscriber_count: entries.len(),
+            subscriber_nodes: subscriber_nodes(entries),
+        });
+    }
+    drop(prefix_guard);
+
+    graph.topics.sort_by(|a, b| a.name.cmp(&b.name));
+    graph.prefixes.sort_by(|a, b| a.prefix.cmp(&b.prefix));
+    graph
+}
+
+/// Returns the owning nodes of `entries`, sorted and without duplicates.
+fn subscriber_nodes(entries: &[SubscriberEntry]) -> Vec<String> {
+    let registrations: Vec<_> = entries.iter().filter_map(|entry| entry.liveness.upgrade()).collect();
+    sorted_names(registrations.iter().filter_map(|registration| registration.node.get()))
+}
+
+fn sorted_names<'a>(names: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut names: Vec<String> = names.cloned().collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
@@ -0,0 +1,241 @@
+// aura_os/aura_core/src/comm/middleware.rs
+
+//! Interceptors that see every message, for cross-cutting concerns such as logging,
+//! metrics or encryption.
+//!
+//! Interceptors are registered process-wide with [`register`] and run in registration
+//! order. The publish chain runs in `Publisher::publish` before the message is shown to
+//! taps and handed to subscribers; the receive chain runs in each subscriber before a
+//! message is returned from `recv`, `try_recv`, `drain` and friends. Either chain may
+//! modify the message. An interceptor that returns `Err` drops the message on publish
+//! (the publish still returns `Ok`) and makes the subscriber skip it on receive.
+//!
+//! Example:
+//! ```ignore
+//! struct Tagger;
+//! impl Interceptor for Tagger {
+//!     fn on_publish(&self, msg: &mut AuraMessage) -> Result<()> {
+//!         msg.data.push_str(" [tagged]");
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let _handle = aura_core::comm::middleware::register(Arc::new(Tagger));
+//! ```
+
+use crate::error::Result;
+use crate::{aura_log, AuraMessage}; // Import from lib.rs
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Source of process-unique interceptor ids.
+static NEXT_INTERCEPTOR_ID: AtomicU64 = AtomicU64::new(1);
+/// Number of registered interceptors, so messages skip the chain lock when there are none.
+static REGISTERED: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static::lazy_static! {
+    static ref CHAIN: Mutex<Vec<(u64, Arc<dyn Interceptor>)>> = Mutex::new(Vec::new());
+}
+
+/// Middleware that sees, and may modify or reject, every message.
+///
+/// Both hooks default to passing the message through unchanged. They run on the
+/// publishing or receiving thread, so they should be quick.
+pub trait Interceptor: Send + Sync {
+    /// Called for each message before it is delivered. Changing the message's `topic`
+    /// has no effect; it is delivered on the topic it was published on.
+    ///
+    /// # Returns
+    /// `Ok(())` to let the (possibly modified) message through, or an error to drop it.
+    fn on_publish(&self, msg: &mut AuraMessage) -> Result<()> {
+        let _ = msg;
+        Ok(())
+    }
+
+    /// Called for each message before a subscriber returns it. Every subscriber runs the
+    /// chain on its own copy.
+    ///
+    /// # Returns
+    /// `Ok(())` to return the (possibly modified) message, or an error to skip it.
+    fn on_receive(&self, msg: &mut AuraMessage) -> Result<()> {
+        let _ = msg;
+        Ok(())
+    }
+}
+
+/// An interceptor registered with [`register`].
+///
+/// The interceptor stays in the chain until this handle is dropped (or
+/// [`InterceptorHandle::unregister`] is called).
+#[must_use = "the interceptor is removed as soon as the handle is dropped"]
+#[derive(Debug)]
+pub struct InterceptorHandle {
+    id: u64,
+}
+
+impl InterceptorHandle {
+    /// Removes the interceptor from the chain. Equivalent to dropping the handle.
+    pub fn unregister(self) {}
+}
+
+impl Drop for InterceptorHandle {
+    fn drop(&mut self) {
+        let mut chain = CHAIN.lock().unwrap_or_else(PoisonError::into_inner);
+        chain.retain(|(id, _)| *id != self.id);
+        REGISTERED.store(chain.len(), Ordering::Release);
+    }
+}
+
+/// Appends `interceptor` to the end of the chain.
+///
+/// # Returns
+/// An `InterceptorHandle` that removes the interceptor when dropped.
+pub fn register(interceptor: Arc<dyn Interceptor>) -> InterceptorHandle {
+    let id = NEXT_INTERCEPTOR_ID.fetch_add(1, Ordering::Relaxed);
+    let mut chain = CHAIN.lock().unwrap_or_else(PoisonError::into_inner);
+    chain.push((id, interceptor));
+    REGISTERED.store(chain.len(), Ordering::Release);
+    aura_log!(debug, "Registered interceptor {} ({} in the chain)", id, chain.len());
+    InterceptorHandle { id }
+}
+
+/// Copies the chain out, so interceptors run without the lock held and may register
+/// or drop interceptors themselves.
+fn chain() -> Option<Vec<Arc<dyn Interceptor>>> {
+    if REGISTERED.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    let chain = CHAIN.lock().unwrap_or_else(PoisonError::into_inner);
+    Some(chain.iter().map(|(_, interceptor)| Arc::clone(interceptor)).collect())
+}
+
+/// Runs the publish chain on `message`.
+///
+/// # Returns
+/// `false` if an interceptor rejected the message.
+pub(crate) fn publish(message: &mut AuraMessage) -> bool {
+    let Some(chain) = chain() else {
+        return true;
+    };
+    let topic = message.topic.clone();
+    for interceptor in chain {
+        if let Err(e) = interceptor.on_publish(message) {
+            aura_log!(debug, "Interceptor dropped message {} on '{}': {}", message.seq, topic, e);
+            return false;
+        }
+    }
+    message.topic = topic;
+    true
+}
+
+/// Runs the receive chain on a copy of `message`.
+///
+/// # Returns
+/// The message to return to the subscriber, or `None` if an interceptor rejected it.
+pub(crate) fn receive(message: Arc<AuraMessage>) -> Option<Arc<AuraMessage>> {
+    let Some(chain) = chain() else {
+        return Some(message);
+    };
+    let mut message = Arc::unwrap_or_clone(message);
+    for interceptor in chain {
+        if let Err(e) = interceptor.on_receive(&mut message) {
+            aura_log!(debug, "Interceptor skipped message {} on '{}': {}", message.seq, message.topic, e);
+            return None;
+        }
+    }
+    Some(Arc::new(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{stats, tap, Publisher, QosProfile, Subscriber};
+    use crate::error::AuraError;
+    use crate::Context;
+
+    // The chain is process-wide, so every interceptor here only acts on its own test's
+    // topic and passes other tests' messages through untouched.
+
+    /// Appends `|mark` to messages on `topic`, on publish and on receive.
+    struct Marker {
+        topic: &'static str,
+        mark: &'static str,
+    }
+
+    impl Interceptor for Marker {
+        fn on_publish(&self, msg: &mut AuraMessage) -> Result<()> {
+            if msg.topic == self.topic {
+                msg.data = format!("{}|{}", msg.data, self.mark);
+            }
+            Ok(())
+        }
+
+        fn on_receive(&self, msg: &mut AuraMessage) -> Result<()> {
+            if msg.topic == self.topic {
+                msg.data = format!("{}|recv-{}", msg.data, self.mark);
+            }
+            Ok(())
+        }
+    }
+
+    /// Rejects messages on `topic` whose data starts with "drop".
+    struct Dropper {
+        topic: &'static str,
+    }
+
+    impl Interceptor for Dropper {
+        fn on_publish(&self, msg: &mut AuraMessage) -> Result<()> {
+            if msg.topic == self.topic && msg.data.starts_with("drop") {
+                return Err(AuraError::Other("rejected by the test".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn interceptors_run_in_registration_order() {
+        let topic = "/middleware_test/order";
+        let _first = register(Arc::new(Marker { topic, mark: "a" }));
+        let _second = register(Arc::new(Marker { topic, mark: "b" }));
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+
+        publisher.publish("msg".to_string()).unwrap();
+        assert_eq!(subscriber.try_recv().unwrap().unwrap().data, "msg|a|b|recv-a|recv-b");
+    }
+
+    #[test]
+    fn rejected_message_is_not_delivered() {
+        let topic = "/middleware_test/drop";
+        let _dropper = register(Arc::new(Dropper { topic }));
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+
+        publisher.publish("drop me".to_string()).unwrap();
+        publisher.publish("keep me".to_string()).unwrap();
+        let received: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(received, ["keep me"]);
+    }
+
+    #[test]
+    fn interceptors_run_before_taps_and_stats() {
+        let topic = "/middleware_test/observed";
+        let _marker = register(Arc::new(Marker { topic, mark: "m" }));
+        let _dropper = register(Arc::new(Dropper { topic }));
+        stats::set_enabled(true);
+        let context = Context::new();
+        let tapped = Arc::new(Mutex::new(Vec::new()));
+        let _tap = {
+            let tapped = Arc::clone(&tapped);
+            tap::tap_in_context(Arc::clone(&context), topic, move |msg| tapped.lock().unwrap().push(msg.data.clone())).unwrap()
+        };
+        let publisher = Publisher::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+
+        publisher.publish("seen".to_string()).unwrap();
+        publisher.publish("drop".to_string()).unwrap();
+        assert_eq!(*tapped.lock().unwrap(), ["seen|m"]);
+        assert_eq!(stats::topic_stats(topic).unwrap().publish_count, 1);
+    }
+}
@@ -17,25 +17,58 @@
 //! - **Introspection:** (Future) Tools to inspect topics, message rates, etc.
 
 // Declare the sub-modules within the `comm` module.
-// These correspond to `publisher.rs` and `subscriber.rs` files.
+// These correspond to `callback.rs`, `compression.rs`, `convert.rs`, `dead_letter.rs`, `deadline.rs`, `filter.rs`, `graph.rs`, `middleware.rs`, `multi.rs`, `publisher.rs`, `qos.rs`, `request.rs`, `serde_format.rs`, `stats.rs`, `subscriber.rs` and `tap.rs` files,
+// plus `testing.rs` with the `test-util` feature and the `transport/` directory with the `tcp` feature.
+pub mod callback;
+pub mod compression;
+pub mod convert;
+pub mod dead_letter;
+pub mod deadline;
+pub mod filter;
+pub mod graph;
+pub mod middleware;
+pub mod multi;
 pub mod publisher;
+pub mod qos;
+pub mod request;
+pub mod serde_format;
+pub mod stats;
 pub mod subscriber;
+pub mod tap;
+#[cfg(feature = "test-util")]
+pub mod testing;
+#[cfg(feature = "tcp")]
+pub mod transport;
 // Future sub-modules:
 // pub mod service_server;
 // pub mod service_client;
 // pub mod action_server;
 // pub mod action_client;
-// pub mod qos; // For Quality of Service profile definitions
 
 // Re-export the primary types from the sub-modules to make them directly
 // accessible via `aura_core::comm::Publisher` etc.
 // This defines the public API of the `comm` module.
-pub use publisher::Publisher;
+pub use callback::{MessageInfo, TypedSubscription};
+pub use compression::Compression;
+pub use convert::{FromAuraMessage, IntoAuraMessage};
+pub use dead_letter::{clear_dead_letter_handler, set_dead_letter_handler, DeadLetter, DeadLetterReason};
+pub use deadline::DeadlineEvent;
+pub use filter::{FilterSubscriber, MessageSource, ThrottleSubscriber};
+pub use graph::{list_topics, GraphSnapshot, TopicInfo};
+pub use middleware::{Interceptor, InterceptorHandle};
+pub use multi::MultiSubscriber;
+pub use publisher::{Publisher, RateLimitPolicy};
+pub use qos::{Durability, OverflowPolicy, QosProfile, Reliability};
+pub use request::{reply, reply_in_context};
+pub use serde_format::SerializeFormat;
+pub use stats::{topic_stats, TopicStats};
 pub use subscriber::Subscriber;
+pub use tap::{tap, tap_in_context, TapHandle};
+#[cfg(feature = "test-util")]
+pub use testing::{bus_topic_count, reset_bus};
 // Future re-exports:
 // pub use service_server::ServiceServer;
 // pub use service_client::ServiceClient;
-// pub use qos::QosProfile; // Example
 
 // --- Constants related to communication ---
 
@@ -43,6 +76,9 @@ pub use subscriber::Subscriber;
 /// by a QoS profile. This is a conceptual constant for now.
 pub const DEFAULT_MESSAGE_QUEUE_SIZE: usize = 10;
 
+/// Queue group joined by `Node::create_work_queue_subscriber`.
+pub const DEFAULT_WORK_QUEUE_GROUP: &str = "work_queue";
+
 // --- Traits for Message Types (Conceptual for Future Use) ---
 
 /// A conceptual marker trait for types that can be used as AuraOS messages.
@@ -54,14 +90,21 @@ pub const DEFAULT_MESSAGE_QUEUE_SIZE: usize = 10;
 /// This would allow for type-safe communication and potentially for message
 /// introspection or generation of message definitions.
 pub trait AuraMessageTrait: Clone + Send + Sync + 'static {
-    // For this sketch, it's just a marker trait.
-    // In the future, it could hold metadata about the message type.
-    // For example, a static method to get the message type name:
-    // fn type_name() -> &'static str;
+    /// The name typed publishers and subscribers register for their topic, so that
+    /// endpoints disagreeing on the type of a topic are rejected at creation.
+    ///
+    /// Defaults to the Rust type path; override it with a stable, short name.
+    fn type_name() -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 // Implement the marker trait for String for our simple AuraMessage.data
-impl AuraMessageTrait for String {}
+impl AuraMessageTrait for String {
+    fn type_name() -> &'static str {
+        "String"
+    }
+}
 
 // We could also implement it for our `crate::AuraMessage` struct if we wanted
 // to pass the whole struct around with this trait, but our current pub/sub
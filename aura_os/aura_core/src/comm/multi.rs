@@ -0,0 +1,208 @@
+// aura_os/aura_core/src/comm/multi.rs
+
+//! A subscriber on several topics at once.
+//!
+//! [`MultiSubscriber`] registers one sender per topic, all feeding a single queue, so a
+//! node reacting to several topics can wait on all of them with one call:
+//!
+//! ```ignore
+//! let inputs = MultiSubscriber::new(&["/cmd_vel", "/estop"])?;
+//! loop {
+//!     let (topic, msg) = inputs.recv_timeout(Duration::from_millis(100))?;
+//!     match topic.as_str() {
+//!         "/estop" => halt(),
+//!         _ => drive(&msg.data),
+//!     }
+//! }
+//! ```
+
+use super::compression;
+use super::qos::QosProfile;
+use super::subscriber::{add_entry, open_queue, SubscriptionHandle};
+use crate::error::{AuraError, Result};
+use crate::naming;
+use crate::{aura_log, AuraMessage, Context, MessageReceiver, MessageSender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Receives the messages of several topics through one queue, in arrival order.
+///
+/// Every topic is registered with the same QoS profile; a `depth` bounds the shared
+/// queue, not each topic. Messages keep their `topic` field, and the receive methods
+/// also return it separately. Dropping the subscriber unregisters all of its topics.
+#[derive(Debug)]
+pub struct MultiSubscriber {
+    context: Arc<Context>,
+    qos: QosProfile,
+    /// Kept to register new topics; also keeps the queue from ever disconnecting.
+    sender: MessageSender,
+    receiver: MessageReceiver,
+    /// One registration per topic, in the order the topics were added.
+    registrations: Mutex<Vec<Arc<SubscriptionHandle>>>,
+}
+
+impl MultiSubscriber {
+    /// Subscribes to every topic in `topic_names` with the default QoS profile.
+    ///
+    /// # Returns
+    /// The new `MultiSubscriber`, or `AuraError::CommunicationError` if a topic name is
+    /// invalid.
+    pub fn new(topic_names: &[&str]) -> Result<Self> {
+        Self::new_in_context(Context::global(), topic_names, QosProfile::default())
+    }
+
+    /// Subscribes to every topic in `topic_names` in `context`, with the given QoS profile.
+    ///
+    /// # Returns
+    /// The new `MultiSubscriber`, or `AuraError::CommunicationError` if a topic name or
+    /// the QoS profile is invalid.
+    pub fn new_in_context(context: Arc<Context>, topic_names: &[&str], qos: QosProfile) -> Result<Self> {
+        if qos.depth == Some(0) || qos.max_backlog == Some(0) {
+            return Err(AuraError::CommunicationError(
+                "Invalid QoS for multi-subscriber: depth and max_backlog must be at least 1.".to_string(),
+            ));
+        }
+        let (sender, receiver) = open_queue(&qos);
+        let subscriber = Self {
+            context,
+            qos,
+            sender,
+            receiver,
+            registrations: Mutex::new(Vec::new()),
+        };
+        for topic_name in topic_names {
+            subscriber.add_topic(topic_name)?;
+        }
+        Ok(subscriber)
+    }
+
+    /// Starts receiving the messages of `topic_name` as well. Adding a topic that is
+    /// already subscribed does nothing.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::CommunicationError` if the topic name is invalid.
+    pub fn add_topic(&self, topic_name: &str) -> Result<()> {
+        naming::validate_absolute_name(topic_name).map_err(|reason| {
+            AuraError::CommunicationError(format!("Invalid topic name '{}': {}.", topic_name, reason))
+        })?;
+        let mut registrations = self.registrations.lock().unwrap_or_else(PoisonError::into_inner);
+        if registrations.iter().any(|registration| registration.topic_name() == topic_name) {
+            return Ok(());
+        }
+
+        aura_log!(info, "Adding topic '{}' to multi-subscriber", topic_name);
+        let registration = SubscriptionHandle::new(Arc::clone(&self.context), topic_name);
+        add_entry(&registration, None, &self.qos, self.sender.clone())?;
+        registrations.push(registration);
+        Ok(())
+    }
+
+    /// Stops receiving the messages of `topic_name`. Messages from it that are already
+    /// queued are still delivered.
+    ///
+    /// # Returns
+    /// `Ok(true)` if the topic was subscribed, or `Ok(false)` if it was not.
+    pub fn remove_topic(&self, topic_name: &str) -> Result<bool> {
+        let mut registrations = self.registrations.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(position) = registrations.iter().position(|registration| registration.topic_name() == topic_name) else {
+            return Ok(false);
+        };
+        registrations[position].detach()?;
+        registrations.remove(position);
+        Ok(true)
+    }
+
+    /// Returns the subscribed topics, in the order they were added.
+    pub fn topics(&self) -> Vec<String> {
+        let registrations = self.registrations.lock().unwrap_or_else(PoisonError::into_inner);
+        registrations.iter().map(|registration| registration.topic_name().to_string()).collect()
+    }
+
+    /// Receives the next message from any of the topics, waiting at most `timeout`.
+    ///
+    /// # Returns
+    /// The topic the message was published on and the message,
+    /// `AuraError::TimeoutError` if none arrived in time, or
+    /// `AuraError::SerializationError` if a compressed payload does not decompress.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<(String, AuraMessage)> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(message) => compression::decompress_message(message).map(Self::labelled),
+            Err(_) => Err(AuraError::TimeoutError(format!(
+                "No message received on topics {:?} within {:?}",
+                self.topics(),
+                timeout
+            ))),
+        }
+    }
+
+    /// Receives the next queued message from any of the topics without blocking.
+    ///
+    /// # Returns
+    /// `Some((topic, message))`, or `None` if nothing is queued. A message whose
+    /// compressed payload does not decompress is logged and skipped.
+    pub fn try_recv(&self) -> Option<(String, AuraMessage)> {
+        let message = self.receiver.try_recv().ok()?;
+        match compression::decompress_message(Arc::clone(&message)) {
+            Ok(message) => Some(Self::labelled(message)),
+            Err(e) => {
+                aura_log!(warn, "Skipping undecodable message on '{}': {}", message.topic, e);
+                None
+            }
+        }
+    }
+
+    /// Returns the number of messages waiting in the shared queue.
+    pub fn backlog_len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    /// Labels `message` with its topic.
+    fn labelled(message: Arc<AuraMessage>) -> (String, AuraMessage) {
+        let message = Arc::unwrap_or_clone(message);
+        (message.topic.clone(), message)
+    }
+}
+
+impl Drop for MultiSubscriber {
+    /// Removes the sender of every topic from the message bus.
+    fn drop(&mut self) {
+        let registrations = std::mem::take(&mut *self.registrations.lock().unwrap_or_else(PoisonError::into_inner));
+        for registration in registrations {
+            if let Err(e) = registration.detach() {
+                aura_log!(warn, "Failed to unregister multi-subscriber from '{}': {}", registration.topic_name(), e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::Publisher;
+
+    #[test]
+    fn messages_are_labelled_with_their_topic() {
+        let context = Context::new();
+        let inputs = MultiSubscriber::new_in_context(Arc::clone(&context), &["/cmd_vel", "/estop"], QosProfile::default()).unwrap();
+        assert_eq!(inputs.topics(), ["/cmd_vel", "/estop"]);
+        let cmd_vel = Publisher::new_in_context(Arc::clone(&context), "/cmd_vel", QosProfile::default()).unwrap();
+        let estop = Publisher::new_in_context(Arc::clone(&context), "/estop", QosProfile::default()).unwrap();
+
+        cmd_vel.publish("forward".to_string()).unwrap();
+        estop.publish("halt".to_string()).unwrap();
+        cmd_vel.publish("stop".to_string()).unwrap();
+        let received: Vec<(String, String)> = std::iter::from_fn(|| inputs.try_recv())
+            .map(|(topic, message)| {
+                assert_eq!(topic, message.topic);
+                (topic, message.data)
+            })
+            .collect();
+        let expected = [("/cmd_vel", "forward"), ("/estop", "halt"), ("/cmd_vel", "stop")];
+        assert_eq!(received, expected.map(|(topic, data)| (topic.to_string(), data.to_string())));
+
+        // After removing a topic, its messages no longer arrive.
+        assert!(inputs.remove_topic("/estop").unwrap());
+        estop.publish("halt".to_string()).unwrap();
+        assert!(matches!(inputs.recv_timeout(Duration::from_millis(20)), Err(AuraError::TimeoutError(_))));
+    }
+}
@@ -1,11 +1,109 @@
 // aura_os/aura_core/src/comm/publisher.rs
 
-use crate::{AuraMessage, MESSAGE_BUS, aura_log}; // Import from lib.rs
+use crate::{prefix_matches, prune_disconnected, AuraMessage, Context, MessageBus, MessageSender, SubscriberEntry, aura_log}; // Import from lib.rs
 use crate::error::{AuraError, Result};
-// In a real system with typed messages, you'd import the AuraMessageTrait
-// use super::AuraMessageTrait; // Assuming AuraMessageTrait is in comm/mod.rs
+use crate::naming;
+use crate::node::Stoppable;
+use crate::param::ParamValue;
+use super::qos::{Durability, OverflowPolicy, QosProfile};
+use super::{AuraMessageTrait, IntoAuraMessage};
+use super::dead_letter::{self, DeadLetter, DeadLetterReason};
+use super::{middleware, request, stats, tap, Subscriber};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::TrySendError;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, Weak};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 // use std::marker::PhantomData; // For generic typed publishers
 
+/// How often `wait_for_subscribers` re-checks the subscriber count while waiting.
+const SUBSCRIBER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Default for [`Publisher::set_no_subscriber_warning_interval`].
+pub const DEFAULT_NO_SUBSCRIBER_WARNING_INTERVAL: Duration = Duration::from_secs(10);
+
+
+/// Shared stop state of a `Publisher`, held weakly by the owning `Node` and the bus.
+#[derive(Debug, Default)]
+pub(crate) struct PublicationHandle {
+    stopped: AtomicBool,
+    /// Fully qualified name of the node that created the publisher, if any.
+    pub(crate) node: OnceLock<String>,
+}
+
+impl Stoppable for PublicationHandle {
+    /// Marks the publisher as stopped; subsequent `publish` calls fail.
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// What a rate-limited `Publisher` does with a publish that comes before its next slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitPolicy {
+    /// Block the caller until the next slot.
+    #[default]
+    Block,
+    /// Discard the message; `publish` returns `Ok(())` without sending it.
+    Drop,
+}
+
+/// Rate limiting state of a `Publisher`.
+#[derive(Debug, Default)]
+struct RateLimiter {
+    /// Minimum time between publishes, or `None` when limiting is disabled.
+    interval: Option<Duration>,
+    policy: RateLimitPolicy,
+    /// Earliest time the next publish may go out.
+    next_slot: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Claims the next publish slot.
+    ///
+    /// # Returns
+    /// The time to wait until before publishing (`None` if it may go out now), or
+    /// `Err(())` if the publish should be dropped.
+    fn claim(&mut self) -> std::result::Result<Option<Instant>, ()> {
+        let Some(interval) = self.interval else {
+            return Ok(None);
+        };
+        let now = Instant::now();
+        let slot = match self.next_slot {
+            Some(next) if next > now => {
+                if self.policy == RateLimitPolicy::Drop {
+                    return Err(());
+                }
+                next
+            }
+            _ => now,
+        };
+        self.next_slot = Some(slot + interval);
+        Ok((slot > now).then_some(slot))
+    }
+}
+
+/// State of `Publisher::warn_on_no_subscribers`.
+#[derive(Debug)]
+struct NoSubscriberWarning {
+    enabled: bool,
+    interval: Duration,
+    last_warned: Option<Instant>,
+    /// Set by the first publish that reaches a subscriber; no warnings after that.
+    seen_subscriber: bool,
+}
+
+impl Default for NoSubscriberWarning {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: DEFAULT_NO_SUBSCRIBER_WARNING_INTERVAL,
+            last_warned: None,
+            seen_subscriber: false,
+        }
+    }
+}
+
 /// Represents a publisher that can send messages on a specific topic.
 ///
 /// In this simplified sketch, `Publisher` sends `String` data.
@@ -18,10 +116,23 @@ use crate::error::{AuraError, Result};
 #[derive(Debug)] // Allow easy printing for debugging
 pub struct Publisher {
     topic_name: String,
+    /// Context whose bus the messages go to.
+    context: Arc<Context>,
+    qos: QosProfile,
+    /// Type declared with `new_typed`, if any.
+    message_type: Option<&'static str>,
+    /// Stop state; the owning `Node` holds a `Weak` to it.
+    handle: Arc<PublicationHandle>,
+    /// Sequence number given to the next published message.
+    next_seq: AtomicU64,
+    /// Set by `with_rate_limit`/`set_rate_limit`.
+    rate_limiter: Mutex<RateLimiter>,
+    /// Set by `warn_on_no_subscribers`.
+    no_subscriber_warning: Mutex<NoSubscriberWarning>,
     // In a real system with generic message types:
     // _message_type: PhantomData<M>, // Ensures type M is known at compile time
     //
-    // This sketch directly interacts with the global MESSAGE_BUS.
+    // This sketch directly interacts with its context's in-process message bus.
     // A real publisher would hold a client handle to the AuraComm system,
     // or a specific communication channel object.
 }
@@ -41,22 +152,94 @@ impl Publisher {
     /// # Returns
     /// A `Result` containing the new `Publisher` or an `AuraError` if creation fails.
     pub fn new(topic_name: &str) -> Result<Self> {
-        // Basic validation for topic name (could be more extensive)
-        if topic_name.is_empty() || !topic_name.starts_with('/') {
+        Self::new_with_qos(topic_name, QosProfile::default())
+    }
+
+    /// Creates a new `Publisher` with the given QoS profile.
+    ///
+    /// With `Durability::TransientLocal`, the bus keeps this topic's most recent message
+    /// and hands it to every subscriber that joins later.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Publisher` or an `AuraError` if creation fails.
+    pub fn new_with_qos(topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::create(Context::global(), topic_name, qos, None)
+    }
+
+    /// Creates a `Publisher` in `context` instead of the global context. Only
+    /// subscribers in the same context receive its messages.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Publisher` or an `AuraError` if creation fails.
+    pub fn new_in_context(context: Arc<Context>, topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::create(context, topic_name, qos, None)
+    }
+
+    /// Creates a `Publisher` that declares the topic's message type as `M::type_name()`.
+    ///
+    /// Every typed publisher and subscriber on a topic must declare the same type;
+    /// untyped ones (from `new`/`new_with_qos`) are compatible with any type.
+    ///
+    /// # Returns
+    /// The new `Publisher`, or `AuraError::CommunicationError` if the topic already
+    /// carries a different type.
+    pub fn new_typed<M: AuraMessageTrait>(topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::create(Context::global(), topic_name, qos, Some(M::type_name()))
+    }
+
+    pub(crate) fn create(
+        context: Arc<Context>,
+        topic_name: &str,
+        qos: QosProfile,
+        message_type: Option<&'static str>,
+    ) -> Result<Self> {
+        // Validate the topic name against the naming grammar (absolute, well-formed segments).
+        naming::validate_absolute_name(topic_name).map_err(|reason| {
+            AuraError::CommunicationError(format!("Invalid topic name '{}': {}.", topic_name, reason))
+        })?;
+
+        if !qos.compression.is_available() {
+            return Err(AuraError::CommunicationError(format!(
+                "Cannot create publisher for topic '{}': {:?} compression is not enabled in this build.",
+                topic_name, qos.compression
+            )));
+        }
+        if let Some(format) = qos.serialize_format.filter(|format| !format.is_available()) {
             return Err(AuraError::CommunicationError(format!(
-                "Invalid topic name '{}': Must be absolute (start with '/') and non-empty.",
-                topic_name
+                "Cannot create publisher for topic '{}': {:?} serialization is not enabled in this build.",
+                topic_name, format
             )));
         }
 
         aura_log!(info, "Creating publisher for topic: '{}'", topic_name);
 
-        // In this sketch, there's no complex registration with a bus here,
-        // as the MESSAGE_BUS is global and subscribers register themselves.
-        // A real publisher would "advertise" itself.
+        // The publisher "advertises" itself with a weak handle, for graph introspection.
+        // Transient-local publishers are also counted, so the latched message can be
+        // released when the last one goes away. Typed publishers claim the topic's
+        // message type.
+        let handle = Arc::new(PublicationHandle::default());
+        {
+            let mut bus_guard = context.bus.lock(topic_name);
+            let topic = bus_guard.entry(topic_name.to_string()).or_default();
+            if let Some(type_name) = message_type {
+                topic.claim_type(topic_name, type_name)?;
+            }
+            if qos.durability == Durability::TransientLocal {
+                topic.latching_publishers += 1;
+            }
+            topic.publishers.retain(|publisher| publisher.strong_count() > 0);
+            topic.publishers.push(Arc::downgrade(&handle));
+        }
 
         Ok(Self {
             topic_name: topic_name.to_string(),
+            context,
+            qos,
+            message_type,
+            handle,
+            next_seq: AtomicU64::new(0),
+            rate_limiter: Mutex::new(RateLimiter::default()),
+            no_subscriber_warning: Mutex::new(NoSubscriberWarning::default()),
             // _message_type: PhantomData, // For generic version
         })
     }
@@ -65,82 +248,1102 @@ impl Publisher {
     ///
     /// # Arguments
     /// * `data`: The data to publish. For this sketch, it's a `String`.
-    ///           In a real system, this would be `message: M` where `M: AuraMessageTrait`.
+    ///   In a real system, this would be `message: M` where `M: AuraMessageTrait`.
+    ///
+    /// The message is stamped with the current time and this publisher's next sequence
+    /// number (0, 1, 2, ...).
     ///
     /// # Returns
     /// `Ok(())` if the message was successfully handed off for publication,
-    /// or an `AuraError` if publication fails (e.g., communication error, serialization error).
+    /// or an `AuraError` if publication fails (e.g., communication error, serialization error,
+    /// or the publisher was stopped by its owning node).
     pub fn publish(&self, data: String /* In future: message: M */) -> Result<()> {
-        aura_log!(trace, "Attempting to publish to topic '{}': \"{}\"", self.topic_name, data);
+        self.publish_annotated(data, |_| ())
+    }
 
-        // Construct the AuraMessage (in future, this would involve serialization of M)
-        let aura_message = AuraMessage {
-            topic: self.topic_name.clone(),
-            data, // If M was generic: data: serialize(message)?
+    /// Publishes a request and blocks until it is answered, for simple request-reply
+    /// (e.g., ping/pong) ahead of full services.
+    ///
+    /// A temporary subscriber is created on a fresh reply topic under
+    /// [`REPLY_TOPIC_PREFIX`](super::request::REPLY_TOPIC_PREFIX), and the request carries
+    /// its correlation id and reply topic. A responder answers with
+    /// [`comm::reply`](super::reply); replies with any other correlation id are ignored.
+    ///
+    /// # Returns
+    /// The reply, `AuraError::TimeoutError` if none arrived within `timeout`, or the same
+    /// errors as `publish`.
+    pub fn publish_and_wait(&self, data: String, timeout: Duration) -> Result<AuraMessage> {
+        let deadline = Instant::now() + timeout;
+        let (correlation_id, reply_to) = request::next_request();
+        let replies = Subscriber::new_in_context(Arc::clone(&self.context), &reply_to, QosProfile::default())?;
+        self.publish_correlated(data, correlation_id, Some(reply_to.clone()))?;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let reply = match replies.recv_timeout(remaining) {
+                Err(AuraError::TimeoutError(_)) => {
+                    return Err(AuraError::TimeoutError(format!(
+                        "No reply to request #{} on '{}' within {:?}.",
+                        correlation_id, self.topic_name, timeout
+                    )));
+                }
+                other => other?,
+            };
+            if reply.correlation_id == Some(correlation_id) {
+                return Ok(reply);
+            }
+            aura_log!(debug, "Ignoring reply on '{}' with unexpected correlation id {:?}", reply_to, reply.correlation_id);
+        }
+    }
+
+    /// Publishes `data` tagged with a request's correlation id and, for a request, the
+    /// topic to reply on. Used by `publish_and_wait` and `comm::reply`.
+    pub(crate) fn publish_correlated(&self, data: String, correlation_id: u64, reply_to: Option<String>) -> Result<()> {
+        self.publish_annotated(data, |message| {
+            message.correlation_id = Some(correlation_id);
+            message.reply_to = reply_to;
+        })
+    }
+
+    /// Does the work of `publish`, letting `annotate` fill in header fields before the
+    /// publish interceptors see the message.
+    fn publish_annotated(&self, data: String, annotate: impl FnOnce(&mut AuraMessage)) -> Result<()> {
+        self.ensure_running()?;
+        if !self.wait_for_slot() {
+            aura_log!(trace, "Dropping message on '{}': over the publisher's rate limit", self.topic_name);
+            return Ok(());
+        }
+        aura_log!(trace, "Attempting to publish to topic '{}': \"{}\"", self.topic_name, data);
+        let Some(aura_message) = self.stamp(data, annotate)? else {
+            return Ok(());
         };
 
-        // Lock the global message bus to get access to the subscriber list.
-        // This is a major simplification and a performance bottleneck.
-        let bus_guard = MESSAGE_BUS.lock().map_err(|e| {
-            AuraError::CommunicationError(format!("Failed to lock message bus for publishing: {}", e))
+        let latch = self.qos.durability == Durability::TransientLocal;
+        let recipients = publish_local(&self.context.bus, &[aura_message], latch, self.qos.publish_timeout)?;
+        self.check_recipients(recipients);
+        Ok(())
+    }
+
+    /// Publishes `value`, converted with [`IntoAuraMessage`] (with the `json` feature, any
+    /// serializable type, encoded as JSON).
+    ///
+    /// # Returns
+    /// `Ok(())`, `AuraError::SerializationError` if the value cannot be converted, or the
+    /// same errors as `publish`.
+    pub fn publish_as<T: IntoAuraMessage>(&self, value: T) -> Result<()> {
+        let message = value.into_aura_message(&self.topic_name).inspect_err(|_| {
+            dead_letter::report(vec![DeadLetter {
+                topic: self.topic_name.clone(),
+                message: None,
+                reason: DeadLetterReason::SerializationFailed,
+            }]);
         })?;
+        self.publish(message.data)
+    }
 
-        if let Some(subscribers_senders) = bus_guard.get(&self.topic_name) {
-            if subscribers_senders.is_empty() {
-                aura_log!(trace, "No active subscribers for topic '{}' at the moment.", self.topic_name);
-                return Ok(()); // Not an error, just no one listening right now
-            }
+    /// Publishes `value` encoded in this publisher's wire format: the QoS
+    /// `serialize_format`, or else the context's; see [`serde_format`](super::serde_format).
+    ///
+    /// # Returns
+    /// `Ok(())`, `AuraError::SerializationError` if the value cannot be encoded, or the
+    /// same errors as `publish`.
+    #[cfg(feature = "serde")]
+    pub fn publish_encoded<T: serde::Serialize>(&self, value: &T) -> Result<()> {
+        let format = self.qos.serialize_format.unwrap_or_else(|| self.context.serialize_format());
+        let data = format.encode_payload(value).inspect_err(|_| {
+            dead_letter::report(vec![DeadLetter {
+                topic: self.topic_name.clone(),
+                message: None,
+                reason: DeadLetterReason::SerializationFailed,
+            }]);
+        })?;
+        self.publish(data)
+    }
 
-            let mut disconnected_subscriber_indices = Vec::new();
-            for (index, sender_channel) in subscribers_senders.iter().enumerate() {
-                // Clone the message for each subscriber (mpsc::Sender sends by value).
-                // In a real system with zero-copy or shared memory, this would be different.
-                match sender_channel.send(aura_message.clone()) {
-                    Ok(_) => {
-                        aura_log!(trace, "Successfully sent message to a subscriber for topic '{}'", self.topic_name);
-                    }
-                    Err(mpsc::SendError(sent_message)) => {
-                        // This error means the receiving end of the channel (subscriber) has been dropped.
-                        aura_log!(warn,
-                            "Failed to send message to a subscriber for topic '{}' (receiver disconnected). Message: {:?}",
-                            self.topic_name, sent_message
-                        );
-                        // Mark this subscriber for potential cleanup.
-                        // Actual cleanup is complex with just `Vec<mpsc::Sender>`
-                        // as senders are not easily identifiable or removable without more info.
-                        // For this sketch, we'll just log. A real system would prune disconnected subscribers.
-                        disconnected_subscriber_indices.push(index);
-                    }
-                }
-            }
-            // Conceptual: if disconnected_subscriber_indices is not empty, the bus manager
-            // would later try to clean those up.
-        } else {
-            aura_log!(trace, "No subscriber list found for topic '{}' (no one has ever subscribed).", self.topic_name);
+    /// Publishes a parameter value, tagged with its type so that
+    /// [`Subscriber::recv_value`](super::Subscriber::recv_value) gets back the same
+    /// variant; see [`ParamValue::to_message_data`] for the encoding.
+    ///
+    /// # Returns
+    /// `Ok(())`, or the same errors as `publish`.
+    pub fn publish_value(&self, value: ParamValue) -> Result<()> {
+        self.publish(value.to_message_data())
+    }
+
+    /// Publishes several messages, in order, with a single pass over the message bus.
+    ///
+    /// Equivalent to calling `publish` for each item, but the bus lock is taken once for
+    /// the whole batch. Every message still gets its own sequence number, but a rate
+    /// limit counts the whole batch as one publish. A subscriber
+    /// that turns out to be disconnected part-way through is pruned and skipped for the
+    /// rest of the batch; the other subscribers receive every message.
+    ///
+    /// # Returns
+    /// `Ok(())` if the batch was handed off, or the same errors as `publish`.
+    pub fn publish_batch(&self, data: Vec<String>) -> Result<()> {
+        self.ensure_running()?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        if !self.wait_for_slot() {
+            aura_log!(trace, "Dropping batch on '{}': over the publisher's rate limit", self.topic_name);
+            return Ok(());
+        }
+        aura_log!(trace, "Attempting to publish a batch of {} message(s) to topic '{}'", data.len(), self.topic_name);
+        let mut messages: Vec<Arc<AuraMessage>> = Vec::with_capacity(data.len());
+        for data in data {
+            messages.extend(self.stamp(data, |_| ())?);
+        }
+        if messages.is_empty() {
+            return Ok(());
         }
 
+        let latch = self.qos.durability == Durability::TransientLocal;
+        let recipients = publish_local(&self.context.bus, &messages, latch, self.qos.publish_timeout)?;
+        self.check_recipients(recipients);
+        Ok(())
+    }
+
+    fn ensure_running(&self) -> Result<()> {
+        if self.handle.stopped.load(Ordering::SeqCst) {
+            return Err(AuraError::CommunicationError(format!(
+                "Publisher for topic '{}' has been stopped.",
+                self.topic_name
+            )));
+        }
         Ok(())
     }
 
+    /// Limits this publisher to `hz` publishes per second (builder form of `set_rate_limit`).
+    ///
+    /// Example:
+    /// ```ignore
+    /// let publisher = node.create_publisher("chatter")?.with_rate_limit(10.0);
+    /// loop {
+    ///     publisher.publish(read_sensor())?; // Returns at most every 100ms.
+    /// }
+    /// ```
+    pub fn with_rate_limit(self, hz: f64) -> Self {
+        self.set_rate_limit(hz);
+        self
+    }
+
+    /// Sets what happens to a publish that comes before the next slot (default: block).
+    pub fn with_rate_limit_policy(self, policy: RateLimitPolicy) -> Self {
+        self.rate_limiter.lock().unwrap_or_else(PoisonError::into_inner).policy = policy;
+        self
+    }
+
+    /// Changes the rate limit at runtime; a rate of zero or less (or `NaN`) disables it.
+    ///
+    /// Publishes are spaced at least `1 / hz` seconds apart: one that comes sooner
+    /// blocks until its slot, or is dropped under `RateLimitPolicy::Drop`. Concurrent
+    /// callers are queued into consecutive slots.
+    pub fn set_rate_limit(&self, hz: f64) {
+        let interval = if hz > 0.0 { Duration::try_from_secs_f64(1.0 / hz).ok() } else { None };
+        let mut limiter = self.rate_limiter.lock().unwrap_or_else(PoisonError::into_inner);
+        limiter.interval = interval;
+        limiter.next_slot = None;
+    }
+
+    /// Returns the rate limit in publishes per second, or `None` if publishing is unlimited.
+    pub fn rate_limit(&self) -> Option<f64> {
+        let limiter = self.rate_limiter.lock().unwrap_or_else(PoisonError::into_inner);
+        limiter.interval.map(|interval| 1.0 / interval.as_secs_f64())
+    }
+
+    /// Enables or disables warning about publishes nobody receives (disabled by default).
+    ///
+    /// While enabled, a publish that reaches no subscriber logs a warning, at most once
+    /// per [`set_no_subscriber_warning_interval`](Self::set_no_subscriber_warning_interval),
+    /// until the first publish that does reach one. A topic that never gets a subscriber
+    /// is usually a misspelled name; one whose subscribers come and go is not reported
+    /// again once it has had one.
+    pub fn warn_on_no_subscribers(&self, enabled: bool) {
+        self.no_subscriber_warning.lock().unwrap_or_else(PoisonError::into_inner).enabled = enabled;
+    }
+
+    /// Sets the minimum time between two no-subscriber warnings
+    /// (default [`DEFAULT_NO_SUBSCRIBER_WARNING_INTERVAL`]).
+    pub fn set_no_subscriber_warning_interval(&self, interval: Duration) {
+        self.no_subscriber_warning.lock().unwrap_or_else(PoisonError::into_inner).interval = interval;
+    }
+
+    /// Records whether a publish reached anyone and warns as set up by `warn_on_no_subscribers`.
+    fn check_recipients(&self, recipients: usize) {
+        let mut warning = self.no_subscriber_warning.lock().unwrap_or_else(PoisonError::into_inner);
+        if recipients > 0 {
+            warning.seen_subscriber = true;
+            return;
+        }
+        if !warning.enabled || warning.seen_subscriber {
+            return;
+        }
+        let now = Instant::now();
+        if warning.last_warned.is_some_and(|last| now.duration_since(last) < warning.interval) {
+            return;
+        }
+        warning.last_warned = Some(now);
+        drop(warning);
+        aura_log!(warn,
+            "Publishing on '{}', which has had no subscriber since this publisher was created. Is the topic name right?",
+            self.topic_name
+        );
+    }
+
+    /// Waits for the next rate limit slot. Returns `false` if the publish should be dropped.
+    fn wait_for_slot(&self) -> bool {
+        // Only the slot is claimed under the lock; the wait happens outside it.
+        let claimed = self.rate_limiter.lock().unwrap_or_else(PoisonError::into_inner).claim();
+        match claimed {
+            Ok(Some(slot)) => {
+                thread::sleep(slot.saturating_duration_since(Instant::now()));
+                true
+            }
+            Ok(None) => true,
+            Err(()) => false,
+        }
+    }
+
+    /// Builds the next message, compressing its payload, runs the publish interceptors,
+    /// shows it to taps and forwards it to remote peers.
+    ///
+    /// # Returns
+    /// The message, `None` if an interceptor dropped it, or
+    /// `AuraError::SerializationError` if compression fails.
+    fn stamp(&self, data: String, annotate: impl FnOnce(&mut AuraMessage)) -> Result<Option<Arc<AuraMessage>>> {
+        // Construct the AuraMessage (in future, this would involve serialization of M).
+        let mut aura_message = AuraMessage {
+            topic: self.topic_name.clone(),
+            data: self.qos.compression.compress(data)?, // If M was generic: data: serialize(message)?
+            seq: self.next_seq.fetch_add(1, Ordering::Relaxed),
+            stamp_ns: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as u64,
+            sender_node: self.handle.node.get().cloned(),
+            correlation_id: None,
+            reply_to: None,
+        };
+        annotate(&mut aura_message);
+        if !middleware::publish(&mut aura_message) {
+            return Ok(None);
+        }
+        stats::record_publish(&self.topic_name);
+        // It is shared by all subscribers, so fan-out only clones the `Arc`.
+        let aura_message = Arc::new(aura_message);
+        tap::notify(&self.context, &aura_message);
+        // Remote peers only exchange the global context's messages.
+        #[cfg(feature = "tcp")]
+        if self.context.is_global() {
+            super::transport::forward(&aura_message, None);
+        }
+
+        Ok(Some(aura_message))
+    }
+
     /// Returns the topic name this publisher is associated with.
     pub fn topic_name(&self) -> &str {
         &self.topic_name
     }
 
+    /// Returns the context this publisher publishes in.
+    pub fn context(&self) -> &Arc<Context> {
+        &self.context
+    }
+
+    /// Returns the QoS profile this publisher was created with.
+    pub fn qos(&self) -> &QosProfile {
+        &self.qos
+    }
+
+    /// Returns the message type declared with `new_typed`, or `None` for an untyped publisher.
+    pub fn message_type(&self) -> Option<&'static str> {
+        self.message_type
+    }
+
+    /// Records the node that created this publisher, for graph introspection.
+    pub(crate) fn set_owner(&self, node: &str) {
+        let _ = self.handle.node.set(node.to_string());
+    }
+
+    /// Returns a handle the owning `Node` can use to stop this publisher.
+    pub(crate) fn stop_handle(&self) -> Weak<dyn Stoppable> {
+        let handle: Weak<PublicationHandle> = Arc::downgrade(&self.handle);
+        handle
+    }
+
+    /// Returns the number of live subscribers currently registered on this publisher's
+    /// topic, including prefix subscribers whose prefix covers it.
+    ///
+    /// Registrations belonging to dropped subscribers are pruned from the bus before
+    /// counting, so the result reflects only subscribers that can still receive messages.
+    pub fn get_num_subscribers(&self) -> Result<usize> {
+        let mut bus_guard = self.context.bus.lock(&self.topic_name);
+
+        let exact = match bus_guard.get_mut(&self.topic_name) {
+            Some(topic) => {
+                prune_disconnected(&mut topic.subscribers);
+                topic.subscribers.len()
+            }
+            None => 0,
+        };
+        drop(bus_guard);
+
+        let mut prefixes = self.context.bus.lock_prefixes();
+        let by_prefix: usize = prefixes
+            .iter_mut()
+            .filter(|(prefix, _)| prefix_matches(prefix, &self.topic_name))
+            .map(|(_, entries)| {
+                prune_disconnected(entries);
+                entries.len()
+            })
+            .sum();
+        Ok(exact + by_prefix)
+    }
+
+    /// Blocks until at least `num` live subscribers exist on this publisher's topic.
+    ///
+    /// Useful in tests and at startup, where messages published before subscribers
+    /// have registered would otherwise be lost. The count is re-checked at a short
+    /// fixed interval (dropped subscribers are not counted) until it reaches `num`
+    /// or `timeout` elapses.
+    ///
+    /// Note that with the current in-process bus this only sees subscribers created
+    /// in the same process.
+    ///
+    /// # Returns
+    /// `Ok(())` once enough subscribers are present, or `AuraError::TimeoutError`
+    /// if the timeout elapses first.
+    pub fn wait_for_subscribers(&self, num: usize, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let current = self.get_num_subscribers()?;
+            if current >= num {
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(AuraError::TimeoutError(format!(
+                    "Timed out after {:?} waiting for {} subscriber(s) on topic '{}' (found {}).",
+                    timeout, num, self.topic_name, current
+                )));
+            }
+            thread::sleep(SUBSCRIBER_POLL_INTERVAL.min(deadline - now));
+        }
+    }
+
     // --- Future Enhancements ---
-    // - `fn wait_for_subscribers(&self, num_subscribers: usize, timeout: Duration) -> Result<()>`
-    // - `fn get_num_subscribers(&self) -> Result<usize>`
     // - Methods related to QoS settings.
     // - Lifecycle methods if the publisher itself has a state.
 }
 
-// `Drop` trait is not strictly necessary for this simplified Publisher,
-// as it doesn't hold resources that need explicit cleanup (like network sockets
-// or threads). If it did, a `Drop` impl would be important.
-// For example, it might unregister itself from AuraComm.
-// impl Drop for Publisher {
-//     fn drop(&mut self) {
-//         aura_log!(info, "Dropping publisher for topic: '{}'. Unregistering...", self.topic_name);
-//         // AuraComm::unregister_publisher(&self.topic_name, self.id);
-//     }
-// }
\ No newline at end of file
+impl Drop for Publisher {
+    /// Releases this publisher's hold on the topic's latched message and message type, if any.
+    fn drop(&mut self) {
+        let latching = self.qos.durability == Durability::TransientLocal;
+        if !latching && self.message_type.is_none() {
+            return;
+        }
+        if let Some(topic) = self.context.bus.lock(&self.topic_name).get_mut(&self.topic_name) {
+            if latching {
+                topic.latching_publishers = topic.latching_publishers.saturating_sub(1);
+                if topic.latching_publishers == 0 {
+                    topic.latched = None;
+                }
+            }
+            if self.message_type.is_some() {
+                topic.release_type();
+            }
+        }
+    }
+}
+
+/// Hands `messages`, all on the same topic, to that topic's subscribers on `bus` in order.
+///
+/// With `latch`, the last message also becomes the topic's latched message. Full
+/// reliable queues are waited on for up to `publish_timeout`. Messages that are lost
+/// go to the dead-letter handler, if one is installed.
+///
+/// # Returns
+/// The number of live subscribers (exact and prefix) the messages were offered to.
+pub(crate) fn publish_local(
+    bus: &MessageBus,
+    messages: &[Arc<AuraMessage>],
+    latch: bool,
+    publish_timeout: Duration,
+) -> Result<usize> {
+    let Some(last) = messages.last() else {
+        return Ok(0);
+    };
+    let topic_name = &last.topic;
+
+    // Lock the message bus shard to get access to the subscriber list.
+    // This is a major simplification and a performance bottleneck.
+    let mut bus_guard = bus.lock(topic_name);
+
+    let capture = dead_letter::is_installed();
+    let mut delivery = Delivery::new(capture);
+    let mut recipients = 0;
+    // Whether the topic ever had subscribers, to tell "all subscribers gone" from
+    // "never had any" even after the dead registrations were pruned.
+    let mut had_subscribers = false;
+    match bus_guard.get_mut(topic_name) {
+        Some(topic) => {
+            if latch {
+                topic.latched = Some(Arc::clone(last));
+            }
+            // Drop registrations of subscribers that have gone away before fanning out.
+            had_subscribers |= topic.had_subscribers;
+            prune_disconnected(&mut topic.subscribers);
+            recipients += topic.subscribers.len();
+            delivery.merge(deliver(&mut topic.subscribers, messages, capture));
+        }
+        None => {
+            aura_log!(trace, "No subscriber list found for topic '{}' (no one has ever subscribed).", topic_name);
+        }
+    }
+    drop(bus_guard);
+
+    // Prefix subscribers are registered apart from the topics. Each subscriber is
+    // either exact or prefix and has a single prefix, so none gets a message twice.
+    {
+        let mut prefixes = bus.lock_prefixes();
+        for (prefix, entries) in prefixes.iter_mut() {
+            if prefix_matches(prefix, topic_name) {
+                // Prefix lists are kept once created, even when emptied.
+                had_subscribers = true;
+                prune_disconnected(entries);
+                recipients += entries.len();
+                delivery.merge(deliver(entries, messages, capture));
+            }
+        }
+    }
+
+    // Log and wait only after releasing the bus locks.
+    if recipients == 0 {
+        aura_log!(trace, "No active subscribers for topic '{}' at the moment.", topic_name);
+    }
+    if delivery.disconnected > 0 {
+        aura_log!(warn,
+            "Failed to send to {} subscriber(s) for topic '{}' (receiver disconnected).",
+            delivery.disconnected, topic_name
+        );
+    }
+    if delivery.dropped > 0 {
+        aura_log!(debug, "Dropped {} message(s) for subscribers on '{}' (queue full).", delivery.dropped, topic_name);
+    }
+
+    let deadline = Instant::now() + publish_timeout;
+    let mut timed_out = 0;
+    for (sender, remaining) in &delivery.pending {
+        let unsent = send_before(sender, remaining, deadline);
+        if !unsent.is_empty() {
+            timed_out += 1;
+            if capture {
+                delivery.lost.extend_from_slice(unsent);
+            }
+        }
+    }
+    if capture {
+        let mut letters = dead_letter::letters(&delivery.lost, DeadLetterReason::QueueOverflow);
+        if had_subscribers && delivery.disconnected == recipients {
+            letters.extend(dead_letter::letters(messages, DeadLetterReason::AllSubscribersDisconnected));
+        }
+        dead_letter::report(letters);
+    }
+    if timed_out > 0 {
+        return Err(AuraError::TimeoutError(format!(
+            "Timed out after {:?} waiting for queue space on {} blocking subscriber(s) of topic '{}'.",
+            publish_timeout, timed_out, topic_name
+        )));
+    }
+    if delivery.overflowed > 0 {
+        return Err(AuraError::CommunicationError(format!(
+            "Backlog overflow: {} message(s) for subscribers of topic '{}' were refused because they reached their max_backlog.",
+            delivery.overflowed, topic_name
+        )));
+    }
+    if delivery.refused > 0 {
+        return Err(AuraError::CommunicationError(format!(
+            "Queue full: {} message(s) for subscribers of topic '{}' were refused (OverflowPolicy::Error).",
+            delivery.refused, topic_name
+        )));
+    }
+
+    Ok(recipients)
+}
+
+/// Outcome of fanning messages out under the bus lock.
+#[derive(Debug, Default)]
+struct Delivery {
+    /// Registrations removed because their receiver was gone.
+    disconnected: usize,
+    /// Messages discarded (new or evicted old ones) because a subscriber's queue was full.
+    dropped: usize,
+    /// Messages refused by subscribers that reached their `max_backlog`.
+    overflowed: usize,
+    /// Messages refused by full subscribers with `OverflowPolicy::Error`.
+    refused: usize,
+    /// Blocking subscribers whose queue was full, with the messages they still need in
+    /// order. The publisher waits on these after releasing the bus lock.
+    pending: Vec<(MessageSender, Vec<Arc<AuraMessage>>)>,
+    /// Whether lost messages are kept in `lost` for the dead-letter handler.
+    capture: bool,
+    /// Messages discarded, evicted or refused, once per affected subscriber.
+    lost: Vec<Arc<AuraMessage>>,
+}
+
+impl Delivery {
+    fn new(capture: bool) -> Self {
+        Self { capture, ..Default::default() }
+    }
+
+    /// Keeps `message` for the dead-letter handler, if one is installed.
+    fn lose(&mut self, message: Arc<AuraMessage>) {
+        if self.capture {
+            self.lost.push(message);
+        }
+    }
+
+    /// Adds the outcome of another fan-out to this one.
+    fn merge(&mut self, other: Delivery) {
+        self.disconnected += other.disconnected;
+        self.dropped += other.dropped;
+        self.overflowed += other.overflowed;
+        self.refused += other.refused;
+        self.pending.extend(other.pending);
+        self.lost.extend(other.lost);
+    }
+}
+
+/// Fans `messages` out, in order, to the registrations of one topic without blocking.
+///
+/// Every subscriber outside a work-queue group gets every message. Each queue group gets
+/// each message once, delivered to the first live member in list order that has room;
+/// that member is then moved to the end of the list, which makes group delivery
+/// round-robin; a message no member has room for is lost. Entries whose receiver turns
+/// out to be gone are removed and get none of the remaining messages.
+fn deliver(entries: &mut Vec<SubscriberEntry>, messages: &[Arc<AuraMessage>], capture: bool) -> Delivery {
+    let mut delivery = Delivery::new(capture);
+    let mut disconnected_ids = Vec::new();
+
+    for entry in entries.iter().filter(|entry| entry.queue_group.is_none()) {
+        for (index, message) in messages.iter().enumerate() {
+            if entry.sender.is_backlog_full() {
+                delivery.overflowed += 1;
+                delivery.lose(Arc::clone(message));
+                continue;
+            }
+            // Every subscriber receives a reference to the same allocation.
+            match entry.sender.try_send(Arc::clone(message)) {
+                Ok(()) => {}
+                Err(TrySendError::Full(message)) => match entry.overflow {
+                    // Queue this and every later message, so they arrive in order.
+                    OverflowPolicy::Block => {
+                        delivery.pending.push((entry.sender.clone(), messages[index..].to_vec()));
+                        break;
+                    }
+                    OverflowPolicy::DropOldest => match entry.sender.send_evicting(message) {
+                        Ok(Some(evicted)) => {
+                            delivery.dropped += 1;
+                            delivery.lose(evicted);
+                        }
+                        Ok(None) => {}
+                        Err(_) => {
+                            disconnected_ids.push(entry.id);
+                            break;
+                        }
+                    },
+                    OverflowPolicy::DropNewest => {
+                        delivery.dropped += 1;
+                        delivery.lose(message);
+                    }
+                    OverflowPolicy::Error => {
+                        delivery.refused += 1;
+                        delivery.lose(message);
+                    }
+                },
+                // The receiving end was dropped after the pruning pass; mark it for removal.
+                Err(TrySendError::Disconnected(_)) => {
+                    disconnected_ids.push(entry.id);
+                    break;
+                }
+            }
+        }
+    }
+
+    for message in messages {
+        let mut served_groups: Vec<String> = Vec::new();
+        let mut full_groups: Vec<String> = Vec::new();
+        let mut rotated_ids = Vec::new();
+        for entry in entries.iter() {
+            let Some(group) = entry.queue_group.as_deref() else {
+                continue;
+            };
+            if served_groups.iter().any(|served| served == group) || disconnected_ids.contains(&entry.id) {
+                continue;
+            }
+            if entry.sender.is_backlog_full() {
+                full_groups.push(group.to_string());
+                continue;
+            }
+            match entry.sender.try_send(Arc::clone(message)) {
+                Ok(()) => {
+                    served_groups.push(group.to_string());
+                    rotated_ids.push(entry.id);
+                }
+                // Try the next member of the group instead.
+                Err(TrySendError::Full(_)) => full_groups.push(group.to_string()),
+                Err(TrySendError::Disconnected(_)) => disconnected_ids.push(entry.id),
+            }
+        }
+        // A group's members need not be adjacent, so its name can be in the list more
+        // than once; the message is still lost only once per group.
+        full_groups.retain(|group| !served_groups.contains(group));
+        full_groups.sort();
+        full_groups.dedup();
+        for _ in &full_groups {
+            delivery.dropped += 1;
+            delivery.lose(Arc::clone(message));
+        }
+        for id in rotated_ids {
+            if let Some(position) = entries.iter().position(|entry| entry.id == id) {
+                let served = entries.remove(position);
+                entries.push(served);
+            }
+        }
+    }
+
+    entries.retain(|entry| !disconnected_ids.contains(&entry.id));
+    delivery.disconnected = disconnected_ids.len();
+    delivery
+}
+
+/// Sends `messages` in order to a full bounded queue, sleeping whenever the queue is
+/// full until the subscriber takes a message, until they are all in or `deadline` passes.
+///
+/// # Returns
+/// The messages still unsent when the deadline passed, or an empty slice. A receiver
+/// that disconnects while waiting counts as done.
+fn send_before<'a>(sender: &MessageSender, messages: &'a [Arc<AuraMessage>], deadline: Instant) -> &'a [Arc<AuraMessage>] {
+    for (index, message) in messages.iter().enumerate() {
+        match sender.send_before(Arc::clone(message), deadline) {
+            Ok(()) => {}
+            Err(TrySendError::Disconnected(_)) => return &[],
+            Err(TrySendError::Full(_)) => return &messages[index..],
+        }
+    }
+    &[]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::qos::Reliability;
+    use crate::MessageQueue;
+    use std::sync::Once;
+
+    lazy_static::lazy_static! {
+        static ref DEAD_LETTERS: Mutex<Vec<DeadLetter>> = Mutex::new(Vec::new());
+    }
+
+    /// Returns the dead letters reported so far for `topic`. The handler is global, so
+    /// it is installed once and collects for every test.
+    fn dead_letters(topic: &str) -> Vec<DeadLetter> {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            dead_letter::set_dead_letter_handler(Arc::new(|letter| {
+                DEAD_LETTERS.lock().unwrap_or_else(PoisonError::into_inner).push(letter);
+            }));
+        });
+        let letters = DEAD_LETTERS.lock().unwrap_or_else(PoisonError::into_inner);
+        letters.iter().filter(|letter| letter.topic == topic).cloned().collect()
+    }
+
+    #[test]
+    fn subscriber_count_follows_live_subscribers() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/count", QosProfile::default()).unwrap();
+        let first = Subscriber::new_in_context(Arc::clone(&context), "/count", QosProfile::default()).unwrap();
+        let _second = Subscriber::new_in_context(Arc::clone(&context), "/count", QosProfile::default()).unwrap();
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 2);
+
+        drop(first);
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 1);
+    }
+
+    #[test]
+    fn messages_carry_sequence_numbers_and_stamps() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/seq", QosProfile::default()).unwrap();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/seq", QosProfile::default()).unwrap();
+        let before = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_nanos() as u64;
+        for data in ["a", "b", "c"] {
+            publisher.publish(data.to_string()).unwrap();
+        }
+
+        let received = subscriber.drain();
+        let seqs: Vec<u64> = received.iter().map(|message| message.seq).collect();
+        assert_eq!(seqs, [0, 1, 2]);
+        assert!(received.iter().all(|message| message.stamp_ns >= before));
+    }
+
+    #[test]
+    fn rate_limit_spaces_publishes() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/limited", QosProfile::default())
+            .unwrap()
+            .with_rate_limit(100.0);
+        let started = Instant::now();
+        for value in 0..10 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        // The first publish goes out at once, the other nine 10 ms apart.
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(85), "10 publishes at 100 Hz took {:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(250), "10 publishes at 100 Hz took {:?}", elapsed);
+    }
+
+    #[test]
+    fn rate_limit_drop_policy_discards_early_publishes() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/limited/drop", QosProfile::default())
+            .unwrap()
+            .with_rate_limit(1.0)
+            .with_rate_limit_policy(RateLimitPolicy::Drop);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/limited/drop", QosProfile::default()).unwrap();
+        for value in 0..10 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        assert_eq!(subscriber.drain().len(), 1);
+
+        publisher.set_rate_limit(0.0);
+        assert_eq!(publisher.rate_limit(), None);
+        publisher.publish("free".to_string()).unwrap();
+        assert_eq!(subscriber.drain().len(), 1);
+    }
+
+    #[test]
+    fn drop_newest_overflow_is_dead_lettered() {
+        dead_letters("/overflow");
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/overflow", QosProfile::default()).unwrap();
+        let qos = QosProfile::default().depth(2).overflow(OverflowPolicy::DropNewest);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/overflow", qos).unwrap();
+        for value in 0..5 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+
+        let kept: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["0", "1"]);
+        let letters = dead_letters("/overflow");
+        let dropped: Vec<&str> = letters
+            .iter()
+            .map(|letter| {
+                assert_eq!(letter.reason, DeadLetterReason::QueueOverflow);
+                letter.message.as_ref().unwrap().data.as_str()
+            })
+            .collect();
+        assert_eq!(dropped, ["2", "3", "4"]);
+    }
+
+    #[test]
+    fn block_overflow_times_out_when_the_queue_stays_full() {
+        let context = Context::new();
+        let qos = QosProfile::default().publish_timeout(Duration::from_millis(50));
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/overflow/block", qos).unwrap();
+        let qos = QosProfile::default().depth(2).overflow(OverflowPolicy::Block);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/overflow/block", qos).unwrap();
+        publisher.publish("0".to_string()).unwrap();
+        publisher.publish("1".to_string()).unwrap();
+
+        let started = Instant::now();
+        match publisher.publish("2".to_string()) {
+            Err(AuraError::TimeoutError(_)) => {}
+            other => panic!("expected a TimeoutError, got {:?}", other),
+        }
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        let kept: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["0", "1"]);
+    }
+
+    #[test]
+    fn drop_oldest_overflow_keeps_the_newest_messages() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/overflow/oldest", QosProfile::default()).unwrap();
+        let qos = QosProfile::default().depth(2).overflow(OverflowPolicy::DropOldest);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/overflow/oldest", qos).unwrap();
+        for value in 0..5 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+
+        let kept: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["3", "4"]);
+    }
+
+    #[test]
+    fn error_overflow_refuses_the_message() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/overflow/error", QosProfile::default()).unwrap();
+        let qos = QosProfile::default().depth(2).overflow(OverflowPolicy::Error);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/overflow/error", qos).unwrap();
+        publisher.publish("0".to_string()).unwrap();
+        publisher.publish("1".to_string()).unwrap();
+
+        match publisher.publish("2".to_string()) {
+            Err(AuraError::CommunicationError(message)) => assert!(message.contains("OverflowPolicy::Error"), "{}", message),
+            other => panic!("expected a CommunicationError, got {:?}", other),
+        }
+        let kept: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["0", "1"]);
+    }
+
+    #[test]
+    fn best_effort_subscriber_misses_messages_when_full() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/reliability/best_effort", QosProfile::default()).unwrap();
+        let qos = QosProfile::default().depth(1).reliability(Reliability::BestEffort);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/reliability/best_effort", qos).unwrap();
+        publisher.publish("kept".to_string()).unwrap();
+        publisher.publish("missed".to_string()).unwrap();
+
+        let kept: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["kept"]);
+    }
+
+    #[test]
+    fn reliable_subscriber_makes_publisher_wait_for_space() {
+        let context = Context::new();
+        let qos = QosProfile::default().publish_timeout(Duration::from_millis(50));
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/reliability/reliable", qos).unwrap();
+        let qos = QosProfile::default().depth(1).reliability(Reliability::Reliable);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/reliability/reliable", qos).unwrap();
+        publisher.publish("first".to_string()).unwrap();
+
+        // Nobody reads: the publish fails after the timeout.
+        assert!(matches!(publisher.publish("second".to_string()), Err(AuraError::TimeoutError(_))));
+
+        // A recv frees the slot while the publisher waits.
+        let qos = QosProfile::default().publish_timeout(Duration::from_secs(2));
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/reliability/reliable", qos).unwrap();
+        let reader = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            let first = subscriber.recv().unwrap();
+            (first, subscriber)
+        });
+        publisher.publish("third".to_string()).unwrap();
+        let (first, subscriber) = reader.join().unwrap();
+        assert_eq!(first.data, "first");
+        assert_eq!(subscriber.recv().unwrap().data, "third");
+    }
+
+    #[test]
+    fn late_subscriber_gets_only_the_latched_message() {
+        let context = Context::new();
+        let qos = QosProfile::default().durability(Durability::TransientLocal);
+        let latching = Publisher::new_in_context(Arc::clone(&context), "/latched/map", qos).unwrap();
+        let volatile = Publisher::new_in_context(Arc::clone(&context), "/latched/scan", QosProfile::default()).unwrap();
+        latching.publish("old map".to_string()).unwrap();
+        latching.publish("map".to_string()).unwrap();
+        volatile.publish("scan".to_string()).unwrap();
+
+        let late = Subscriber::new_in_context(Arc::clone(&context), "/latched/map", QosProfile::default()).unwrap();
+        let kept: Vec<String> = late.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["map"]);
+        let late = Subscriber::new_in_context(Arc::clone(&context), "/latched/scan", QosProfile::default()).unwrap();
+        assert!(late.try_recv().unwrap().is_none());
+
+        // The latched message goes away with the last transient-local publisher.
+        drop(latching);
+        let later = Subscriber::new_in_context(Arc::clone(&context), "/latched/map", QosProfile::default()).unwrap();
+        assert!(later.try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn endpoint_of_another_type_is_rejected() {
+        let context = Context::new();
+        let expected = "Type mismatch on topic '/typed': it carries 'String', not 'Pose'.";
+        let _strings = Publisher::create(Arc::clone(&context), "/typed", QosProfile::default(), Some(String::type_name())).unwrap();
+
+        match Subscriber::register(Arc::clone(&context), "/typed", None, QosProfile::default(), Some("Pose")) {
+            Err(AuraError::CommunicationError(message)) => assert_eq!(message, expected),
+            other => panic!("expected a CommunicationError, got {:?}", other),
+        }
+        match Publisher::create(Arc::clone(&context), "/typed", QosProfile::default(), Some("Pose")) {
+            Err(AuraError::CommunicationError(message)) => assert_eq!(message, expected),
+            other => panic!("expected a CommunicationError, got {:?}", other),
+        }
+        // Untyped endpoints and endpoints of the same type are still accepted.
+        Subscriber::new_in_context(Arc::clone(&context), "/typed", QosProfile::default()).unwrap();
+        Subscriber::register(Arc::clone(&context), "/typed", None, QosProfile::default(), Some(String::type_name())).unwrap();
+    }
+
+    #[test]
+    fn publish_after_subscribers_are_pruned_is_dead_lettered() {
+        dead_letters("/orphaned");
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/orphaned", QosProfile::default()).unwrap();
+        drop(Subscriber::new_in_context(Arc::clone(&context), "/orphaned", QosProfile::default()).unwrap());
+        // The first publish prunes the dead registration; the second finds none left.
+        publisher.publish("first".to_string()).unwrap();
+        publisher.publish("second".to_string()).unwrap();
+
+        let reasons: Vec<DeadLetterReason> = dead_letters("/orphaned").iter().map(|letter| letter.reason).collect();
+        assert_eq!(reasons, [DeadLetterReason::AllSubscribersDisconnected; 2]);
+    }
+
+    #[test]
+    fn queue_group_splits_messages_between_workers() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/jobs", QosProfile::default()).unwrap();
+        let workers: Vec<Subscriber> = (0..2)
+            .map(|_| Subscriber::register(Arc::clone(&context), "/jobs", Some("workers"), QosProfile::default(), None).unwrap())
+            .collect();
+        for job in 0..100 {
+            publisher.publish(job.to_string()).unwrap();
+        }
+
+        let received: Vec<usize> = workers.iter().map(|worker| worker.drain().len()).collect();
+        assert_eq!(received.iter().sum::<usize>(), 100);
+        for count in received {
+            assert!((45..=55).contains(&count), "worker got {} of 100 messages", count);
+        }
+    }
+
+    #[test]
+    fn full_queue_group_loses_message_once() {
+        let entry = |id, group: &str| {
+            let (sender, receiver) = MessageQueue::open(Some(1));
+            sender.try_send(Arc::new(AuraMessage::default())).unwrap();
+            let entry = SubscriberEntry {
+                id,
+                sender,
+                overflow: OverflowPolicy::DropNewest,
+                queue_group: Some(group.to_string()),
+                liveness: Weak::new(),
+            };
+            (entry, receiver)
+        };
+        // Members of group "a" are not adjacent, as round-robin rotation can leave them.
+        let (a1, _a1_queue) = entry(1, "a");
+        let (b1, _b1_queue) = entry(2, "b");
+        let (a2, _a2_queue) = entry(3, "a");
+        let mut entries = vec![a1, b1, a2];
+
+        let message = Arc::new(AuraMessage { topic: "/jobs".to_string(), ..Default::default() });
+        let delivery = deliver(&mut entries, &[message], true);
+        assert_eq!(delivery.dropped, 2);
+        assert_eq!(delivery.lost.len(), 2);
+    }
+
+    #[test]
+    fn no_subscriber_warning_fires_once_and_not_after_a_subscriber() {
+        use crate::node::logger::capture_logs;
+
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/typo", QosProfile::default()).unwrap();
+        publisher.warn_on_no_subscribers(true);
+        let warnings = |lines: Vec<String>| lines.iter().filter(|line| line.contains("has had no subscriber")).count();
+
+        let lines = capture_logs(|| {
+            for _ in 0..5 {
+                publisher.publish("lost".to_string()).unwrap();
+            }
+        });
+        assert_eq!(warnings(lines), 1);
+
+        let listened = Publisher::new_in_context(Arc::clone(&context), "/heard", QosProfile::default()).unwrap();
+        listened.warn_on_no_subscribers(true);
+        listened.set_no_subscriber_warning_interval(Duration::ZERO);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/heard", QosProfile::default()).unwrap();
+        let lines = capture_logs(|| {
+            listened.publish("heard".to_string()).unwrap();
+            drop(subscriber);
+            listened.publish("gone again".to_string()).unwrap();
+        });
+        assert_eq!(warnings(lines), 0);
+    }
+
+    #[test]
+    fn blocked_reliable_publish_wakes_when_a_slot_frees() {
+        let context = Context::new();
+        let qos = QosProfile::default().publish_timeout(Duration::from_secs(10));
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/reliability/wake", qos).unwrap();
+        let qos = QosProfile::default().depth(1).reliability(Reliability::Reliable);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/reliability/wake", qos).unwrap();
+        publisher.publish("0".to_string()).unwrap();
+
+        // A slow consumer frees one slot every 30 ms.
+        let reader = std::thread::spawn(move || {
+            (0..4)
+                .map(|_| {
+                    std::thread::sleep(Duration::from_millis(30));
+                    subscriber.recv().unwrap().data
+                })
+                .collect::<Vec<_>>()
+        });
+        let started = Instant::now();
+        for value in 1..4 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        let elapsed = started.elapsed();
+        assert_eq!(reader.join().unwrap(), ["0", "1", "2", "3"]);
+
+        // Each publish returned once the consumer took a message, not at the timeout.
+        assert!(elapsed >= Duration::from_millis(80), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn batch_reaches_every_subscriber_in_order_with_consecutive_seqs() {
+        let context = Context::new();
+        let subscribers: Vec<Subscriber> = (0..2)
+            .map(|_| Subscriber::new_in_context(Arc::clone(&context), "/batch", QosProfile::default()).unwrap())
+            .collect();
+        let gone = Subscriber::new_in_context(Arc::clone(&context), "/batch", QosProfile::default()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/batch", QosProfile::default()).unwrap();
+        publisher.publish("single".to_string()).unwrap();
+        drop(gone);
+
+        let batch: Vec<String> = (0..5).map(|index| format!("batch {}", index)).collect();
+        publisher.publish_batch(batch.clone()).unwrap();
+        publisher.publish_batch(Vec::new()).unwrap();
+
+        for subscriber in &subscribers {
+            let received = subscriber.drain();
+            assert_eq!(received[0].data, "single");
+            let data: Vec<&str> = received[1..].iter().map(|message| message.data.as_str()).collect();
+            assert_eq!(data, batch);
+            let seqs: Vec<u64> = received.iter().map(|message| message.seq).collect();
+            assert_eq!(seqs, [0, 1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn wait_for_subscribers_times_out_or_sees_a_late_subscriber() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/waiting", QosProfile::default()).unwrap();
+        let started = Instant::now();
+        let result = publisher.wait_for_subscribers(1, Duration::from_millis(50));
+        assert!(matches!(result, Err(AuraError::TimeoutError(_))));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+
+        let late = {
+            let context = Arc::clone(&context);
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(30));
+                Subscriber::new_in_context(context, "/waiting", QosProfile::default()).unwrap()
+            })
+        };
+        publisher.wait_for_subscribers(1, Duration::from_secs(5)).unwrap();
+        let subscriber = late.join().unwrap();
+        publisher.publish("not lost".to_string()).unwrap();
+        assert_eq!(subscriber.try_recv().unwrap().unwrap().data, "not lost");
+
+        // A dropped subscriber is not counted.
+        drop(subscriber);
+        assert!(publisher.wait_for_subscribers(1, Duration::ZERO).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+// aura_os/aura_core/src/comm/qos.rs
+
+//! Quality of Service (QoS) settings for publishers and subscribers.
+//!
+//! Only the settings the in-process bus can honor are defined here; more (history
+//! kinds, deadlines, liveliness) will be added as the transport grows.
+
+use super::compression::Compression;
+use super::serde_format::SerializeFormat;
+use std::time::Duration;
+
+/// Default for [`QosProfile::publish_timeout`].
+pub const DEFAULT_PUBLISH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Whether a topic's messages outlive the moment they are published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Durability {
+    /// Messages go only to subscribers that exist when they are published.
+    #[default]
+    Volatile,
+    /// The most recent message is kept ("latched") and delivered immediately to every
+    /// subscriber that joins later. Suited to maps, static transforms and other
+    /// published-once data. The message is released when the last transient-local
+    /// publisher on the topic is dropped.
+    TransientLocal,
+}
+
+/// What happens when a subscriber's bounded queue is full.
+///
+/// Only meaningful for subscribers with a `depth`; unbounded queues never fill up.
+/// For finer control, see [`OverflowPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Reliability {
+    /// The publisher waits for space, up to its `publish_timeout`
+    /// (`OverflowPolicy::Block`).
+    #[default]
+    Reliable,
+    /// The message is dropped for this subscriber and the publisher carries on
+    /// (`OverflowPolicy::DropNewest`).
+    BestEffort,
+}
+
+/// What a publish does with a message for a subscriber whose bounded queue is full.
+///
+/// Set per subscriber with [`QosProfile::overflow`]; when unset, the subscriber's
+/// `Reliability` picks `Block` or `DropNewest`. Only meaningful with a `depth`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// The publisher waits for space, up to its `publish_timeout`, then fails with
+    /// `AuraError::TimeoutError`.
+    Block,
+    /// The oldest queued message is discarded to make room; suits subscribers that
+    /// only care about the latest state.
+    DropOldest,
+    /// The new message is discarded for this subscriber.
+    DropNewest,
+    /// The new message is discarded and the publish fails right away with
+    /// `AuraError::CommunicationError` (other subscribers still get it).
+    Error,
+}
+
+/// A bundle of QoS settings.
+///
+/// Subscribers use `reliability`, `overflow`, `depth`, `max_backlog` and `deadline`; publishers use
+/// `durability`, `publish_timeout` and `compression`. Both use `serialize_format`.
+///
+/// Example:
+/// ```ignore
+/// let map_pub = Publisher::new_with_qos("/map", QosProfile::default().durability(Durability::TransientLocal))?;
+/// let scans = Subscriber::new_with_qos("/scan", QosProfile::default().depth(5).reliability(Reliability::BestEffort))?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QosProfile {
+    pub durability: Durability,
+    pub reliability: Reliability,
+    /// Overrides what `reliability` does when the queue is full; see [`OverflowPolicy`].
+    pub overflow: Option<OverflowPolicy>,
+    /// Capacity of a subscriber's queue, or `None` (the default) for an unbounded queue
+    /// that keeps every message until it is received.
+    pub depth: Option<usize>,
+    /// Guard against an unbounded queue growing without limit behind a slow consumer:
+    /// once this many messages are waiting, further messages for the subscriber are
+    /// refused and the publish fails with `AuraError::CommunicationError`. Ignored when
+    /// `depth` is set. `None` (the default) means no limit.
+    pub max_backlog: Option<usize>,
+    /// How long a publisher waits on full `Reliable` queues before failing with
+    /// `AuraError::TimeoutError`.
+    pub publish_timeout: Duration,
+    /// Longest expected gap between messages. A subscriber with a deadline can watch for
+    /// gaps with `Subscriber::on_deadline_event`. `None` (the default) means no deadline.
+    pub deadline: Option<Duration>,
+    /// Compression a publisher applies to message payloads; see
+    /// [`compression`](super::compression). Defaults to `Compression::None`.
+    pub compression: Compression,
+    /// Wire format for `publish_encoded`/`recv_decoded`; see
+    /// [`serde_format`](super::serde_format). `None` (the default) uses the context's format.
+    pub serialize_format: Option<SerializeFormat>,
+}
+
+impl Default for QosProfile {
+    fn default() -> Self {
+        Self {
+            durability: Durability::default(),
+            reliability: Reliability::default(),
+            overflow: None,
+            depth: None,
+            max_backlog: None,
+            publish_timeout: DEFAULT_PUBLISH_TIMEOUT,
+            deadline: None,
+            compression: Compression::None,
+            serialize_format: None,
+        }
+    }
+}
+
+impl QosProfile {
+    /// Returns this profile with the given durability.
+    pub fn durability(mut self, durability: Durability) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    /// Returns this profile with the given reliability.
+    pub fn reliability(mut self, reliability: Reliability) -> Self {
+        self.reliability = reliability;
+        self
+    }
+
+    /// Returns this profile with the given overflow policy.
+    pub fn overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Returns the overflow policy in effect: `overflow` if set, else the one implied
+    /// by `reliability`.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow.unwrap_or(match self.reliability {
+            Reliability::Reliable => OverflowPolicy::Block,
+            Reliability::BestEffort => OverflowPolicy::DropNewest,
+        })
+    }
+
+    /// Returns this profile with a bounded queue of `depth` messages.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Returns this profile with an unbounded queue capped at `max_backlog` waiting messages.
+    pub fn max_backlog(mut self, max_backlog: usize) -> Self {
+        self.max_backlog = Some(max_backlog);
+        self
+    }
+
+    /// Returns this profile with the given publish timeout.
+    pub fn publish_timeout(mut self, publish_timeout: Duration) -> Self {
+        self.publish_timeout = publish_timeout;
+        self
+    }
+
+    /// Returns this profile with the given deadline.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Returns this profile with the given payload compression.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns this profile with the given wire format, overriding the context's.
+    pub fn serialize_format(mut self, format: SerializeFormat) -> Self {
+        self.serialize_format = Some(format);
+        self
+    }
+}
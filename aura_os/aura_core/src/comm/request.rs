@@ -0,0 +1,103 @@
+// aura_os/aura_core/src/comm/request.rs
+
+//! Lightweight request-reply over topics, ahead of full services.
+//!
+//! [`Publisher::publish_and_wait`](super::Publisher::publish_and_wait) publishes a
+//! request that carries a correlation id and a private reply topic, then blocks until
+//! a message with the same correlation id arrives there. A responder subscribed to the
+//! request topic answers with [`reply`].
+//!
+//! Example:
+//! ```ignore
+//! // Responder
+//! let requests = Subscriber::new("/ping")?;
+//! let request = requests.recv()?;
+//! aura_core::comm::reply(&request, "pong".to_string())?;
+//!
+//! // Requester
+//! let response = Publisher::new("/ping")?.publish_and_wait("ping".to_string(), Duration::from_secs(1))?;
+//! ```
+
+use super::{Publisher, QosProfile};
+use crate::error::{AuraError, Result};
+use crate::{AuraMessage, Context}; // Import from lib.rs
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Namespace of the per-request reply topics.
+pub const REPLY_TOPIC_PREFIX: &str = "/aura/reply";
+
+/// Source of process-unique correlation ids.
+static NEXT_CORRELATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Returns a fresh correlation id and the reply topic that goes with it. The process id
+/// keeps reply topics apart when requests cross a transport.
+pub(crate) fn next_request() -> (u64, String) {
+    let id = NEXT_CORRELATION_ID.fetch_add(1, Ordering::Relaxed);
+    (id, format!("{}/p{}_{}", REPLY_TOPIC_PREFIX, std::process::id(), id))
+}
+
+/// Answers `request` with `data`, in the global context.
+///
+/// # Returns
+/// `Ok(())` once the reply is published, `AuraError::CommunicationError` if `request`
+/// was not sent with `publish_and_wait`, or the same errors as `Publisher::publish`.
+pub fn reply(request: &AuraMessage, data: String) -> Result<()> {
+    reply_in_context(Context::global(), request, data)
+}
+
+/// Answers `request` with `data` in `context`; see [`reply`].
+pub fn reply_in_context(context: Arc<Context>, request: &AuraMessage, data: String) -> Result<()> {
+    let (Some(correlation_id), Some(reply_to)) = (request.correlation_id, request.reply_to.as_deref()) else {
+        return Err(AuraError::CommunicationError(format!(
+            "Message #{} on '{}' is not a request: it has no correlation id or reply topic.",
+            request.seq, request.topic
+        )));
+    };
+    let publisher = Publisher::new_in_context(context, reply_to, QosProfile::default())?;
+    publisher.publish_correlated(data, correlation_id, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::Subscriber;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn request_is_answered_by_a_replier() {
+        let context = Context::new();
+        let requests = Subscriber::new_in_context(Arc::clone(&context), "/ping", QosProfile::default()).unwrap();
+        let replier = {
+            let context = Arc::clone(&context);
+            thread::spawn(move || {
+                let request = requests.recv_timeout(Duration::from_secs(2)).unwrap();
+                assert!(request.reply_to.as_deref().unwrap().starts_with(REPLY_TOPIC_PREFIX));
+                reply_in_context(context, &request, format!("{} pong", request.data)).unwrap();
+                request.correlation_id
+            })
+        };
+
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/ping", QosProfile::default()).unwrap();
+        let response = publisher.publish_and_wait("ping".to_string(), Duration::from_secs(2)).unwrap();
+        assert_eq!(response.data, "ping pong");
+        assert_eq!(response.correlation_id, replier.join().unwrap());
+    }
+
+    #[test]
+    fn request_without_a_replier_times_out() {
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/unanswered", QosProfile::default()).unwrap();
+        match publisher.publish_and_wait("ping".to_string(), Duration::from_millis(50)) {
+            Err(AuraError::TimeoutError(message)) => assert!(message.contains("/unanswered"), "{}", message),
+            other => panic!("expected a TimeoutError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn plain_message_cannot_be_replied_to() {
+        let message = AuraMessage { topic: "/ping".to_string(), ..AuraMessage::default() };
+        assert!(matches!(reply_in_context(Context::new(), &message, "pong".to_string()), Err(AuraError::CommunicationError(_))));
+    }
+}
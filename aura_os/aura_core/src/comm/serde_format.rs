@@ -0,0 +1,276 @@
+// aura_os/aura_core/src/comm/serde_format.rs
+
+//! Pluggable wire formats for serde-based messages.
+//!
+//! [`Publisher::publish_encoded`](super::Publisher::publish_encoded) and
+//! [`Subscriber::recv_decoded`](super::Subscriber::recv_decoded) turn values into message
+//! payloads with a [`MessageCodec`], chosen through [`SerializeFormat`]: JSON for
+//! debugging, bincode for speed, CBOR for compactness. The format comes from the
+//! endpoint's `QosProfile::serialize_format` or, if that is unset, from its context's
+//! default ([`Context::set_serialize_format`](crate::Context::set_serialize_format)).
+//!
+//! JSON payloads are plain text, the same as `publish_as` produces. Since `data` is a
+//! `String`, binary payloads are a header character naming the format (`'\u{3}'` for
+//! bincode, `'\u{4}'` for CBOR) followed by the bytes in base64, like compressed
+//! payloads. The header lets a subscriber expecting another format report the mismatch
+//! instead of failing with a decoder error. Each codec needs its cargo feature (`json`,
+//! `bincode` or `cbor`).
+//!
+//! Example:
+//! ```ignore
+//! let qos = QosProfile::default().serialize_format(SerializeFormat::Cbor);
+//! Publisher::new_with_qos("/pose", qos.clone())?.publish_encoded(&Pose { x: 1.0, y: 2.0 })?;
+//! let pose: Pose = Subscriber::new_with_qos("/pose", qos)?.recv_decoded()?;
+//! ```
+
+use crate::error::AuraError;
+#[cfg(feature = "serde")]
+use crate::error::Result;
+
+/// Header character of a bincode payload.
+const BINCODE_HEADER: char = '\u{3}';
+/// Header character of a CBOR payload.
+const CBOR_HEADER: char = '\u{4}';
+
+/// Wire format of serde-encoded message payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SerializeFormat {
+    /// Compact JSON text. Needs the `json` feature.
+    #[default]
+    Json,
+    /// bincode: fastest, not self-describing. Needs the `bincode` feature.
+    Bincode,
+    /// CBOR: compact and self-describing. Needs the `cbor` feature.
+    Cbor,
+}
+
+impl SerializeFormat {
+    /// Returns `true` if this build has the format's codec.
+    pub fn is_available(self) -> bool {
+        match self {
+            SerializeFormat::Json => cfg!(feature = "json"),
+            SerializeFormat::Bincode => cfg!(feature = "bincode"),
+            SerializeFormat::Cbor => cfg!(feature = "cbor"),
+        }
+    }
+
+    /// Returns the format `data` was encoded with, judging by its header.
+    pub fn of_payload(data: &str) -> SerializeFormat {
+        match data.chars().next() {
+            Some(BINCODE_HEADER) => SerializeFormat::Bincode,
+            Some(CBOR_HEADER) => SerializeFormat::Cbor,
+            _ => SerializeFormat::Json,
+        }
+    }
+
+    /// The cargo feature providing the format's codec.
+    fn feature(self) -> &'static str {
+        match self {
+            SerializeFormat::Json => "json",
+            SerializeFormat::Bincode => "bincode",
+            SerializeFormat::Cbor => "cbor",
+        }
+    }
+}
+
+/// Converts serde values to and from bytes in one wire format.
+#[cfg(feature = "serde")]
+pub trait MessageCodec {
+    /// Encodes `value`.
+    ///
+    /// # Returns
+    /// The bytes, or `AuraError::SerializationError` if the value cannot be encoded.
+    fn encode<T: serde::Serialize>(value: &T) -> Result<Vec<u8>>;
+
+    /// Decodes a value from `bytes`.
+    ///
+    /// # Returns
+    /// The value, or `AuraError::SerializationError` if the bytes do not decode as `T`.
+    fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T>;
+}
+
+/// [`SerializeFormat::Json`] codec.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+#[cfg(feature = "json")]
+impl MessageCodec for JsonCodec {
+    fn encode<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+
+    fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// [`SerializeFormat::Bincode`] codec.
+#[cfg(feature = "bincode")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeCodec;
+
+#[cfg(feature = "bincode")]
+impl MessageCodec for BincodeCodec {
+    fn encode<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+        bincode::serialize(value).map_err(|e| AuraError::SerializationError(format!("bincode: {}", e)))
+    }
+
+    fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+        bincode::deserialize(bytes).map_err(|e| AuraError::SerializationError(format!("bincode: {}", e)))
+    }
+}
+
+/// [`SerializeFormat::Cbor`] codec.
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborCodec;
+
+#[cfg(feature = "cbor")]
+impl MessageCodec for CborCodec {
+    fn encode<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).map_err(|e| AuraError::SerializationError(format!("CBOR: {}", e)))?;
+        Ok(bytes)
+    }
+
+    fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+        ciborium::from_reader(bytes).map_err(|e| AuraError::SerializationError(format!("CBOR: {}", e)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl SerializeFormat {
+    /// Encodes `value` into a message payload in this format.
+    ///
+    /// # Returns
+    /// The payload, or `AuraError::SerializationError` if the value cannot be encoded or
+    /// the format's feature is not enabled.
+    #[cfg_attr(not(any(feature = "json", feature = "bincode", feature = "cbor")), allow(unused_variables))]
+    pub fn encode_payload<T: serde::Serialize>(self, value: &T) -> Result<String> {
+        match self {
+            #[cfg(feature = "json")]
+            SerializeFormat::Json => String::from_utf8(JsonCodec::encode(value)?)
+                .map_err(|e| AuraError::SerializationError(format!("JSON output is not UTF-8: {}", e))),
+            #[cfg(feature = "bincode")]
+            SerializeFormat::Bincode => Ok(headed(BINCODE_HEADER, &BincodeCodec::encode(value)?)),
+            #[cfg(feature = "cbor")]
+            SerializeFormat::Cbor => Ok(headed(CBOR_HEADER, &CborCodec::encode(value)?)),
+            #[allow(unreachable_patterns)]
+            _ => Err(unavailable(self)),
+        }
+    }
+
+    /// Decodes a payload produced by [`SerializeFormat::encode_payload`] in this format.
+    ///
+    /// # Returns
+    /// The value, or `AuraError::SerializationError` if the payload was encoded in another
+    /// format, does not decode as `T`, or the format's feature is not enabled.
+    pub fn decode_payload<T: serde::de::DeserializeOwned>(self, data: &str) -> Result<T> {
+        let sent = SerializeFormat::of_payload(data);
+        if sent != self {
+            return Err(AuraError::SerializationError(format!(
+                "Payload is encoded as {:?}, but the receiver decodes {:?}; publisher and \
+                 subscriber must use the same SerializeFormat.",
+                sent, self
+            )));
+        }
+        match self {
+            #[cfg(feature = "json")]
+            SerializeFormat::Json => JsonCodec::decode(data.as_bytes()),
+            #[cfg(feature = "bincode")]
+            SerializeFormat::Bincode => BincodeCodec::decode(&decode_base64(&data[BINCODE_HEADER.len_utf8()..])?),
+            #[cfg(feature = "cbor")]
+            SerializeFormat::Cbor => CborCodec::decode(&decode_base64(&data[CBOR_HEADER.len_utf8()..])?),
+            #[allow(unreachable_patterns)]
+            _ => Err(unavailable(self)),
+        }
+    }
+}
+
+/// Error for a format whose feature is not enabled.
+pub(crate) fn unavailable(format: SerializeFormat) -> AuraError {
+    AuraError::SerializationError(format!(
+        "{:?} serialization needs aura_core to be built with the `{}` feature",
+        format,
+        format.feature()
+    ))
+}
+
+#[cfg(any(feature = "bincode", feature = "cbor"))]
+fn headed(header: char, bytes: &[u8]) -> String {
+    use base64::Engine;
+    let mut payload = String::from(header);
+    base64::engine::general_purpose::STANDARD.encode_string(bytes, &mut payload);
+    payload
+}
+
+#[cfg(any(feature = "bincode", feature = "cbor"))]
+fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| AuraError::SerializationError(format!("Binary payload is not valid base64: {}", e)))
+}
+
+#[cfg(all(test, any(feature = "json", feature = "bincode", feature = "cbor")))]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile, Subscriber};
+    use crate::Context;
+    use serde::{Deserialize, Serialize};
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Pose {
+        frame: String,
+        x: f64,
+        y: f64,
+    }
+
+    fn pose() -> Pose {
+        Pose {
+            frame: "map".to_string(),
+            x: 1.5,
+            y: -2.0,
+        }
+    }
+
+    /// Publishes a pose as `sent` and decodes it as `expected` on the same topic.
+    fn round_trip(sent: SerializeFormat, expected: SerializeFormat) -> Result<Pose> {
+        let context = Context::new();
+        let qos = QosProfile::default().serialize_format(sent);
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/pose", qos).unwrap();
+        let qos = QosProfile::default().serialize_format(expected);
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/pose", qos).unwrap();
+        publisher.publish_encoded(&pose()).unwrap();
+        subscriber.recv_timeout_decoded(std::time::Duration::from_secs(1))
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trips() {
+        assert_eq!(round_trip(SerializeFormat::Json, SerializeFormat::Json).unwrap(), pose());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_round_trips() {
+        assert_eq!(round_trip(SerializeFormat::Bincode, SerializeFormat::Bincode).unwrap(), pose());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn cbor_round_trips() {
+        assert_eq!(round_trip(SerializeFormat::Cbor, SerializeFormat::Cbor).unwrap(), pose());
+    }
+
+    #[cfg(all(feature = "json", feature = "cbor"))]
+    #[test]
+    fn mismatched_format_is_a_serialization_error() {
+        match round_trip(SerializeFormat::Cbor, SerializeFormat::Json) {
+            Err(AuraError::SerializationError(message)) => assert!(message.contains("encoded as Cbor"), "{}", message),
+            other => panic!("expected a SerializationError, got {:?}", other),
+        }
+    }
+}
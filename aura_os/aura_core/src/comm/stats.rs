@@ -0,0 +1,144 @@
+// aura_os/aura_core/src/comm/stats.rs
+
+use crate::aura_log; // Import from lib.rs
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Length of the sliding window over which `messages_per_sec` is computed.
+pub const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Publish statistics for a single topic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicStats {
+    /// The fully qualified topic name.
+    pub name: String,
+    /// Total number of messages published since statistics were enabled (or reset).
+    pub publish_count: u64,
+    /// Publish rate over the last `RATE_WINDOW`.
+    pub messages_per_sec: f64,
+    /// Wall-clock time of the most recent publish.
+    pub last_publish_time: SystemTime,
+}
+
+/// Raw bookkeeping for one topic.
+#[derive(Debug)]
+struct TopicRecord {
+    publish_count: u64,
+    /// Publish instants that fall inside the sliding window.
+    recent: VecDeque<Instant>,
+    last_publish_time: SystemTime,
+}
+
+impl TopicRecord {
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some(oldest) = self.recent.front() {
+            if now.duration_since(*oldest) > RATE_WINDOW {
+                self.recent.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+// Statistics collection is off by default so publishing pays only for one atomic load.
+static STATS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static::lazy_static! {
+    static ref TOPIC_STATS: Mutex<HashMap<String, TopicRecord>> = Mutex::new(HashMap::new());
+}
+
+/// Enables or disables statistics collection at runtime.
+///
+/// While disabled, `Publisher::publish` records nothing. Existing statistics are kept;
+/// use [`reset`] to discard them.
+pub fn set_enabled(enabled: bool) {
+    STATS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if statistics collection is currently enabled.
+pub fn is_enabled() -> bool {
+    STATS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Discards all collected statistics.
+pub fn reset() {
+    lock_stats().clear();
+}
+
+/// Returns the statistics for `name`, or `None` if nothing has been published on it
+/// while collection was enabled.
+pub fn topic_stats(name: &str) -> Option<TopicStats> {
+    let mut stats = lock_stats();
+    let record = stats.get_mut(name)?;
+    record.evict_expired(Instant::now());
+
+    Some(TopicStats {
+        name: name.to_string(),
+        publish_count: record.publish_count,
+        messages_per_sec: record.recent.len() as f64 / RATE_WINDOW.as_secs_f64(),
+        last_publish_time: record.last_publish_time,
+    })
+}
+
+/// Records a publish on `topic_name`. Called by `Publisher::publish`; a no-op while disabled.
+pub(crate) fn record_publish(topic_name: &str) {
+    if !is_enabled() {
+        return;
+    }
+
+    let mut stats = lock_stats();
+
+    let now = Instant::now();
+    let record = stats.entry(topic_name.to_string()).or_insert_with(|| TopicRecord {
+        publish_count: 0,
+        recent: VecDeque::new(),
+        last_publish_time: SystemTime::now(),
+    });
+    record.publish_count += 1;
+    record.last_publish_time = SystemTime::now();
+    record.recent.push_back(now);
+    record.evict_expired(now);
+}
+
+/// Locks the statistics table, recovering it (and clearing the poison, so the warning
+/// is logged once) if a panicking thread poisoned the lock. Counters are only ever
+/// bumped, so a torn update at worst miscounts one publish.
+fn lock_stats() -> MutexGuard<'static, HashMap<String, TopicRecord>> {
+    TOPIC_STATS.lock().unwrap_or_else(|poisoned| {
+        aura_log!(warn, "Topic statistics lock was poisoned by a panicking thread; recovering");
+        TOPIC_STATS.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile};
+    use crate::Context;
+    use std::thread;
+
+    #[test]
+    fn reported_rate_tracks_publish_rate() {
+        // Collection is process-wide, so tests only ever switch it on, never off.
+        set_enabled(true);
+        let publisher = Publisher::new_in_context(Context::new(), "/test/stats/rate", QosProfile::default()).unwrap();
+        let period = Duration::from_millis(20);
+        let start = Instant::now();
+        // 50 Hz for a little over one window, on a fixed schedule so sleep overshoot
+        // does not accumulate.
+        for tick in 0..60 {
+            if let Some(wait) = (start + period * tick).checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+            publisher.publish(tick.to_string()).unwrap();
+        }
+
+        let stats = topic_stats("/test/stats/rate").unwrap();
+        assert_eq!(stats.publish_count, 60);
+        assert!((40.0..=60.0).contains(&stats.messages_per_sec), "rate was {}", stats.messages_per_sec);
+    }
+}
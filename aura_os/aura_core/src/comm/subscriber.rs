@@ -1,22 +1,119 @@
 // aura_os/aura_core/src/comm/subscriber.rs
 
-use crate::{AuraMessage, MESSAGE_BUS, aura_log}; // Import from lib.rs
+use crate::{AuraMessage, Context, MessageQueue, MessageReceiver, MessageSender, SubscriberEntry, aura_log}; // Import from lib.rs
 use crate::error::{AuraError, Result};
-use std::sync::{mpsc, Arc, Mutex};
-use std::time::Duration;
+use crate::naming;
+use crate::node::Stoppable;
+use crate::param::ParamValue;
+use super::compression;
+use super::qos::QosProfile;
+use super::deadline::{DeadlineEvent, Watchdog};
+use super::{AuraMessageTrait, FromAuraMessage};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+/// How often a blocked receive checks whether shutdown has been requested.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How often the forwarding thread of `into_stream` checks whether the stream was dropped.
+#[cfg(feature = "async")]
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Source of process-unique subscriber ids.
+static NEXT_SUBSCRIBER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The bus-side identity of a `Subscriber`.
+///
+/// The `Subscriber` holds the only strong reference; the bus entry and the owning
+/// `Node` (if any) hold `Weak` references, so dropping the subscriber is enough for
+/// both to notice it is gone.
+#[derive(Debug)]
+pub(crate) struct SubscriptionHandle {
+    id: u64,
+    /// The topic, or for a prefix subscriber the namespace it covers.
+    topic_name: String,
+    context: Arc<Context>,
+    /// Registered as a prefix subscriber rather than on one topic.
+    prefix: bool,
+    /// Fully qualified name of the node that created the subscriber, if any.
+    pub(crate) node: OnceLock<String>,
+}
+
+impl SubscriptionHandle {
+    /// Creates a registration with a fresh id for `topic_name` in `context`.
+    pub(crate) fn new(context: Arc<Context>, topic_name: &str) -> Arc<Self> {
+        Self::create(context, topic_name, false)
+    }
+
+    fn create(context: Arc<Context>, topic_name: &str, prefix: bool) -> Arc<Self> {
+        Arc::new(Self {
+            id: NEXT_SUBSCRIBER_ID.fetch_add(1, Ordering::Relaxed),
+            topic_name: topic_name.to_string(),
+            context,
+            prefix,
+            node: OnceLock::new(),
+        })
+    }
+
+    /// Returns the topic this registration is for.
+    pub(crate) fn topic_name(&self) -> &str {
+        &self.topic_name
+    }
+
+    /// Removes this subscriber's sender from the message bus.
+    pub(crate) fn detach(&self) -> Result<()> {
+        if self.prefix {
+            if let Some(entries) = self.context.bus.lock_prefixes().get_mut(&self.topic_name) {
+                entries.retain(|entry| entry.id != self.id);
+            }
+            return Ok(());
+        }
+        let mut bus = self.context.bus.lock(&self.topic_name);
+        if let Some(topic) = bus.get_mut(&self.topic_name) {
+            topic.subscribers.retain(|entry| entry.id != self.id);
+        }
+        Ok(())
+    }
+}
+
+impl Stoppable for SubscriptionHandle {
+    /// Removes this subscriber's sender from the message bus. Once removed, the
+    /// subscriber's channel reports `ChannelDisconnected` after draining queued messages.
+    fn stop(&self) {
+        if let Err(e) = self.detach() {
+            aura_log!(warn, "Failed to stop subscriber on '{}': {}", self.topic_name, e);
+        }
+    }
+}
 
 /// Represents a subscriber that can receive messages from a specific topic.
 ///
-/// In this simplified sketch, `Subscriber` receives `AuraMessage` data.
+/// In this simplified sketch, `Subscriber` receives `AuraMessage` data. Messages are
+/// delivered as `Arc<AuraMessage>` shared by every subscriber on the topic; the
+/// `*_shared` methods hand out that `Arc` directly, while the plain methods return an
+/// owned message (cloned only if other subscribers still hold a reference).
 /// A full implementation would be generic over a message type `T: AuraMessageTrait`,
 /// handle deserialization, and interact with the AuraComm layer for network transport
 /// and Quality of Service (QoS) management.
 #[derive(Debug)] // Allow easy printing for debugging
 pub struct Subscriber {
     topic_name: String,
-    receiver: mpsc::Receiver<AuraMessage>, // Channel receiver for incoming messages
-    // In a real system with generic message types:
-    // _message_type: PhantomData<M>, // Ensures type M is known at compile time
+    receiver: MessageReceiver, // Receiving end of the subscriber's queue
+    /// Settings to re-register with after `unsubscribe`.
+    qos: QosProfile,
+    queue_group: Option<String>,
+    /// Set by `unsubscribe`, cleared by `resubscribe`.
+    unsubscribed: AtomicBool,
+    /// Registration handle; the bus holds a `Weak` to it so it can tell when this
+    /// subscriber has been dropped and prune its registration.
+    registration: Arc<SubscriptionHandle>,
+    /// Type declared with `new_typed`, if any. Held until the subscriber is dropped.
+    message_type: Option<&'static str>,
+    /// Messages ever delivered to the queue, counted when the QoS sets a deadline.
+    arrivals: Arc<AtomicU64>,
+    /// Set by `on_deadline_event`.
+    watchdog: Option<Watchdog>,
 }
 
 impl Subscriber {
@@ -34,56 +131,794 @@ impl Subscriber {
     /// # Returns
     /// A `Result` containing the new `Subscriber` or an `AuraError` if creation fails.
     pub fn new(topic_name: &str) -> Result<Self> {
-        aura_log!(info, "Creating subscriber for topic: '{}'", topic_name);
-        let (sender, receiver) = mpsc::channel();
+        Self::register(Context::global(), topic_name, None, QosProfile::default(), None)
+    }
+
+    /// Creates a new `Subscriber` with the given QoS profile.
+    ///
+    /// With a `depth`, the subscriber's queue is bounded. When it is full, `Reliable`
+    /// subscribers make publishers wait (up to their `publish_timeout`) and
+    /// `BestEffort` subscribers miss the message, unless the profile sets an
+    /// [`OverflowPolicy`](super::OverflowPolicy). Without a `depth`, a `max_backlog`
+    /// caps the unbounded queue; see [`Subscriber::backlog_len`].
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Subscriber` or an `AuraError` if creation fails.
+    pub fn new_with_qos(topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::register(Context::global(), topic_name, None, qos, None)
+    }
+
+    /// Creates a `Subscriber` in `context` instead of the global context. It only
+    /// receives messages published in the same context.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Subscriber` or an `AuraError` if creation fails.
+    pub fn new_in_context(context: Arc<Context>, topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::register(context, topic_name, None, qos, None)
+    }
+
+    /// Creates a `Subscriber` for every topic under the namespace `prefix`.
+    ///
+    /// `prefix` is an absolute namespace, optionally followed by `/` or `/*`:
+    /// "/sensors", "/sensors/" and "/sensors/*" all receive "/sensors/imu" and
+    /// "/sensors/lidar/points", but not "/sensors" itself or "/sensorsX". "/" (or "/*")
+    /// receives every topic. Each message's `topic` field tells which topic it came
+    /// from. Latched messages are not replayed to prefix subscribers.
+    ///
+    /// # Returns
+    /// The new `Subscriber`, or `AuraError::CommunicationError` if the prefix is invalid.
+    pub fn new_prefix(prefix: &str) -> Result<Self> {
+        Self::register_prefix(Context::global(), prefix, QosProfile::default())
+    }
+
+    /// Registers a prefix subscriber in `context`; see [`Subscriber::new_prefix`].
+    pub(crate) fn register_prefix(context: Arc<Context>, prefix: &str, qos: QosProfile) -> Result<Self> {
+        let namespace = match prefix.strip_suffix("/*").or_else(|| prefix.strip_suffix('/')).unwrap_or(prefix) {
+            "" | "/" => "/",
+            namespace => {
+                naming::validate_absolute_name(namespace).map_err(|reason| {
+                    AuraError::CommunicationError(format!("Invalid topic prefix '{}': {}.", prefix, reason))
+                })?;
+                namespace
+            }
+        };
+        if qos.depth == Some(0) || qos.max_backlog == Some(0) {
+            return Err(AuraError::CommunicationError(format!(
+                "Invalid QoS for prefix subscriber on '{}': depth and max_backlog must be at least 1.",
+                prefix
+            )));
+        }
+
+        aura_log!(info, "Creating prefix subscriber for topics under '{}'", namespace);
+        let registration = SubscriptionHandle::create(context, namespace, true);
+        let arrivals = Arc::new(AtomicU64::new(0));
+        let receiver = Self::attach(&registration, None, &qos, &arrivals)?;
+
+        Ok(Self {
+            topic_name: namespace.to_string(),
+            receiver,
+            qos,
+            queue_group: None,
+            unsubscribed: AtomicBool::new(false),
+            registration,
+            message_type: None,
+            arrivals,
+            watchdog: None,
+        })
+    }
+
+    /// Creates a `Subscriber` that declares the topic's message type as `M::type_name()`.
+    ///
+    /// Every typed publisher and subscriber on a topic must declare the same type;
+    /// untyped ones are compatible with any type.
+    ///
+    /// # Returns
+    /// The new `Subscriber`, or `AuraError::CommunicationError` if the topic already
+    /// carries a different type.
+    pub fn new_typed<M: AuraMessageTrait>(topic_name: &str, qos: QosProfile) -> Result<Self> {
+        Self::register(Context::global(), topic_name, None, qos, Some(M::type_name()))
+    }
 
-        // Lock the global message bus to register this subscriber
-        let mut bus = MESSAGE_BUS.lock().map_err(|_| AuraError::CommunicationError("Failed to lock message bus".into()))?;
-        bus.entry(topic_name.to_string())
-            .or_insert_with(Vec::new)
-            .push(sender); // Register the sender channel for this topic
+    /// Creates a `Subscriber` that joins the work-queue group `queue_group` on a topic.
+    ///
+    /// Subscribers in the same group share the topic's messages instead of each receiving
+    /// a copy: every published message goes to exactly one member of the group, chosen
+    /// round-robin. Regular subscribers and other groups on the same topic are unaffected.
+    ///
+    /// # Arguments
+    /// * `topic_name`: The fully qualified name of the topic to subscribe to.
+    /// * `queue_group`: The group name; must be a valid base name (e.g., "workers").
+    ///
+    /// # Returns
+    /// The new `Subscriber`, or `AuraError::CommunicationError` if the topic or group
+    /// name is invalid.
+    pub fn new_in_queue_group(topic_name: &str, queue_group: &str) -> Result<Self> {
+        naming::validate_base_name(queue_group).map_err(|reason| {
+            AuraError::CommunicationError(format!("Invalid queue group name '{}': {}.", queue_group, reason))
+        })?;
+        Self::register(Context::global(), topic_name, Some(queue_group), QosProfile::default(), None)
+    }
+
+    pub(crate) fn register(
+        context: Arc<Context>,
+        topic_name: &str,
+        queue_group: Option<&str>,
+        qos: QosProfile,
+        message_type: Option<&'static str>,
+    ) -> Result<Self> {
+        // Validate the topic name against the naming grammar (absolute, well-formed segments).
+        naming::validate_absolute_name(topic_name).map_err(|reason| {
+            AuraError::CommunicationError(format!("Invalid topic name '{}': {}.", topic_name, reason))
+        })?;
+
+        aura_log!(info, "Creating subscriber for topic: '{}'", topic_name);
+        if qos.depth == Some(0) || qos.max_backlog == Some(0) {
+            return Err(AuraError::CommunicationError(format!(
+                "Invalid QoS for subscriber on '{}': depth and max_backlog must be at least 1.",
+                topic_name
+            )));
+        }
+        if let Some(format) = qos.serialize_format.filter(|format| !format.is_available()) {
+            return Err(AuraError::CommunicationError(format!(
+                "Cannot create subscriber for topic '{}': {:?} serialization is not enabled in this build.",
+                topic_name, format
+            )));
+        }
+        let registration = SubscriptionHandle::new(context, topic_name);
+        if let Some(type_name) = message_type {
+            let mut bus = registration.context.bus.lock(topic_name);
+            bus.entry(topic_name.to_string()).or_default().claim_type(topic_name, type_name)?;
+        }
+        let arrivals = Arc::new(AtomicU64::new(0));
+        let receiver = match Self::attach(&registration, queue_group, &qos, &arrivals) {
+            Ok(receiver) => receiver,
+            Err(e) => {
+                if message_type.is_some() {
+                    release_type(&registration);
+                }
+                return Err(e);
+            }
+        };
 
         Ok(Self {
             topic_name: topic_name.to_string(),
             receiver,
-            // _message_type: PhantomData, // For generic version
+            qos,
+            queue_group: queue_group.map(str::to_string),
+            unsubscribed: AtomicBool::new(false),
+            registration,
+            message_type,
+            arrivals,
+            watchdog: None,
         })
     }
 
+    /// Creates a queue for `registration` and adds its sender to the message bus.
+    fn attach(
+        registration: &Arc<SubscriptionHandle>,
+        queue_group: Option<&str>,
+        qos: &QosProfile,
+        arrivals: &Arc<AtomicU64>,
+    ) -> Result<MessageReceiver> {
+        let (mut sender, receiver) = open_queue(qos);
+        if qos.deadline.is_some() {
+            sender = sender.counting_arrivals(Arc::clone(arrivals));
+        }
+        add_entry(registration, queue_group, qos, sender)?;
+        Ok(receiver)
+    }
+
+    /// Stops receiving without giving up the `Subscriber`.
+    ///
+    /// The subscriber's sender is removed from the message bus, so publishers no longer
+    /// deliver to it. Until [`Subscriber::resubscribe`] is called, the receive methods
+    /// fail immediately with `AuraError::ChannelDisconnected` (and `drain` returns
+    /// nothing); messages already queued are discarded on resubscription.
+    ///
+    /// # Returns
+    /// `Ok(())`. This does not currently fail: a poisoned bus lock is recovered.
+    pub fn unsubscribe(&self) -> Result<()> {
+        self.unsubscribed.store(true, Ordering::SeqCst);
+        self.registration.detach()?;
+        aura_log!(debug, "Unsubscribed from topic '{}'", self.topic_name);
+        Ok(())
+    }
+
+    /// Registers this subscriber on its topic again, with a fresh, empty queue and the
+    /// QoS it was created with. Also usable after the owning node stopped the subscriber.
+    ///
+    /// # Returns
+    /// `Ok(())`. This does not currently fail: a poisoned bus lock is recovered.
+    pub fn resubscribe(&mut self) -> Result<()> {
+        // Ensure there is no stale registration, so the subscriber is never listed twice.
+        self.registration.detach()?;
+        self.receiver = Self::attach(&self.registration, self.queue_group.as_deref(), &self.qos, &self.arrivals)?;
+        self.unsubscribed.store(false, Ordering::SeqCst);
+        aura_log!(debug, "Resubscribed to topic '{}'", self.topic_name);
+        Ok(())
+    }
+
+    /// Returns `false` between `unsubscribe` and `resubscribe`.
+    pub fn is_subscribed(&self) -> bool {
+        !self.unsubscribed.load(Ordering::SeqCst)
+    }
+
     /// Receives a message from the topic associated with this subscriber.
     ///
     /// # Arguments
     /// * `timeout`: Duration to wait for a message before timing out.
     ///
     /// # Returns
-    /// A `Result` containing the received `AuraMessage` if successful, or an `AuraError` if receiving fails.
+    /// A `Result` containing the received `AuraMessage` if successful,
+    /// `AuraError::TimeoutError` if no message arrived in time,
+    /// `AuraError::ChannelDisconnected` if the channel has been disconnected or shutdown
+    /// of the subscriber's context was requested while waiting (see [`Subscriber::recv`]),
+    /// or `AuraError::SerializationError` if a compressed payload does not decompress
+    /// (the message is consumed).
     pub fn recv_timeout(&self, timeout: Duration) -> Result<AuraMessage> {
-        self.receiver.recv_timeout(timeout).map_err(|e| {
-            match e {
-                mpsc::RecvTimeoutError::Timeout => AuraError::CommunicationError("Receive timed out".into()),
-                mpsc::RecvTimeoutError::Disconnected => AuraError::CommunicationError("Channel disconnected".into()),
+        self.recv_timeout_shared(timeout).map(Arc::unwrap_or_clone)
+    }
+
+    /// Like [`Subscriber::recv_timeout`], but returns the message shared with the other
+    /// subscribers instead of an owned copy.
+    pub fn recv_timeout_shared(&self, timeout: Duration) -> Result<Arc<AuraMessage>> {
+        compression::decompress_message(self.recv_timeout_raw(timeout)?)
+    }
+
+    /// Like [`Subscriber::recv_timeout_shared`], but leaves a compressed payload as it is.
+    pub(crate) fn recv_timeout_raw(&self, timeout: Duration) -> Result<Arc<AuraMessage>> {
+        self.ensure_subscribed()?;
+        self.recv_until_shutdown(Some(timeout))
+    }
+
+    /// Receives a message without blocking.
+    ///
+    /// # Returns
+    /// `Ok(Some(message))` if a message was queued, `Ok(None)` if the queue is currently
+    /// empty, `AuraError::ChannelDisconnected` if the channel has been disconnected, or
+    /// `AuraError::SerializationError` if a compressed payload does not decompress.
+    pub fn try_recv(&self) -> Result<Option<AuraMessage>> {
+        Ok(self.try_recv_shared()?.map(Arc::unwrap_or_clone))
+    }
+
+    /// Like [`Subscriber::try_recv`], but returns the shared message.
+    pub fn try_recv_shared(&self) -> Result<Option<Arc<AuraMessage>>> {
+        self.ensure_subscribed()?;
+        match self.receiver.try_recv() {
+            Ok(message) => compression::decompress_message(message).map(Some),
+            Err(mpsc::TryRecvError::Empty) => Ok(None),
+            Err(mpsc::TryRecvError::Disconnected) => Err(self.disconnected_error()),
+        }
+    }
+
+    /// Receives a message, blocking until one arrives, the channel is disconnected or
+    /// shutdown of the subscriber's context is requested.
+    ///
+    /// Shutdown is noticed within about 50 ms, and only while the queue is
+    /// empty: messages queued before or after the request are still returned, so a thread
+    /// looping on `recv` drains its backlog and then exits.
+    ///
+    /// # Returns
+    /// A `Result` containing the received `AuraMessage`, or `AuraError::ChannelDisconnected`
+    /// if the channel has been disconnected or shutdown was requested.
+    pub fn recv(&self) -> Result<AuraMessage> {
+        self.recv_shared().map(Arc::unwrap_or_clone)
+    }
+
+    /// Like [`Subscriber::recv`], but returns the shared message.
+    pub fn recv_shared(&self) -> Result<Arc<AuraMessage>> {
+        self.ensure_subscribed()?;
+        compression::decompress_message(self.recv_until_shutdown(None)?)
+    }
+
+    /// Receives a message, blocking like [`Subscriber::recv`], and converts it with
+    /// [`FromAuraMessage`] (with the `json` feature, any deserializable type).
+    ///
+    /// # Returns
+    /// The value, `AuraError::SerializationError` if the message does not convert (it is
+    /// consumed either way), or the same errors as `recv`.
+    pub fn recv_as<T: FromAuraMessage>(&self) -> Result<T> {
+        T::from_aura_message(&*self.recv_shared()?)
+    }
+
+    /// Like [`Subscriber::recv_as`], but waits at most `timeout`, like `recv_timeout`.
+    pub fn recv_timeout_as<T: FromAuraMessage>(&self, timeout: Duration) -> Result<T> {
+        T::from_aura_message(&*self.recv_timeout_shared(timeout)?)
+    }
+
+    /// Receives a message, blocking like [`Subscriber::recv`], and decodes it in this
+    /// subscriber's wire format: the QoS `serialize_format`, or else the context's; see
+    /// [`serde_format`](super::serde_format).
+    ///
+    /// # Returns
+    /// The value, `AuraError::SerializationError` if the payload is in another format or
+    /// does not decode (it is consumed either way), or the same errors as `recv`.
+    #[cfg(feature = "serde")]
+    pub fn recv_decoded<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        self.decode(&*self.recv_shared()?)
+    }
+
+    /// Like [`Subscriber::recv_decoded`], but waits at most `timeout`, like `recv_timeout`.
+    #[cfg(feature = "serde")]
+    pub fn recv_timeout_decoded<T: serde::de::DeserializeOwned>(&self, timeout: Duration) -> Result<T> {
+        self.decode(&*self.recv_timeout_shared(timeout)?)
+    }
+
+    #[cfg(feature = "serde")]
+    fn decode<T: serde::de::DeserializeOwned>(&self, message: &AuraMessage) -> Result<T> {
+        let format = self.qos.serialize_format.unwrap_or_else(|| self.registration.context.serialize_format());
+        format.decode_payload(&message.data).map_err(|e| match e {
+            AuraError::SerializationError(reason) => {
+                AuraError::SerializationError(format!("Message #{} on '{}': {}", message.seq, message.topic, reason))
             }
+            other => other,
         })
     }
 
-    /// Returns the topic name this subscriber is associated with.
+    /// Receives a parameter value sent with
+    /// [`Publisher::publish_value`](super::Publisher::publish_value), waiting at most
+    /// `timeout`.
+    ///
+    /// # Returns
+    /// The value, `AuraError::SerializationError` if the payload is not a tagged
+    /// parameter value, or the same errors as `recv_timeout`.
+    pub fn recv_value(&self, timeout: Duration) -> Result<ParamValue> {
+        ParamValue::from_message_data(&self.recv_timeout_shared(timeout)?.data)
+    }
+
+    /// Pulls every message currently queued for this subscriber, without blocking.
+    ///
+    /// Useful for batch processing. Returns an empty `Vec` if nothing is queued. Messages
+    /// whose compressed payload does not decompress are logged and skipped.
+    pub fn drain(&self) -> Vec<AuraMessage> {
+        self.drain_raw()
+            .into_iter()
+            .filter_map(|message| match compression::decompress_message(message) {
+                Ok(message) => Some(Arc::unwrap_or_clone(message)),
+                Err(e) => {
+                    aura_log!(warn, "Skipping undecodable message on '{}': {}", self.topic_name, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Like [`Subscriber::drain`], but leaves compressed payloads as they are.
+    pub(crate) fn drain_raw(&self) -> Vec<Arc<AuraMessage>> {
+        if !self.is_subscribed() {
+            return Vec::new();
+        }
+        self.receiver.drain()
+    }
+
+    /// Returns the number of messages waiting in this subscriber's queue.
+    pub fn backlog_len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    /// Converts this subscriber into an async `Stream` of messages for tokio users.
+    ///
+    /// This is a bridge until a native async transport exists: the blocking receiver
+    /// is moved onto a dedicated forwarding thread that pushes messages into a
+    /// `tokio::sync::mpsc` channel, so each stream costs one OS thread. Dropping the
+    /// stream closes that channel; the forwarding thread notices within about 100 ms,
+    /// even on a quiet topic, and exits, dropping the subscriber and so releasing the
+    /// subscription. The stream ends if the subscription channel is disconnected.
+    #[cfg(feature = "async")]
+    pub fn into_stream(self) -> impl tokio_stream::Stream<Item = AuraMessage> {
+        let (tx, rx) = tokio::sync::mpsc::channel(super::DEFAULT_MESSAGE_QUEUE_SIZE);
+        let thread_name = format!("aura-stream{}", self.topic_name);
+
+        let spawn_result = std::thread::Builder::new().name(thread_name).spawn(move || {
+            // Wake up periodically so a dropped stream is noticed even on a quiet topic.
+            while !tx.is_closed() {
+                match self.recv_timeout(STREAM_POLL_INTERVAL) {
+                    Ok(message) => {
+                        if tx.blocking_send(message).is_err() {
+                            break; // Stream dropped.
+                        }
+                    }
+                    Err(AuraError::TimeoutError(_)) => continue,
+                    Err(AuraError::SerializationError(e)) => {
+                        aura_log!(warn, "Skipping undecodable message on '{}': {}", self.topic_name, e);
+                    }
+                    Err(_) => break, // Disconnected.
+                }
+            }
+            aura_log!(debug, "Async stream forwarder for topic '{}' exiting.", self.topic_name);
+        });
+        if let Err(e) = spawn_result {
+            // `tx` was moved into the closure and dropped with it, so the stream simply ends.
+            aura_log!(error, "Failed to spawn async stream forwarder: {}", e);
+        }
+
+        tokio_stream::wrappers::ReceiverStream::new(rx)
+    }
+
+    /// Starts a watchdog calling `callback` whenever the QoS deadline passes without a
+    /// message arriving, and again when messages resume; see [`DeadlineEvent`].
+    ///
+    /// Arrival means delivery into this subscriber's queue, whether or not the message
+    /// has been received yet. The callback runs on the watchdog's own thread. Calling
+    /// this again replaces the previous callback; dropping the subscriber stops it.
+    ///
+    /// # Returns
+    /// `Ok(())`, `AuraError::CommunicationError` if the subscriber's QoS has no
+    /// deadline, or `AuraError::IoError` if the watchdog thread cannot be started.
+    pub fn on_deadline_event<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: Fn(DeadlineEvent) + Send + 'static,
+    {
+        let Some(deadline) = self.qos.deadline else {
+            return Err(AuraError::CommunicationError(format!(
+                "Subscriber for topic '{}' has no deadline in its QoS profile.",
+                self.topic_name
+            )));
+        };
+        // Stop the previous watchdog first, so only one callback is ever active.
+        self.watchdog = None;
+        self.watchdog = Some(Watchdog::start(&self.topic_name, deadline, Arc::clone(&self.arrivals), callback)?);
+        Ok(())
+    }
+
+    /// Returns the context this subscriber receives in.
+    pub fn context(&self) -> &Arc<Context> {
+        &self.registration.context
+    }
+
+    /// Returns `true` for a subscriber created with [`Subscriber::new_prefix`].
+    pub fn is_prefix(&self) -> bool {
+        self.registration.prefix
+    }
+
+    /// Returns the topic name this subscriber is associated with; for a prefix
+    /// subscriber, the namespace it covers (e.g., "/sensors").
     pub fn topic_name(&self) -> &str {
         &self.topic_name
     }
 
+    /// Records the node that created this subscriber, for graph introspection.
+    pub(crate) fn set_owner(&self, node: &str) {
+        let _ = self.registration.node.set(node.to_string());
+    }
+
+    /// Returns a handle the owning `Node` can use to stop this subscriber.
+    pub(crate) fn stop_handle(&self) -> Weak<dyn Stoppable> {
+        let handle: Weak<SubscriptionHandle> = Arc::downgrade(&self.registration);
+        handle
+    }
+
+    /// Waits for the next queued message, at most `timeout` if given, waking up every
+    /// `SHUTDOWN_POLL_INTERVAL` to give up once the context's shutdown is requested.
+    fn recv_until_shutdown(&self, timeout: Option<Duration>) -> Result<Arc<AuraMessage>> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let shutdown = self.context().shutdown_token();
+        loop {
+            let mut wait = SHUTDOWN_POLL_INTERVAL;
+            if let Some(deadline) = deadline {
+                wait = wait.min(deadline.saturating_duration_since(Instant::now()));
+            }
+            match self.receiver.recv_timeout(wait) {
+                Ok(message) => return Ok(message),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(self.disconnected_error()),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+            if shutdown.is_requested() {
+                return Err(AuraError::ChannelDisconnected(format!(
+                    "Shutdown requested while waiting on topic '{}'",
+                    self.topic_name
+                )));
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(AuraError::TimeoutError(format!(
+                    "No message received on topic '{}' within {:?}",
+                    self.topic_name,
+                    timeout.unwrap_or_default()
+                )));
+            }
+        }
+    }
+
+    fn ensure_subscribed(&self) -> Result<()> {
+        if self.is_subscribed() {
+            Ok(())
+        } else {
+            Err(AuraError::ChannelDisconnected(format!(
+                "Subscriber for topic '{}' is unsubscribed; call resubscribe() to receive again",
+                self.topic_name
+            )))
+        }
+    }
+
+    fn disconnected_error(&self) -> AuraError {
+        AuraError::ChannelDisconnected(format!(
+            "Subscription channel for topic '{}' disconnected",
+            self.topic_name
+        ))
+    }
+
     // --- Future Enhancements ---
     // - `fn get_num_subscribers(&self) -> Result<usize>`
     // - Methods related to QoS settings.
     // - Lifecycle methods if the subscriber itself has a state.
 }
 
-// `Drop` trait is not strictly necessary for this simplified Subscriber,
-// as it doesn't hold resources that need explicit cleanup (like network sockets
-// or threads). If it did, a `Drop` impl would be important.
-// For example, it might unregister itself from AuraComm.
-// impl Drop for Subscriber {
-//     fn drop(&mut self) {
-//         aura_log!(info, "Dropping subscriber for topic: '{}'. Unregistering...", self.topic_name);
-//         // AuraComm::unregister_subscriber(&self.topic_name, self.id);
-//     }
-// }
+/// Creates a subscriber queue as configured by `qos`.
+pub(crate) fn open_queue(qos: &QosProfile) -> (MessageSender, MessageReceiver) {
+    let (sender, receiver) = MessageQueue::open(qos.depth);
+    match qos.depth {
+        Some(_) => (sender, receiver),
+        None => (sender.with_max_backlog(qos.max_backlog), receiver),
+    }
+}
+
+/// Adds `sender` to the message bus as the entry of `registration`.
+pub(crate) fn add_entry(
+    registration: &Arc<SubscriptionHandle>,
+    queue_group: Option<&str>,
+    qos: &QosProfile,
+    sender: MessageSender,
+) -> Result<()> {
+    let topic_name = registration.topic_name.as_str();
+    let entry = SubscriberEntry {
+        id: registration.id,
+        sender,
+        overflow: qos.overflow_policy(),
+        queue_group: queue_group.map(str::to_string),
+        liveness: Arc::downgrade(registration),
+    };
+    if registration.prefix {
+        registration.context.bus.lock_prefixes().entry(topic_name.to_string()).or_default().push(entry);
+        return Ok(());
+    }
+
+    // Lock the context's message bus to register this subscriber
+    let mut bus = registration.context.bus.lock(topic_name);
+    let topic = match bus.get_mut(topic_name) {
+        Some(topic) => topic,
+        None => bus.entry(topic_name.to_string()).or_default(),
+    };
+    // A late joiner on a transient-local topic starts with the latched message.
+    if let Some(latched) = &topic.latched {
+        let _ = entry.sender.try_send(Arc::clone(latched));
+    }
+    topic.subscribers.push(entry); // Register the sender channel for this topic
+    topic.had_subscribers = true;
+
+    Ok(())
+}
+
+impl Drop for Subscriber {
+    /// Releases the topic's message type claimed by `new_typed`. The bus entry itself
+    /// is pruned lazily once the registration handle is gone.
+    fn drop(&mut self) {
+        if self.message_type.is_some() {
+            release_type(&self.registration);
+        }
+    }
+}
+
+fn release_type(registration: &SubscriptionHandle) {
+    let topic_name = registration.topic_name.as_str();
+    if let Some(topic) = registration.context.bus.lock(topic_name).get_mut(topic_name) {
+        topic.release_type();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn blocked_recv_returns_after_shutdown_request() {
+        // A private context, since tripping the global one would end every other test's loops.
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/idle", QosProfile::default()).unwrap();
+        let receiver = thread::spawn(move || {
+            let started = Instant::now();
+            (subscriber.recv(), started.elapsed())
+        });
+        thread::sleep(Duration::from_millis(100));
+        context.shutdown_token().request();
+
+        let (result, waited) = receiver.join().unwrap();
+        assert!(matches!(result, Err(AuraError::ChannelDisconnected(_))));
+        assert!(waited < Duration::from_millis(100) + SHUTDOWN_POLL_INTERVAL * 4, "recv took {:?}", waited);
+    }
+
+    #[test]
+    fn unsubscribed_subscriber_misses_messages_until_resubscribed() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/toggled", QosProfile::default()).unwrap();
+        let mut subscriber = Subscriber::new_in_context(Arc::clone(&context), "/toggled", QosProfile::default()).unwrap();
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 1);
+
+        subscriber.unsubscribe().unwrap();
+        assert!(!subscriber.is_subscribed());
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 0);
+        publisher.publish("missed".to_string()).unwrap();
+        assert!(matches!(subscriber.try_recv(), Err(AuraError::ChannelDisconnected(_))));
+
+        subscriber.resubscribe().unwrap();
+        assert!(subscriber.is_subscribed());
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 1);
+        publisher.publish("delivered".to_string()).unwrap();
+        let received: Vec<String> = subscriber.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(received, ["delivered"]);
+    }
+
+    #[test]
+    fn prefix_subscriber_gets_only_topics_below_the_namespace() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let subscriber = Subscriber::register_prefix(Arc::clone(&context), "/robot1/", QosProfile::default()).unwrap();
+        for topic in ["/robot1/a", "/robot1/b/c", "/robot10/x", "/robot1"] {
+            let publisher = Publisher::new_in_context(Arc::clone(&context), topic, QosProfile::default()).unwrap();
+            publisher.publish(topic.to_string()).unwrap();
+        }
+
+        let topics: Vec<String> = subscriber.drain().into_iter().map(|message| message.topic).collect();
+        assert_eq!(topics, ["/robot1/a", "/robot1/b/c"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn stream_yields_messages_and_stops_when_dropped() {
+        use crate::comm::Publisher;
+        use tokio_stream::StreamExt;
+
+        let context = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/stream", QosProfile::default()).unwrap();
+        let mut stream = Subscriber::new_in_context(Arc::clone(&context), "/stream", QosProfile::default())
+            .unwrap()
+            .into_stream();
+        for value in ["a", "b", "c"] {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            let message = tokio::time::timeout(Duration::from_secs(2), stream.next()).await.unwrap().unwrap();
+            received.push(message.data);
+        }
+        assert_eq!(received, ["a", "b", "c"]);
+
+        // The forwarding thread owns the subscriber; once it exits the subscription is gone.
+        drop(stream);
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while publisher.get_num_subscribers().unwrap() > 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(publisher.get_num_subscribers().unwrap(), 0);
+    }
+
+    #[test]
+    fn parameter_values_round_trip_over_a_topic() {
+        use crate::comm::Publisher;
+
+        let values = vec![
+            ParamValue::String("frame:base_link".to_string()),
+            ParamValue::String(String::new()),
+            ParamValue::Int(-42),
+            ParamValue::Float(0.1),
+            ParamValue::Float(-3.5e-12),
+            ParamValue::Bool(true),
+            ParamValue::Null,
+        ];
+        for value in &values {
+            assert_eq!(&ParamValue::from_message_data(&value.to_message_data()).unwrap(), value);
+        }
+
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/values", QosProfile::default()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/values", QosProfile::default()).unwrap();
+        for value in &values {
+            publisher.publish_value(value.clone()).unwrap();
+            assert_eq!(&subscriber.recv_value(Duration::from_secs(1)).unwrap(), value);
+        }
+
+        publisher.publish("f64:fast".to_string()).unwrap();
+        assert!(matches!(subscriber.recv_value(Duration::from_secs(1)), Err(AuraError::SerializationError(_))));
+        for malformed in ["3.14", "u8:3", "i64:1.5", "bool:yes", ""] {
+            assert!(matches!(ParamValue::from_message_data(malformed), Err(AuraError::SerializationError(_))));
+        }
+    }
+
+    #[test]
+    fn max_backlog_caps_an_unbounded_queue() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let qos = QosProfile::default().max_backlog(3);
+        let slow = Subscriber::new_in_context(Arc::clone(&context), "/backlog", qos).unwrap();
+        let uncapped = Subscriber::new_in_context(Arc::clone(&context), "/backlog", QosProfile::default()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/backlog", QosProfile::default()).unwrap();
+        for value in 0..3 {
+            publisher.publish(value.to_string()).unwrap();
+        }
+        assert_eq!(slow.backlog_len(), 3);
+
+        let overflow = publisher.publish("3".to_string());
+        assert!(matches!(overflow, Err(AuraError::CommunicationError(ref e)) if e.starts_with("Backlog overflow")));
+        assert_eq!(slow.backlog_len(), 3);
+        assert_eq!(uncapped.backlog_len(), 4);
+
+        // Taking a message frees room for the next one.
+        assert_eq!(slow.try_recv().unwrap().unwrap().data, "0");
+        publisher.publish("4".to_string()).unwrap();
+        let kept: Vec<String> = slow.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(kept, ["1", "2", "4"]);
+        assert_eq!(slow.backlog_len(), 0);
+
+        let zero = QosProfile::default().max_backlog(0);
+        assert!(Subscriber::new_in_context(Arc::clone(&context), "/backlog", zero).is_err());
+    }
+
+    #[test]
+    fn subscribers_share_one_allocation() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let first = Subscriber::new_in_context(Arc::clone(&context), "/shared", QosProfile::default()).unwrap();
+        let second = Subscriber::new_in_context(Arc::clone(&context), "/shared", QosProfile::default()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/shared", QosProfile::default()).unwrap();
+        publisher.publish("x".repeat(1 << 20)).unwrap();
+
+        let a = first.recv_shared().unwrap();
+        let b = second.try_recv_shared().unwrap().unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(a.data.len(), 1 << 20);
+
+        // The owned API still works and hands out the same content.
+        publisher.publish("owned".to_string()).unwrap();
+        assert_eq!(first.recv().unwrap().data, "owned");
+        assert_eq!(second.try_recv().unwrap().unwrap().data, "owned");
+    }
+
+    #[test]
+    fn empty_queue_is_none_for_try_recv_and_a_timeout_for_recv_timeout() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/quiet", QosProfile::default()).unwrap();
+        assert!(subscriber.try_recv().unwrap().is_none());
+        assert!(subscriber.drain().is_empty());
+        let started = Instant::now();
+        assert!(matches!(subscriber.recv_timeout(Duration::from_millis(30)), Err(AuraError::TimeoutError(_))));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/quiet", QosProfile::default()).unwrap();
+        for data in ["a", "b"] {
+            publisher.publish(data.to_string()).unwrap();
+        }
+        assert_eq!(subscriber.recv().unwrap().data, "a");
+        assert_eq!(subscriber.try_recv().unwrap().unwrap().data, "b");
+        assert!(subscriber.try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn timeout_and_disconnect_are_different_errors() {
+        use crate::comm::Publisher;
+
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/sensors/imu", QosProfile::default()).unwrap();
+        match subscriber.recv_timeout(Duration::from_millis(10)) {
+            Err(AuraError::TimeoutError(message)) => assert!(message.contains("/sensors/imu"), "{}", message),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+
+        // Messages queued before the disconnect are still delivered.
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/sensors/imu", QosProfile::default()).unwrap();
+        publisher.publish("last".to_string()).unwrap();
+        subscriber.stop_handle().upgrade().unwrap().stop();
+        assert_eq!(subscriber.recv_timeout(Duration::from_millis(10)).unwrap().data, "last");
+        match subscriber.recv_timeout(Duration::from_millis(10)) {
+            Err(AuraError::ChannelDisconnected(message)) => assert!(message.contains("/sensors/imu"), "{}", message),
+            other => panic!("expected a disconnect, got {:?}", other),
+        }
+        assert!(matches!(subscriber.try_recv(), Err(AuraError::ChannelDisconnected(_))));
+    }
+}
@@ -0,0 +1,173 @@
+// aura_os/aura_core/src/comm/tap.rs
+
+use crate::error::{AuraError, Result};
+use crate::naming;
+use crate::{aura_log, AuraMessage, Context}; // Import from lib.rs
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+type TapCallback = Arc<dyn Fn(&AuraMessage) + Send + Sync>;
+
+/// Source of process-unique tap ids.
+static NEXT_TAP_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The taps installed in one [`Context`], keyed by topic.
+#[derive(Default)]
+pub(crate) struct TapRegistry {
+    taps: Mutex<HashMap<String, Vec<(u64, TapCallback)>>>,
+}
+
+impl std::fmt::Debug for TapRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TapRegistry").field("topics", &self.lock().len()).finish()
+    }
+}
+
+impl TapRegistry {
+    /// Locks the registry. A registry poisoned by a panic is recovered, since each
+    /// update is a single push or retain; the poison is cleared so the warning is
+    /// logged once.
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Vec<(u64, TapCallback)>>> {
+        self.taps.lock().unwrap_or_else(|poisoned| {
+            aura_log!(warn, "Tap registry lock was poisoned by a panicking thread; recovering");
+            self.taps.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+}
+
+/// A debugging observer attached to a topic with [`tap`].
+///
+/// The tap stays installed until this handle is dropped (or [`TapHandle::detach`] is
+/// called).
+#[must_use = "the tap is removed as soon as the handle is dropped"]
+pub struct TapHandle {
+    id: u64,
+    topic_name: String,
+    context: Arc<Context>,
+}
+
+impl TapHandle {
+    /// Returns the topic this tap observes.
+    pub fn topic_name(&self) -> &str {
+        &self.topic_name
+    }
+
+    /// Removes the tap. Equivalent to dropping the handle.
+    pub fn detach(self) {}
+}
+
+impl std::fmt::Debug for TapHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TapHandle")
+            .field("id", &self.id)
+            .field("topic_name", &self.topic_name)
+            .finish()
+    }
+}
+
+impl Drop for TapHandle {
+    fn drop(&mut self) {
+        let mut taps = self.context.taps.lock();
+        if let Some(entries) = taps.get_mut(&self.topic_name) {
+            entries.retain(|(id, _)| *id != self.id);
+            if entries.is_empty() {
+                taps.remove(&self.topic_name);
+            }
+        }
+    }
+}
+
+/// Installs an observer that sees every message published on `topic_name` in the
+/// global context, before it is delivered to subscribers.
+///
+/// Unlike a `Subscriber`, a tap has no queue and is not registered on the message bus,
+/// so it does not show up in subscriber counts or the topic graph. Any number of taps
+/// may observe the same topic. The callback runs on the publishing thread, so it should
+/// be quick. Messages arriving from other processes over a transport are shown to the
+/// global context's taps too.
+///
+/// Example:
+/// ```ignore
+/// let _tap = aura_core::comm::tap("/chatter", |msg| println!("saw {}", msg.data))?;
+/// ```
+///
+/// # Returns
+/// A `TapHandle` that detaches the tap when dropped, or `AuraError::CommunicationError`
+/// if the topic name is invalid.
+pub fn tap<F>(topic_name: &str, callback: F) -> Result<TapHandle>
+where
+    F: Fn(&AuraMessage) + Send + Sync + 'static,
+{
+    tap_in_context(Context::global(), topic_name, callback)
+}
+
+/// Like [`tap`], but observes messages published in `context` instead of the global
+/// context.
+pub fn tap_in_context<F>(context: Arc<Context>, topic_name: &str, callback: F) -> Result<TapHandle>
+where
+    F: Fn(&AuraMessage) + Send + Sync + 'static,
+{
+    naming::validate_absolute_name(topic_name).map_err(|reason| {
+        AuraError::CommunicationError(format!("Invalid topic name '{}': {}.", topic_name, reason))
+    })?;
+
+    let id = NEXT_TAP_ID.fetch_add(1, Ordering::Relaxed);
+    context
+        .taps
+        .lock()
+        .entry(topic_name.to_string())
+        .or_default()
+        .push((id, Arc::new(callback)));
+    aura_log!(debug, "Installed tap {} on topic '{}'", id, topic_name);
+
+    Ok(TapHandle {
+        id,
+        topic_name: topic_name.to_string(),
+        context,
+    })
+}
+
+/// Invokes every tap in `context` on the message's topic. Called by `Publisher::publish`.
+pub(crate) fn notify(context: &Context, message: &AuraMessage) {
+    // Copy the callbacks out so they run without the registry lock held; a tap may
+    // then safely install or drop other taps.
+    let callbacks: Vec<TapCallback> = match context.taps.lock().get(&message.topic) {
+        Some(entries) => entries.iter().map(|(_, callback)| Arc::clone(callback)).collect(),
+        None => return,
+    };
+    for callback in callbacks {
+        callback(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile};
+
+    #[test]
+    fn tap_sees_its_context_until_detached() {
+        let context = Context::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let handle = {
+            let seen = Arc::clone(&seen);
+            tap_in_context(Arc::clone(&context), "/tapped", move |msg| seen.lock().unwrap().push(msg.data.clone())).unwrap()
+        };
+        assert_eq!(handle.topic_name(), "/tapped");
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/tapped", QosProfile::default()).unwrap();
+        let elsewhere = Publisher::new_in_context(Context::new(), "/tapped", QosProfile::default()).unwrap();
+
+        publisher.publish("observed".to_string()).unwrap();
+        elsewhere.publish("other context".to_string()).unwrap();
+        handle.detach();
+        publisher.publish("after detach".to_string()).unwrap();
+        assert_eq!(*seen.lock().unwrap(), ["observed"]);
+    }
+
+    #[test]
+    fn invalid_topic_is_rejected() {
+        assert!(matches!(tap("no_slash", |_| {}), Err(AuraError::CommunicationError(_))));
+    }
+}
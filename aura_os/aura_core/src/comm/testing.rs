@@ -0,0 +1,74 @@
+// aura_os/aura_core/src/comm/testing.rs
+
+//! Test support for code that uses the global context (feature `test-util`).
+//!
+//! Tests in one process share the global context's message bus, so subscribers and
+//! latched messages left behind by one test can leak into the next. Calling
+//! [`reset_bus`] at the start of a test gives it an empty bus. Tests that can create
+//! their own [`Context`] should prefer that, since it also isolates tests running in
+//! parallel, which `reset_bus` does not.
+//!
+//! Example:
+//! ```ignore
+//! aura_core::comm::reset_bus();
+//! let _sub = Subscriber::new("/chatter")?;
+//! assert_eq!(aura_core::comm::bus_topic_count(), 1);
+//! ```
+
+use crate::Context;
+use std::sync::PoisonError;
+
+/// Removes every topic registration, latched message, declared topic type and prefix
+/// subscription from the global context's message bus.
+///
+/// Subscribers that still exist stop receiving and, once their queue is drained,
+/// report `ChannelDisconnected`; publishers that still exist publish to no one. Poisoned
+/// bus locks (e.g. from a test that panicked) are cleared as well.
+pub fn reset_bus() {
+    let context = Context::global();
+    for shard in context.bus.shards() {
+        shard.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+    context.bus.lock_prefixes().clear();
+}
+
+/// Returns the number of topics the global context's message bus has an entry for.
+///
+/// A topic gets an entry when its first publisher or subscriber is created, and keeps
+/// it after they are dropped, until [`reset_bus`]. Prefix subscriptions are not counted.
+pub fn bus_topic_count() -> usize {
+    Context::global()
+        .bus
+        .shards()
+        .map(|shard| shard.lock().unwrap_or_else(PoisonError::into_inner).len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, Subscriber};
+    use crate::AuraError;
+    use std::time::Duration;
+
+    #[test]
+    fn reset_detaches_existing_subscribers() {
+        let _bus = crate::global_bus_test_lock();
+        reset_bus();
+        let old = Subscriber::new("/testing/reset").unwrap();
+        let publisher = Publisher::new("/testing/reset").unwrap();
+        publisher.publish("before".to_string()).unwrap();
+        assert_eq!(bus_topic_count(), 1);
+
+        reset_bus();
+        assert_eq!(bus_topic_count(), 0);
+        publisher.publish("after".to_string()).unwrap();
+        assert_eq!(old.try_recv().unwrap().unwrap().data, "before");
+        assert!(matches!(old.try_recv(), Err(AuraError::ChannelDisconnected(_))));
+
+        let new = Subscriber::new("/testing/reset").unwrap();
+        assert_eq!(bus_topic_count(), 1);
+        Publisher::new("/testing/reset").unwrap().publish("fresh".to_string()).unwrap();
+        assert_eq!(new.recv_timeout(Duration::from_secs(1)).unwrap().data, "fresh");
+    }
+}
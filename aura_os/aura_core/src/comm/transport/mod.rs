@@ -0,0 +1,189 @@
+// aura_os/aura_core/src/comm/transport/mod.rs
+
+//! # Inter-process transports
+//!
+//! Connects the in-process message bus to the buses of other processes. Each
+//! connection to another process is a *peer*. Every message published locally is
+//! forwarded to all peers; every message received from a peer is delivered to the
+//! local subscribers of its topic and relayed to all other peers. With one process
+//! acting as the hub ([`tcp::TcpBusServer`]) and the others connected to it
+//! ([`tcp::TcpBusClient`]), a message published anywhere reaches subscribers everywhere.
+//!
+//! Peers must form a star (or any other tree); a cycle of connections would relay
+//! messages forever. That includes a process connecting a client to its own server.
+//! All traffic is forwarded regardless of which topics the other side subscribes to;
+//! messages nobody listens to are discarded on arrival.
+//!
+//! On the wire, each message is a frame: a 4-byte big-endian length followed by that
+//! many bytes of JSON-encoded `AuraMessage`.
+//!
+//! This module is only available with the `tcp` feature enabled.
+
+pub mod tcp;
+
+pub use tcp::{TcpBusClient, TcpBusServer};
+
+use super::qos::DEFAULT_PUBLISH_TIMEOUT;
+use super::{publisher, tap};
+use crate::error::{AuraError, Result};
+use crate::{aura_log, AuraMessage, Context};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Largest frame accepted from a peer, to bound the allocation made for a bogus length.
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Environment variable naming the bus endpoint `aura_core::init` joins.
+pub const BUS_ADDR_ENV: &str = "AURA_BUS_ADDR";
+
+/// Something a message can be forwarded to.
+pub(crate) trait PeerLink: Send + Sync {
+    /// Sends `message` to the peer.
+    fn send(&self, message: &AuraMessage) -> Result<()>;
+}
+
+/// Source of process-unique peer ids.
+static NEXT_PEER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The endpoint `aura_core::init` opened from [`BUS_ADDR_ENV`]. It is only held, to
+/// keep the connection open until `aura_core::shutdown`.
+#[derive(Debug)]
+#[allow(dead_code)]
+enum BusEndpoint {
+    Server(TcpBusServer),
+    Client(TcpBusClient),
+}
+
+lazy_static::lazy_static! {
+    static ref PEERS: Mutex<Vec<(u64, Arc<dyn PeerLink>)>> = Mutex::new(Vec::new());
+    static ref ENV_ENDPOINT: Mutex<Option<BusEndpoint>> = Mutex::new(None);
+}
+
+/// Joins the bus named by [`BUS_ADDR_ENV`], if set. Called by `aura_core::init`.
+///
+/// The process connects as a client if a server is already listening at that address
+/// and otherwise becomes the server itself, so whichever process starts first hosts
+/// the bus.
+pub(crate) fn join_from_env() {
+    let Ok(addr) = std::env::var(BUS_ADDR_ENV) else {
+        return;
+    };
+    let mut endpoint = ENV_ENDPOINT.lock().unwrap_or_else(PoisonError::into_inner);
+    if endpoint.is_some() {
+        return;
+    }
+    *endpoint = match TcpBusClient::connect(addr.as_str()) {
+        Ok(client) => Some(BusEndpoint::Client(client)),
+        Err(_) => match TcpBusServer::bind(addr.as_str()) {
+            Ok(server) => Some(BusEndpoint::Server(server)),
+            Err(e) => {
+                aura_log!(error, "Failed to join the bus at {}='{}': {}", BUS_ADDR_ENV, addr, e);
+                None
+            }
+        },
+    };
+}
+
+/// Closes the endpoint opened by [`join_from_env`]. Called by `aura_core::shutdown`.
+pub(crate) fn leave() {
+    let endpoint = ENV_ENDPOINT.lock().unwrap_or_else(PoisonError::into_inner).take();
+    drop(endpoint);
+}
+
+/// Registers a peer to forward messages to and returns its id.
+pub(crate) fn add_peer(link: Arc<dyn PeerLink>) -> u64 {
+    let id = NEXT_PEER_ID.fetch_add(1, Ordering::Relaxed);
+    PEERS.lock().unwrap_or_else(PoisonError::into_inner).push((id, link));
+    id
+}
+
+/// Stops forwarding messages to the peer with the given id.
+pub(crate) fn remove_peer(id: u64) {
+    PEERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|(peer_id, _)| *peer_id != id);
+}
+
+/// Returns the number of connected peers.
+pub fn peer_count() -> usize {
+    PEERS.lock().unwrap_or_else(PoisonError::into_inner).len()
+}
+
+/// Forwards `message` to every peer except `except` (the peer it came from, if any).
+///
+/// Peers that fail to accept the message are dropped. Called by `Publisher::publish`.
+pub(crate) fn forward(message: &AuraMessage, except: Option<u64>) {
+    // Copy the links out so a slow socket does not hold up peer registration.
+    let links: Vec<(u64, Arc<dyn PeerLink>)> = PEERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .filter(|(id, _)| Some(*id) != except)
+        .map(|(id, link)| (*id, Arc::clone(link)))
+        .collect();
+    for (id, link) in links {
+        if let Err(e) = link.send(message) {
+            aura_log!(warn, "Dropping peer {} after failing to forward to it: {}", id, e);
+            remove_peer(id);
+        }
+    }
+}
+
+/// Handles a message that arrived from peer `from`: delivers it in the global context
+/// and relays it to the other peers.
+pub(crate) fn receive(message: AuraMessage, from: u64) {
+    let message = Arc::new(message);
+    let context = Context::global();
+    tap::notify(&context, &message);
+    forward(&message, Some(from));
+    if let Err(e) = publisher::publish_local(&context.bus, std::slice::from_ref(&message), false, DEFAULT_PUBLISH_TIMEOUT) {
+        aura_log!(warn, "Failed to deliver message from peer {} on '{}': {}", from, message.topic, e);
+    }
+}
+
+/// Writes `message` as one length-prefixed JSON frame.
+pub(crate) fn write_frame<W: Write>(writer: &mut W, message: &AuraMessage) -> Result<()> {
+    let payload = serde_json::to_vec(message)?;
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|len| *len as usize <= MAX_FRAME_LEN)
+        .ok_or_else(|| {
+            AuraError::CommunicationError(format!(
+                "Message on '{}' is {} bytes, over the {} byte frame limit.",
+                message.topic,
+                payload.len(),
+                MAX_FRAME_LEN
+            ))
+        })?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(&payload)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads one length-prefixed JSON frame.
+///
+/// # Returns
+/// `Ok(Some(message))`, `Ok(None)` if the peer closed the connection between frames,
+/// `AuraError::IoError` on a read failure, or `AuraError::SerializationError` /
+/// `AuraError::CommunicationError` for a malformed frame.
+pub(crate) fn read_frame<R: Read>(reader: &mut R) -> Result<Option<AuraMessage>> {
+    let mut len_bytes = [0u8; 4];
+    match reader.read_exact(&mut len_bytes) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(AuraError::CommunicationError(format!(
+            "Peer sent a {} byte frame, over the {} byte limit.",
+            len, MAX_FRAME_LEN
+        )));
+    }
+    let mut payload = vec![0u8; len];
+    reader.read_exact(&mut payload)?;
+    Ok(Some(serde_json::from_slice(&payload)?))
+}
@@ -0,0 +1,333 @@
+// aura_os/aura_core/src/comm/transport/tcp.rs
+
+use super::{read_frame, receive, write_frame, PeerLink};
+use crate::comm::qos::DEFAULT_PUBLISH_TIMEOUT;
+use crate::error::Result;
+use crate::{aura_log, AuraMessage};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often the accept loop checks whether the server is being dropped.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The writing side of a connection, registered as a peer.
+#[derive(Debug)]
+struct TcpPeer {
+    stream: Mutex<TcpStream>,
+}
+
+impl PeerLink for TcpPeer {
+    fn send(&self, message: &AuraMessage) -> Result<()> {
+        let mut stream = self.stream.lock().unwrap_or_else(PoisonError::into_inner);
+        write_frame(&mut *stream, message)
+    }
+}
+
+/// One socket to another process: registered as a peer, with a thread delivering
+/// whatever arrives on it. Dropping it closes the socket.
+#[derive(Debug)]
+struct Connection {
+    peer_id: u64,
+    remote_addr: SocketAddr,
+    stream: TcpStream,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl Connection {
+    fn open(stream: TcpStream) -> Result<Self> {
+        let remote_addr = stream.peer_addr()?;
+        // A peer that stops reading must not stall publishers indefinitely.
+        stream.set_write_timeout(Some(DEFAULT_PUBLISH_TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        let mut read_stream = stream.try_clone()?;
+        let peer_id = super::add_peer(Arc::new(TcpPeer {
+            stream: Mutex::new(stream.try_clone()?),
+        }));
+
+        let reader = thread::Builder::new()
+            .name(format!("aura-tcp-{}", remote_addr))
+            .spawn(move || {
+                loop {
+                    match read_frame(&mut read_stream) {
+                        Ok(Some(message)) => receive(message, peer_id),
+                        Ok(None) => break,
+                        Err(e) => {
+                            aura_log!(debug, "Connection to {} closed: {}", remote_addr, e);
+                            break;
+                        }
+                    }
+                }
+                super::remove_peer(peer_id);
+                aura_log!(info, "Peer {} at {} disconnected", peer_id, remote_addr);
+            });
+        let reader = match reader {
+            Ok(reader) => reader,
+            Err(e) => {
+                super::remove_peer(peer_id);
+                return Err(e.into());
+            }
+        };
+        aura_log!(info, "Peer {} connected at {}", peer_id, remote_addr);
+
+        Ok(Self {
+            peer_id,
+            remote_addr,
+            stream,
+            reader: Some(reader),
+        })
+    }
+
+    fn is_open(&self) -> bool {
+        self.reader.as_ref().is_some_and(|reader| !reader.is_finished())
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        super::remove_peer(self.peer_id);
+        let _ = self.stream.shutdown(Shutdown::Both);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+/// The hub other processes connect to with [`TcpBusClient`].
+///
+/// Messages published in this process go to every connected client, and messages
+/// from one client go to the local subscribers and to every other client.
+///
+/// Example:
+/// ```ignore
+/// let server = TcpBusServer::bind("127.0.0.1:7447")?;
+/// // In another process:
+/// let client = TcpBusClient::connect("127.0.0.1:7447")?;
+/// ```
+///
+/// Dropping the server disconnects all clients.
+#[derive(Debug)]
+pub struct TcpBusServer {
+    local_addr: SocketAddr,
+    stop_requested: Arc<AtomicBool>,
+    connections: Arc<Mutex<Vec<Connection>>>,
+    acceptor: Option<JoinHandle<()>>,
+}
+
+impl TcpBusServer {
+    /// Listens for clients on `addr`. Port 0 picks a free port; see [`Self::local_addr`].
+    ///
+    /// # Returns
+    /// The running server, or `AuraError::IoError` if the address cannot be bound.
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let connections = Arc::new(Mutex::new(Vec::new()));
+        let acceptor = {
+            let stop_requested = Arc::clone(&stop_requested);
+            let connections = Arc::clone(&connections);
+            thread::Builder::new()
+                .name("aura-tcp-accept".to_string())
+                .spawn(move || accept_loop(&listener, &stop_requested, &connections))?
+        };
+        aura_log!(info, "Bus server listening on {}", local_addr);
+
+        Ok(Self {
+            local_addr,
+            stop_requested,
+            connections,
+            acceptor: Some(acceptor),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Returns the number of currently connected clients.
+    pub fn client_count(&self) -> usize {
+        let mut connections = self.connections.lock().unwrap_or_else(PoisonError::into_inner);
+        connections.retain(Connection::is_open);
+        connections.len()
+    }
+}
+
+fn accept_loop(listener: &TcpListener, stop_requested: &AtomicBool, connections: &Mutex<Vec<Connection>>) {
+    while !stop_requested.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, addr)) => {
+                let opened = stream.set_nonblocking(false).map_err(Into::into).and_then(|()| Connection::open(stream));
+                match opened {
+                    Ok(connection) => {
+                        let mut connections = connections.lock().unwrap_or_else(PoisonError::into_inner);
+                        connections.retain(Connection::is_open);
+                        connections.push(connection);
+                    }
+                    Err(e) => {
+                        aura_log!(warn, "Failed to set up connection from {}: {}", addr, e);
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => thread::sleep(ACCEPT_POLL_INTERVAL),
+            Err(e) => {
+                aura_log!(warn, "Failed to accept bus connection: {}", e);
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+impl Drop for TcpBusServer {
+    /// Stops accepting clients and closes every connection.
+    fn drop(&mut self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+        if let Some(acceptor) = self.acceptor.take() {
+            let _ = acceptor.join();
+        }
+        // Take the connections out first so their readers are not joined under the lock.
+        let connections = std::mem::take(&mut *self.connections.lock().unwrap_or_else(PoisonError::into_inner));
+        drop(connections);
+        aura_log!(info, "Bus server on {} stopped", self.local_addr);
+    }
+}
+
+/// A connection from this process to a [`TcpBusServer`].
+///
+/// Messages published in this process go to the server (and from there to its other
+/// clients), and messages from the server go to the local subscribers. Dropping the
+/// client disconnects it.
+#[derive(Debug)]
+pub struct TcpBusClient {
+    connection: Connection,
+}
+
+impl TcpBusClient {
+    /// Connects to the server at `addr`.
+    ///
+    /// # Returns
+    /// The connected client, or `AuraError::IoError` if the connection fails.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        Ok(Self {
+            connection: Connection::open(stream)?,
+        })
+    }
+
+    /// Returns the address of the server.
+    pub fn server_addr(&self) -> SocketAddr {
+        self.connection.remote_addr
+    }
+
+    /// Returns `false` once the server has closed the connection.
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_open()
+    }
+}
+
+#[cfg(all(test, feature = "tcp"))]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, Subscriber};
+    use std::io::Write;
+    use std::time::Instant;
+
+    /// Polls `condition` for up to two seconds.
+    fn wait_until(condition: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while !condition() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        true
+    }
+
+    fn message(topic: &str, data: &str) -> AuraMessage {
+        AuraMessage {
+            topic: topic.to_string(),
+            data: data.to_string(),
+            ..AuraMessage::default()
+        }
+    }
+
+    /// Reads frames from `stream` until one on `topic` arrives; other tests may be
+    /// publishing in the global context at the same time.
+    fn read_topic(stream: &mut TcpStream, topic: &str) -> AuraMessage {
+        stream.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        loop {
+            let frame = read_frame(stream).unwrap().expect("the server closed the connection");
+            if frame.topic == topic {
+                return frame;
+            }
+        }
+    }
+
+    #[test]
+    fn server_exchanges_messages_with_a_client() {
+        let _bus = crate::global_bus_test_lock();
+        let server = TcpBusServer::bind("127.0.0.1:0").unwrap();
+        let subscriber = Subscriber::new("/tcp_test/server/inbound").unwrap();
+        let mut remote = TcpStream::connect(server.local_addr()).unwrap();
+        assert!(wait_until(|| server.client_count() == 1));
+
+        write_frame(&mut remote, &message("/tcp_test/server/inbound", "from the client")).unwrap();
+        let received = subscriber.recv_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(received.data, "from the client");
+
+        let publisher = Publisher::new("/tcp_test/server/outbound").unwrap();
+        publisher.publish("from the server".to_string()).unwrap();
+        let forwarded = read_topic(&mut remote, "/tcp_test/server/outbound");
+        assert_eq!(forwarded.data, "from the server");
+    }
+
+    #[test]
+    fn client_exchanges_messages_with_a_server() {
+        let _bus = crate::global_bus_test_lock();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let subscriber = Subscriber::new("/tcp_test/client/inbound").unwrap();
+        let client = TcpBusClient::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut remote, _) = listener.accept().unwrap();
+        assert_eq!(client.server_addr(), listener.local_addr().unwrap());
+
+        write_frame(&mut remote, &message("/tcp_test/client/inbound", "from the server")).unwrap();
+        let received = subscriber.recv_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(received.data, "from the server");
+
+        let publisher = Publisher::new("/tcp_test/client/outbound").unwrap();
+        publisher.publish("from the client".to_string()).unwrap();
+        let forwarded = read_topic(&mut remote, "/tcp_test/client/outbound");
+        assert_eq!(forwarded.data, "from the client");
+
+        drop(remote);
+        assert!(wait_until(|| !client.is_connected()));
+    }
+
+    #[test]
+    fn peer_disconnecting_mid_frame_is_dropped() {
+        let _bus = crate::global_bus_test_lock();
+        let server = TcpBusServer::bind("127.0.0.1:0").unwrap();
+        let mut remote = TcpStream::connect(server.local_addr()).unwrap();
+        assert!(wait_until(|| server.client_count() == 1));
+
+        // Announce a 100 byte frame, send part of it and hang up.
+        remote.write_all(&100u32.to_be_bytes()).unwrap();
+        remote.write_all(b"{\"topic\":").unwrap();
+        drop(remote);
+        assert!(wait_until(|| server.client_count() == 0));
+
+        // The server keeps running and accepts the next client.
+        let publisher = Publisher::new("/tcp_test/disconnect").unwrap();
+        publisher.publish("nobody is listening".to_string()).unwrap();
+        let mut remote = TcpStream::connect(server.local_addr()).unwrap();
+        assert!(wait_until(|| server.client_count() == 1));
+        publisher.publish("reconnected".to_string()).unwrap();
+        assert_eq!(read_topic(&mut remote, "/tcp_test/disconnect").data, "reconnected");
+    }
+}
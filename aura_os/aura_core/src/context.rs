@@ -0,0 +1,199 @@
+// aura_os/aura_core/src/context.rs
+
+//! The shared state nodes and communication endpoints run in.
+//!
+//! A [`Context`] owns a message bus, a node registry, taps and a shutdown token.
+//! Publishers, subscribers and nodes in different contexts never see each other: a
+//! message published in one context reaches only subscribers (and taps) of the same
+//! context, and node names only collide within a context. That makes it possible to
+//! run several independent systems in one process, e.g. tests running in parallel.
+//!
+//! Everything created without an explicit context (`Node::new`, `Publisher::new`,
+//! `Subscriber::new`, ...) uses the global context, as do the free functions
+//! `list_topics`, `list_nodes`, `request_shutdown` and friends.
+//!
+//! Some things are not per context. Inter-process transports only carry the global
+//! context's messages, so a custom context never reaches, or hears from, other
+//! processes. Interceptors, topic statistics and the dead-letter handler are
+//! process-wide and see the messages of every context.
+//!
+//! Example:
+//! ```ignore
+//! let context = Context::new();
+//! let node = NodeBuilder::new("talker").context(Arc::clone(&context)).build()?;
+//! let publisher = node.create_publisher("chatter")?; // Only visible within `context`.
+//! ```
+
+use crate::comm::graph::{self, GraphSnapshot, TopicInfo};
+use crate::comm::serde_format::{self, SerializeFormat};
+use crate::comm::tap::TapRegistry;
+use crate::error::Result;
+use crate::node::registry::{NodeInfo, NodeRegistry};
+use crate::MessageBus;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::time::Duration;
+
+lazy_static::lazy_static! {
+    static ref GLOBAL_CONTEXT: Arc<Context> = Context::new();
+}
+
+/// A message bus, node registry, taps and shutdown token shared by the nodes and
+/// endpoints created in it. See the [module docs](self).
+///
+/// Isolation covers the bus, the registry, taps and shutdown, but not everything: only
+/// the global context is connected to other processes by the TCP transport, and
+/// interceptors, topic statistics and the dead-letter handler are shared by all
+/// contexts in the process.
+#[derive(Debug, Default)]
+pub struct Context {
+    pub(crate) bus: MessageBus,
+    pub(crate) nodes: NodeRegistry,
+    pub(crate) taps: TapRegistry,
+    shutdown: ShutdownToken,
+    /// Wire format for endpoints whose QoS does not set one.
+    serialize_format: Mutex<SerializeFormat>,
+}
+
+impl Context {
+    /// Creates a new context, isolated from every other one.
+    pub fn new() -> Arc<Context> {
+        Arc::new(Context::default())
+    }
+
+    /// Returns the global context, used whenever no context is given explicitly.
+    pub fn global() -> Arc<Context> {
+        Arc::clone(&GLOBAL_CONTEXT)
+    }
+
+    /// Returns `true` if this is the global context.
+    pub fn is_global(&self) -> bool {
+        std::ptr::eq(self, &**GLOBAL_CONTEXT)
+    }
+
+    /// Lists the topics on this context's bus; see [`comm::list_topics`](crate::comm::list_topics).
+    pub fn list_topics(&self) -> Vec<TopicInfo> {
+        graph::list_topics_on(&self.bus)
+    }
+
+    /// Takes a snapshot of this context's communication graph; see [`comm::graph::snapshot`](crate::comm::graph::snapshot).
+    pub fn graph_snapshot(&self) -> GraphSnapshot {
+        graph::snapshot_of(&self.bus)
+    }
+
+    /// Returns every live node in this context, sorted by fully qualified name.
+    pub fn list_nodes(&self) -> Vec<NodeInfo> {
+        self.nodes.list()
+    }
+
+    /// Looks up a live node in this context by fully qualified name.
+    pub fn find_node(&self, fully_qualified_name: &str) -> Option<NodeInfo> {
+        self.nodes.find(fully_qualified_name)
+    }
+
+    /// Returns the wire format used by endpoints whose QoS does not set
+    /// `serialize_format`. Defaults to `SerializeFormat::Json`.
+    pub fn serialize_format(&self) -> SerializeFormat {
+        *self.serialize_format.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Changes the default wire format of this context's endpoints; see
+    /// [`serde_format`](crate::comm::serde_format). Publishers and subscribers pick it up
+    /// on their next encode or decode.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::SerializationError` if the format's feature is not enabled.
+    pub fn set_serialize_format(&self, format: SerializeFormat) -> Result<()> {
+        if !format.is_available() {
+            return Err(serde_format::unavailable(format));
+        }
+        *self.serialize_format.lock().unwrap_or_else(PoisonError::into_inner) = format;
+        Ok(())
+    }
+
+    /// Returns this context's shutdown token.
+    pub fn shutdown_token(&self) -> &ShutdownToken {
+        &self.shutdown
+    }
+}
+
+/// A one-shot signal telling the loops of a context to wind down.
+///
+/// Once requested it stays set. The flag gives a lock-free `is_requested`; the
+/// mutex/condvar pair lets `wait*` block without polling.
+#[derive(Debug, Default)]
+pub struct ShutdownToken {
+    requested: AtomicBool,
+    wait: (Mutex<()>, Condvar),
+}
+
+impl ShutdownToken {
+    /// Trips the signal and wakes every waiter. Calling it more than once is harmless.
+    pub fn request(&self) {
+        let (lock, condvar) = &self.wait;
+        // Set the flag under the lock so a waiter cannot miss the notification between
+        // checking the flag and starting to wait.
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        self.requested.store(true, Ordering::SeqCst);
+        condvar.notify_all();
+    }
+
+    /// Returns `true` once shutdown has been requested.
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    /// Blocks the calling thread until shutdown is requested.
+    pub fn wait(&self) {
+        let (lock, condvar) = &self.wait;
+        let guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let _guard = condvar
+            .wait_while(guard, |_| !self.is_requested())
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+
+    /// Blocks until shutdown is requested or `timeout` elapses, whichever comes first.
+    ///
+    /// # Returns
+    /// `true` if shutdown has been requested.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let (lock, condvar) = &self.wait;
+        let guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let _result = condvar
+            .wait_timeout_while(guard, timeout, |_| !self.is_requested())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.is_requested()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile, Subscriber};
+    use crate::node::Node;
+
+    #[test]
+    fn contexts_are_isolated() {
+        let first = Context::new();
+        let second = Context::new();
+        let publisher = Publisher::new_in_context(Arc::clone(&first), "/chatter", QosProfile::default()).unwrap();
+        let same = Subscriber::new_in_context(Arc::clone(&first), "/chatter", QosProfile::default()).unwrap();
+        let other = Subscriber::new_in_context(Arc::clone(&second), "/chatter", QosProfile::default()).unwrap();
+        publisher.publish("hello".to_string()).unwrap();
+
+        assert_eq!(same.try_recv().unwrap().unwrap().data, "hello");
+        assert!(other.try_recv().unwrap().is_none());
+
+        // Node names only collide within a context, and node endpoints stay in it.
+        let talker = Node::new_in_context(Arc::clone(&first), "talker", "").unwrap();
+        assert!(Node::new_in_context(Arc::clone(&first), "talker", "").is_err());
+        let listener = Node::new_in_context(Arc::clone(&second), "talker", "").unwrap();
+        let heard = listener.create_subscriber("/chatter").unwrap();
+        talker.create_publisher("/chatter").unwrap().publish("from first".to_string()).unwrap();
+        assert!(heard.try_recv().unwrap().is_none());
+        assert_eq!(same.try_recv().unwrap().unwrap().data, "from first");
+
+        second.shutdown_token().request();
+        assert!(!first.shutdown_token().is_requested());
+    }
+}
@@ -0,0 +1,368 @@
+// aura_os/aura_core/src/diagnostics.rs
+
+//! Standard component health reporting.
+//!
+//! Components describe their health as [`DiagnosticStatus`] entries (OK, WARN or ERROR
+//! plus a message and key/value details). A [`DiagnosticPublisher`] owned by a node
+//! publishes its current statuses on [`DIAGNOSTICS_TOPIC`] at a rate taken from the
+//! node's `diagnostics_rate_hz` parameter, and a [`DiagnosticAggregator`] keeps the
+//! latest status of every component and rolls them up into one system level.
+//!
+//! Example:
+//! ```ignore
+//! let diagnostics = DiagnosticPublisher::new(&node)?;
+//! diagnostics.update(DiagnosticStatus::new("lidar", DiagLevel::Warn, "dropping scans"));
+//!
+//! let aggregator = DiagnosticAggregator::new()?;
+//! if aggregator.overall_level() == DiagLevel::Error { /* stop the robot */ }
+//! ```
+
+use crate::comm::{Publisher, QosProfile, Subscriber};
+use crate::error::Result;
+use crate::node::Node;
+use crate::param::{ParamValue, ParameterDescriptor};
+use crate::{aura_log, AuraMessage, Context};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Topic diagnostic arrays are published on.
+pub const DIAGNOSTICS_TOPIC: &str = "/diagnostics";
+
+/// Node parameter holding a `DiagnosticPublisher`'s publish rate, in Hz.
+pub const RATE_PARAMETER: &str = "diagnostics_rate_hz";
+
+/// Publish rate used when the node does not set [`RATE_PARAMETER`].
+pub const DEFAULT_RATE_HZ: f64 = 1.0;
+
+/// How often a `DiagnosticAggregator` checks whether it should stop.
+const AGGREGATOR_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Severity of a diagnostic status, ordered from healthy to failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DiagLevel {
+    #[default]
+    Ok,
+    Warn,
+    Error,
+}
+
+impl DiagLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            DiagLevel::Ok => "OK",
+            DiagLevel::Warn => "WARN",
+            DiagLevel::Error => "ERROR",
+        }
+    }
+
+    fn parse(s: &str) -> Option<DiagLevel> {
+        match s {
+            "OK" => Some(DiagLevel::Ok),
+            "WARN" => Some(DiagLevel::Warn),
+            "ERROR" => Some(DiagLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+/// The health of one component.
+///
+/// On the wire a diagnostic array is one line per status, with tab-separated fields
+/// `LEVEL name message key=value...`; tabs, newlines, backslashes and `=` inside fields
+/// are escaped with a backslash.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DiagnosticStatus {
+    /// Component name (e.g., "lidar", "battery"). The aggregator keys statuses by it.
+    pub name: String,
+    pub level: DiagLevel,
+    /// Human-readable summary (e.g., "dropping scans").
+    pub message: String,
+    /// Extra details, such as measured values.
+    pub key_values: Vec<(String, String)>,
+}
+
+impl DiagnosticStatus {
+    /// Creates a status without key/value details.
+    pub fn new(name: &str, level: DiagLevel, message: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            level,
+            message: message.to_string(),
+            key_values: Vec::new(),
+        }
+    }
+
+    /// Returns this status with `key` = `value` added to its details.
+    pub fn with_value(mut self, key: &str, value: impl ToString) -> Self {
+        self.key_values.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Encodes `statuses` as the data of one diagnostics message.
+    pub fn encode_array(statuses: &[DiagnosticStatus]) -> String {
+        statuses
+            .iter()
+            .map(|status| {
+                let mut fields = vec![status.level.as_str().to_string(), escape(&status.name), escape(&status.message)];
+                fields.extend(status.key_values.iter().map(|(key, value)| format!("{}={}", escape(key), escape(value))));
+                fields.join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Decodes a message from [`DIAGNOSTICS_TOPIC`], or returns `None` if it is not a
+    /// diagnostic array.
+    pub fn parse_array(message: &AuraMessage) -> Option<Vec<DiagnosticStatus>> {
+        if message.data.is_empty() {
+            return Some(Vec::new());
+        }
+        message.data.split('\n').map(Self::parse_line).collect()
+    }
+
+    fn parse_line(line: &str) -> Option<DiagnosticStatus> {
+        let mut fields = line.split('\t');
+        let level = DiagLevel::parse(fields.next()?)?;
+        let name = unescape(fields.next()?)?;
+        let message = unescape(fields.next()?)?;
+        let key_values = fields
+            .map(|field| {
+                let (key, value) = field.split_once('=')?;
+                Some((unescape(key)?, unescape(value)?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(DiagnosticStatus { name, level, message, key_values })
+    }
+}
+
+/// Escapes the separators of the wire format (`=` as `\e`, so keys may contain it).
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '=' => escaped.push_str("\\e"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(field: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        unescaped.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'n' => '\n',
+            'e' => '=',
+            _ => return None,
+        });
+    }
+    Some(unescaped)
+}
+
+/// Publishes a node's component statuses on [`DIAGNOSTICS_TOPIC`] from a background
+/// thread. Dropping it stops the thread.
+///
+/// Statuses are kept until replaced by one with the same name, so each publish reports
+/// every component the node has described so far.
+#[derive(Debug)]
+pub struct DiagnosticPublisher {
+    statuses: Arc<Mutex<Vec<DiagnosticStatus>>>,
+    publisher: Arc<Publisher>,
+    /// Dropping the sender wakes the thread and tells it to exit.
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DiagnosticPublisher {
+    /// Starts publishing for `node`, at the rate in the node's [`RATE_PARAMETER`]
+    /// (declared with [`DEFAULT_RATE_HZ`] if the node has not set it).
+    ///
+    /// # Returns
+    /// The running publisher, `AuraError::ParameterConfigurationError` if the rate is not
+    /// a float between 0.001 and 1000, or an `AuraError` if the publisher or thread cannot
+    /// be created.
+    pub fn new(node: &Node) -> Result<Self> {
+        let params = node.params();
+        params.declare_parameter_with_descriptor(
+            RATE_PARAMETER,
+            ParamValue::Float(DEFAULT_RATE_HZ),
+            ParameterDescriptor::new("Rate at which diagnostic statuses are published, in Hz.")
+                .with_range(0.001, 1000.0),
+        )?;
+        let rate_hz = params.get_parameter_as::<f64>(RATE_PARAMETER)?;
+        let period = Duration::from_secs_f64(1.0 / rate_hz);
+
+        let publisher = Arc::new(node.create_publisher(DIAGNOSTICS_TOPIC)?);
+        let statuses = Arc::new(Mutex::new(Vec::new()));
+        let (stop, stop_rx) = mpsc::channel::<()>();
+
+        let thread = {
+            let publisher = Arc::clone(&publisher);
+            let statuses = Arc::clone(&statuses);
+            thread::Builder::new().name("aura-diagnostics".to_string()).spawn(move || {
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stop_rx.recv_timeout(period) {
+                    let data = DiagnosticStatus::encode_array(&statuses.lock().unwrap_or_else(PoisonError::into_inner));
+                    if let Err(e) = publisher.publish(data) {
+                        aura_log!(warn, "Failed to publish diagnostics: {}", e);
+                    }
+                }
+            })?
+        };
+
+        Ok(Self {
+            statuses,
+            publisher,
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
+    /// Records `status`, replacing any earlier status with the same name. It goes out
+    /// with the next periodic publish.
+    pub fn update(&self, status: DiagnosticStatus) {
+        let mut statuses = self.statuses.lock().unwrap_or_else(PoisonError::into_inner);
+        match statuses.iter_mut().find(|existing| existing.name == status.name) {
+            Some(existing) => *existing = status,
+            None => statuses.push(status),
+        }
+    }
+
+    /// Publishes the current statuses immediately, e.g. right after an error is detected.
+    ///
+    /// # Returns
+    /// `Ok(())`, or the same errors as `Publisher::publish`.
+    pub fn publish_now(&self) -> Result<()> {
+        let data = DiagnosticStatus::encode_array(&self.statuses.lock().unwrap_or_else(PoisonError::into_inner));
+        self.publisher.publish(data)
+    }
+}
+
+impl Drop for DiagnosticPublisher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Collects the latest status of every component from [`DIAGNOSTICS_TOPIC`].
+///
+/// The overall level is the worst level among the latest statuses, so one component in
+/// `Error` makes the whole system `Error` until it reports again. Messages are processed
+/// on the aggregator's own thread; dropping the aggregator stops it.
+#[derive(Debug)]
+pub struct DiagnosticAggregator {
+    latest: Arc<Mutex<BTreeMap<String, DiagnosticStatus>>>,
+    stop_requested: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DiagnosticAggregator {
+    /// Starts aggregating the diagnostics of the global context.
+    ///
+    /// # Returns
+    /// The running aggregator, or an `AuraError` if its subscriber or thread cannot be created.
+    pub fn new() -> Result<Self> {
+        Self::new_in_context(Context::global())
+    }
+
+    /// Like [`DiagnosticAggregator::new`], but for the diagnostics of `context`.
+    pub fn new_in_context(context: Arc<Context>) -> Result<Self> {
+        let subscriber = Subscriber::new_in_context(context, DIAGNOSTICS_TOPIC, QosProfile::default())?;
+        let latest = Arc::new(Mutex::new(BTreeMap::new()));
+        let stop_requested = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let latest = Arc::clone(&latest);
+            let stop_requested = Arc::clone(&stop_requested);
+            thread::Builder::new().name("aura-diagnostics-aggregator".to_string()).spawn(move || {
+                while !stop_requested.load(Ordering::SeqCst) {
+                    let Ok(message) = subscriber.recv_timeout(AGGREGATOR_POLL_INTERVAL) else {
+                        continue;
+                    };
+                    let Some(statuses) = DiagnosticStatus::parse_array(&message) else {
+                        aura_log!(warn, "Ignoring malformed diagnostics message #{}", message.seq);
+                        continue;
+                    };
+                    let mut latest = latest.lock().unwrap_or_else(PoisonError::into_inner);
+                    for status in statuses {
+                        latest.insert(status.name.clone(), status);
+                    }
+                }
+            })?
+        };
+
+        Ok(Self {
+            latest,
+            stop_requested,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the worst level among the latest statuses, or `DiagLevel::Ok` if no
+    /// component has reported yet.
+    pub fn overall_level(&self) -> DiagLevel {
+        let latest = self.latest.lock().unwrap_or_else(PoisonError::into_inner);
+        latest.values().map(|status| status.level).max().unwrap_or_default()
+    }
+
+    /// Returns the latest status of the component `name`, if it has reported.
+    pub fn status(&self, name: &str) -> Option<DiagnosticStatus> {
+        self.latest.lock().unwrap_or_else(PoisonError::into_inner).get(name).cloned()
+    }
+
+    /// Returns the latest status of every component, sorted by name.
+    pub fn statuses(&self) -> Vec<DiagnosticStatus> {
+        self.latest.lock().unwrap_or_else(PoisonError::into_inner).values().cloned().collect()
+    }
+}
+
+impl Drop for DiagnosticAggregator {
+    fn drop(&mut self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::NodeBuilder;
+    use std::time::Instant;
+
+    #[test]
+    fn overall_level_is_worst_reported_level() {
+        let context = Context::new();
+        let aggregator = DiagnosticAggregator::new_in_context(Arc::clone(&context)).unwrap();
+        let node = NodeBuilder::new("health").context(context).build().unwrap();
+        let diagnostics = DiagnosticPublisher::new(&node).unwrap();
+        diagnostics.update(DiagnosticStatus::new("lidar", DiagLevel::Warn, "dropping scans"));
+        diagnostics.update(DiagnosticStatus::new("battery", DiagLevel::Error, "voltage low").with_value("volts", 10.2));
+        diagnostics.publish_now().unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while aggregator.statuses().len() < 2 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(aggregator.overall_level(), DiagLevel::Error);
+        assert_eq!(aggregator.status("lidar").unwrap().level, DiagLevel::Warn);
+        let battery = aggregator.status("battery").unwrap();
+        assert_eq!(battery.key_values, [("volts".to_string(), "10.2".to_string())]);
+    }
+}
@@ -14,6 +14,8 @@ pub enum AuraError {
     InitializationFailed(String),
     /// Errors related to communication (publishing, subscribing, service calls).
     CommunicationError(String),
+    /// A communication channel was disconnected (e.g., every sender on a topic is gone).
+    ChannelDisconnected(String),
     /// Errors when a requested parameter is not found or cannot be accessed.
     ParameterNotFound(String),
     /// Errors related to parameter configuration or type mismatches.
@@ -34,6 +36,87 @@ pub enum AuraError {
     Other(String),
 }
 
+/// A stable, machine-readable identifier for each kind of `AuraError`.
+///
+/// Unlike the `AuraError` variants, codes carry no payload, so they are cheap to copy,
+/// compare and log. New codes may be added in future releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuraErrorCode {
+    InitializationFailed,
+    CommunicationError,
+    ChannelDisconnected,
+    ParameterNotFound,
+    ParameterConfigurationError,
+    NodeError,
+    ConfigurationError,
+    SerializationError,
+    TimeoutError,
+    NotImplemented,
+    IoError,
+    Other,
+}
+
+/// Coarse classification of errors, for deciding how to react to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The operation may succeed if retried (timeouts, communication hiccups).
+    Transient,
+    /// Bad parameters or configuration; retrying without changing them will not help.
+    Configuration,
+    /// The component is in a state it cannot recover from.
+    Fatal,
+    /// The requested functionality does not exist yet.
+    NotImplemented,
+}
+
+impl AuraError {
+    /// Returns the stable code identifying this kind of error.
+    pub fn code(&self) -> AuraErrorCode {
+        match self {
+            AuraError::InitializationFailed(_) => AuraErrorCode::InitializationFailed,
+            AuraError::CommunicationError(_) => AuraErrorCode::CommunicationError,
+            AuraError::ChannelDisconnected(_) => AuraErrorCode::ChannelDisconnected,
+            AuraError::ParameterNotFound(_) => AuraErrorCode::ParameterNotFound,
+            AuraError::ParameterConfigurationError(_) => AuraErrorCode::ParameterConfigurationError,
+            AuraError::NodeError(_) => AuraErrorCode::NodeError,
+            AuraError::ConfigurationError(_) => AuraErrorCode::ConfigurationError,
+            AuraError::SerializationError(_) => AuraErrorCode::SerializationError,
+            AuraError::TimeoutError(_) => AuraErrorCode::TimeoutError,
+            AuraError::NotImplemented(_) => AuraErrorCode::NotImplemented,
+            AuraError::IoError(_) => AuraErrorCode::IoError,
+            AuraError::Other(_) => AuraErrorCode::Other,
+        }
+    }
+
+    /// Returns the category of this error, so callers can decide whether to retry
+    /// without matching on every variant.
+    ///
+    /// A disconnected channel does not come back, so it is `Fatal` rather than
+    /// `Transient`. Errors with no clear category (I/O, serialization, `Other`) are also
+    /// treated as `Fatal`.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            AuraError::TimeoutError(_) | AuraError::CommunicationError(_) => ErrorCategory::Transient,
+            AuraError::ParameterNotFound(_)
+            | AuraError::ParameterConfigurationError(_)
+            | AuraError::ConfigurationError(_) => ErrorCategory::Configuration,
+            AuraError::NotImplemented(_) => ErrorCategory::NotImplemented,
+            AuraError::InitializationFailed(_)
+            | AuraError::NodeError(_)
+            | AuraError::ChannelDisconnected(_)
+            | AuraError::SerializationError(_)
+            | AuraError::IoError(_)
+            | AuraError::Other(_) => ErrorCategory::Fatal,
+        }
+    }
+
+    /// Returns `true` if retrying the operation might succeed.
+    pub fn is_transient(&self) -> bool {
+        self.category() == ErrorCategory::Transient
+    }
+}
+
 // Implement the `Display` trait to provide a user-friendly representation of the error.
 // This is what users will typically see when an error is printed.
 impl fmt::Display for AuraError {
@@ -41,6 +124,7 @@ impl fmt::Display for AuraError {
         match self {
             AuraError::InitializationFailed(s) => write!(f, "AuraOS Initialization Failed: {}", s),
             AuraError::CommunicationError(s) => write!(f, "AuraOS Communication Error: {}", s),
+            AuraError::ChannelDisconnected(s) => write!(f, "AuraOS Channel Disconnected: {}", s),
             AuraError::ParameterNotFound(s) => write!(f, "AuraOS Parameter Not Found: {}", s),
             AuraError::ParameterConfigurationError(s) => write!(f, "AuraOS Parameter Configuration Error: {}", s),
             AuraError::NodeError(s) => write!(f, "AuraOS Node Error: {}", s),
@@ -78,7 +162,84 @@ impl From<std::io::Error> for AuraError {
     }
 }
 
+// Conversions from the optional serialization formats. The original error is rendered
+// into the message, since these variants carry a `String`. JSON is used for data
+// (messages, bags), so it maps to `SerializationError`. YAML and TOML are used for
+// configuration files, so they map to `ConfigurationError`.
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for AuraError {
+    fn from(err: serde_json::Error) -> Self {
+        AuraError::SerializationError(format!("JSON: {}", err))
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl From<serde_yaml::Error> for AuraError {
+    fn from(err: serde_yaml::Error) -> Self {
+        AuraError::ConfigurationError(format!("YAML: {}", err))
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for AuraError {
+    fn from(err: toml::de::Error) -> Self {
+        AuraError::ConfigurationError(format!("TOML: {}", err))
+    }
+}
+
 // Define a convenient `Result` type alias for functions within `aura_core`
 // and for users of the crate. This avoids having to write `std::result::Result<T, AuraError>`
 // everywhere.
-pub type Result<T> = std::result::Result<T, AuraError>;
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, AuraError>;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn io_error_is_reachable_through_source() {
+        let error: AuraError = std::io::Error::new(std::io::ErrorKind::NotFound, "robot.params").into();
+        let source = error.source().expect("an I/O error has a source");
+        let io_error = source.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound);
+        assert_eq!(error.to_string(), "AuraOS I/O Error: robot.params");
+
+        assert!(AuraError::Other("no cause".to_string()).source().is_none());
+    }
+
+    #[test]
+    fn every_variant_has_its_own_code_and_category() {
+        let text = || "detail".to_string();
+        let cases = vec![
+            (AuraError::InitializationFailed(text()), AuraErrorCode::InitializationFailed, ErrorCategory::Fatal),
+            (AuraError::CommunicationError(text()), AuraErrorCode::CommunicationError, ErrorCategory::Transient),
+            (AuraError::ChannelDisconnected(text()), AuraErrorCode::ChannelDisconnected, ErrorCategory::Fatal),
+            (AuraError::ParameterNotFound(text()), AuraErrorCode::ParameterNotFound, ErrorCategory::Configuration),
+            (
+                AuraError::ParameterConfigurationError(text()),
+                AuraErrorCode::ParameterConfigurationError,
+                ErrorCategory::Configuration,
+            ),
+            (AuraError::NodeError(text()), AuraErrorCode::NodeError, ErrorCategory::Fatal),
+            (AuraError::ConfigurationError(text()), AuraErrorCode::ConfigurationError, ErrorCategory::Configuration),
+            (AuraError::SerializationError(text()), AuraErrorCode::SerializationError, ErrorCategory::Fatal),
+            (AuraError::TimeoutError(text()), AuraErrorCode::TimeoutError, ErrorCategory::Transient),
+            (AuraError::NotImplemented(text()), AuraErrorCode::NotImplemented, ErrorCategory::NotImplemented),
+            (
+                AuraError::IoError(std::io::Error::other(text())),
+                AuraErrorCode::IoError,
+                ErrorCategory::Fatal,
+            ),
+            (AuraError::Other(text()), AuraErrorCode::Other, ErrorCategory::Fatal),
+        ];
+
+        let mut seen = std::collections::HashSet::new();
+        for (error, code, category) in &cases {
+            assert_eq!(error.code(), *code, "{}", error);
+            assert_eq!(error.category(), *category, "{}", error);
+            assert_eq!(error.is_transient(), *category == ErrorCategory::Transient);
+            assert!(seen.insert(error.code()), "{:?} is shared by two variants", code);
+        }
+        assert_eq!(seen.len(), 12);
+    }
+}
@@ -12,9 +12,20 @@
 // Publicly re-export modules to make their contents accessible to users of `aura_core`.
 // This defines the public API of the `aura_core` crate.
 pub mod comm;
+pub mod context;
+pub mod diagnostics;
 pub mod error;
+pub mod naming;
 pub mod node;
 pub mod param;
+#[cfg(feature = "record")]
+pub mod record;
+
+// Re-export the error types at the crate root so applications can simply write
+// `aura_core::Result<()>` without reaching into the `error` module.
+pub use error::{AuraError, AuraErrorCode, ErrorCategory, Result};
+pub use context::{Context, ShutdownToken};
+
 // As AuraOS grows, more modules will be added here:
 // pub mod lifecycle;
 // pub mod executor;
@@ -25,8 +36,17 @@ pub mod param;
 
 // For this initial sketch, we'll use a very simplified in-memory message bus.
 // In a real AuraOS, `AuraComm` would be a sophisticated, distributed system.
-use std::collections::HashMap;
-use std::sync::{mpsc, Arc, Mutex}; // mpsc for simple multi-producer, single-consumer channels
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use comm::qos::OverflowPolicy;
+use comm::subscriber::SubscriptionHandle;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, TrySendError}; // Error types shared with the std channels
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError, Weak};
+use std::time::{Duration, Instant};
 
 /// Represents a message passed within the AuraOS system.
 ///
@@ -34,36 +54,570 @@ use std::sync::{mpsc, Arc, Mutex}; // mpsc for simple multi-producer, single-con
 /// serialization traits (e.g., `serde::Serialize + serde::DeserializeOwned`),
 /// or simply `Vec<u8>` representing serialized bytes. The `topic` would also
 /// be more structured.
-#[derive(Debug, Clone)]
+///
+/// `seq` and `stamp_ns` are filled in by `Publisher::publish`. A message built by
+/// hand can leave them at their defaults with `..Default::default()`.
+///
+/// With the `serde` feature the message can be serialized, e.g. to send it over
+/// the wire or persist it. Missing `seq`/`stamp_ns` fields deserialize as zero, and a
+/// missing `sender_node`, `correlation_id` or `reply_to` as `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuraMessage {
     pub topic: String,
     pub data: String, // Simplified to String for this sketch
+    /// Position of this message in its publisher's output, starting at 0. A gap
+    /// between consecutive messages from one publisher means messages were lost.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seq: u64,
+    /// Publish time in nanoseconds since the UNIX epoch (wall clock). This is a plain
+    /// system clock reading; publishers do not use the node's
+    /// [`Clock`](crate::node::Clock) yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stamp_ns: u64,
+    /// Fully qualified name of the node whose publisher sent the message, or `None` for
+    /// a publisher created outside a node.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub sender_node: Option<String>,
+    /// Correlation id of a request sent with `Publisher::publish_and_wait`, echoed by
+    /// the reply. `None` for ordinary messages.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub correlation_id: Option<u64>,
+    /// Topic on which the sender of a request waits for the reply.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub reply_to: Option<String>,
+}
+
+/// A single subscriber registration on the message bus.
+///
+/// Asking the queue whether its receiver is still alive would mean locking it, so
+/// each entry also carries a `Weak` handle to the registration owned by the
+/// `Subscriber`. Once the subscriber is dropped the
+/// handle goes away and the entry can be pruned without a send attempt.
+#[derive(Debug)]
+pub(crate) struct SubscriberEntry {
+    /// Process-unique id of the owning subscriber, used to remove this entry explicitly.
+    pub(crate) id: u64,
+    pub(crate) sender: MessageSender,
+    /// What to do when a bounded queue is full.
+    pub(crate) overflow: OverflowPolicy,
+    /// Work-queue group, if any. Each message goes to only one member of a group.
+    pub(crate) queue_group: Option<String>,
+    pub(crate) liveness: Weak<SubscriptionHandle>,
+}
+
+impl SubscriberEntry {
+    /// Returns `true` while the owning `Subscriber` is still alive.
+    pub(crate) fn is_connected(&self) -> bool {
+        self.liveness.strong_count() > 0
+    }
+}
+
+/// A subscriber's message queue, shared by its sending and receiving ends.
+///
+/// A `VecDeque` under a mutex rather than an `mpsc` channel, so a publisher can evict
+/// the oldest message of a full queue (`OverflowPolicy::DropOldest`) and sleep on a
+/// condvar until the receiver makes room (`OverflowPolicy::Block`). Like a channel,
+/// the queue reports itself disconnected to the receiver once every sender is gone
+/// and it is empty, and to the senders once the receiver is gone.
+#[derive(Debug)]
+pub(crate) struct MessageQueue {
+    state: Mutex<QueueState>,
+    /// Capacity, or `None` for an unbounded queue.
+    depth: Option<usize>,
+    /// Signalled when a message is pushed or the last sender goes away.
+    readable: Condvar,
+    /// Signalled when a message is taken or the receiver goes away.
+    writable: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    messages: VecDeque<Arc<AuraMessage>>,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+/// Result of a send into a `MessageQueue`; `Full` and `Disconnected` hand the message back.
+pub(crate) type SendResult = std::result::Result<(), TrySendError<Arc<AuraMessage>>>;
+
+impl MessageQueue {
+    /// Creates a queue holding at most `depth` messages (unbounded for `None`) and its
+    /// two ends.
+    pub(crate) fn open(depth: Option<usize>) -> (MessageSender, MessageReceiver) {
+        let queue = Arc::new(Self {
+            state: Mutex::new(QueueState {
+                messages: VecDeque::new(),
+                senders: 1,
+                receiver_alive: true,
+            }),
+            depth,
+            readable: Condvar::new(),
+            writable: Condvar::new(),
+        });
+        let sender = MessageSender {
+            queue: Arc::clone(&queue),
+            max_backlog: None,
+            arrivals: None,
+        };
+        (sender, MessageReceiver { queue })
+    }
+
+    // A panic while the lock is held cannot leave the deque inconsistent, so a
+    // poisoned lock is recovered.
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_full(&self, state: &QueueState) -> bool {
+        self.depth.is_some_and(|depth| state.messages.len() >= depth)
+    }
+}
+
+/// The sending half of a subscriber's queue, held by its bus entry.
+#[derive(Debug)]
+pub(crate) struct MessageSender {
+    queue: Arc<MessageQueue>,
+    /// Backlog at which an unbounded queue stops accepting messages.
+    max_backlog: Option<usize>,
+    /// Counts every message accepted into the queue, for a subscriber's deadline watchdog.
+    arrivals: Option<Arc<AtomicU64>>,
+}
+
+impl MessageSender {
+    /// Caps an unbounded queue at `max_backlog` waiting messages.
+    pub(crate) fn with_max_backlog(mut self, max_backlog: Option<usize>) -> Self {
+        self.max_backlog = max_backlog;
+        self
+    }
+
+    /// Makes the sender count every message it delivers in `arrivals`.
+    pub(crate) fn counting_arrivals(mut self, arrivals: Arc<AtomicU64>) -> Self {
+        self.arrivals = Some(arrivals);
+        self
+    }
+
+    /// Returns `true` if the queue has reached its `max_backlog`.
+    pub(crate) fn is_backlog_full(&self) -> bool {
+        self.max_backlog
+            .is_some_and(|max| self.queue.lock().messages.len() >= max)
+    }
+
+    /// Sends without blocking. Only a bounded queue can report `Full`.
+    pub(crate) fn try_send(&self, message: Arc<AuraMessage>) -> SendResult {
+        let mut state = self.queue.lock();
+        if !state.receiver_alive {
+            return Err(TrySendError::Disconnected(message));
+        }
+        if self.queue.is_full(&state) {
+            return Err(TrySendError::Full(message));
+        }
+        self.push(&mut state, message);
+        Ok(())
+    }
+
+    /// Sends without blocking, evicting the oldest queued message if the queue is full.
+    ///
+    /// # Returns
+    /// The evicted message, if any, or `Disconnected` if the receiver is gone.
+    pub(crate) fn send_evicting(&self, message: Arc<AuraMessage>) -> std::result::Result<Option<Arc<AuraMessage>>, TrySendError<Arc<AuraMessage>>> {
+        let mut state = self.queue.lock();
+        if !state.receiver_alive {
+            return Err(TrySendError::Disconnected(message));
+        }
+        let evicted = if self.queue.is_full(&state) { state.messages.pop_front() } else { None };
+        self.push(&mut state, message);
+        Ok(evicted)
+    }
+
+    /// Sends, sleeping until the receiver makes room if the queue is full.
+    ///
+    /// # Returns
+    /// `Ok(())`, `Full` if `deadline` passed with the queue still full, or
+    /// `Disconnected` if the receiver is (or goes) away.
+    pub(crate) fn send_before(&self, message: Arc<AuraMessage>, deadline: Instant) -> SendResult {
+        let mut state = self.queue.lock();
+        loop {
+            if !state.receiver_alive {
+                return Err(TrySendError::Disconnected(message));
+            }
+            if !self.queue.is_full(&state) {
+                self.push(&mut state, message);
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(TrySendError::Full(message));
+            }
+            state = self
+                .queue
+                .writable
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    fn push(&self, state: &mut QueueState, message: Arc<AuraMessage>) {
+        state.messages.push_back(message);
+        self.queue.readable.notify_one();
+        if let Some(arrivals) = &self.arrivals {
+            arrivals.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Clone for MessageSender {
+    fn clone(&self) -> Self {
+        self.queue.lock().senders += 1;
+        Self {
+            queue: Arc::clone(&self.queue),
+            max_backlog: self.max_backlog,
+            arrivals: self.arrivals.clone(),
+        }
+    }
 }
 
-// Simulate a central message bus or topic registry using lazy_static for global initialization.
-// - `Arc<Mutex<...>>` is a common pattern for thread-safe shared mutable state in Rust.
-// - The `HashMap` maps a topic name (String) to a list of `mpsc::Sender` channels.
-//   Each sender corresponds to an active subscriber on that topic.
-//
-// CAVEAT: This global, mutex-protected HashMap is a major simplification and would be a
-// bottleneck in a real, high-performance system. It serves only to demonstrate the
-// basic pub/sub interaction within a single process for this sketch.
-lazy_static::lazy_static! {
-    pub(crate) static ref MESSAGE_BUS: Arc<Mutex<HashMap<String, Vec<mpsc::Sender<AuraMessage>>>>> =
-        Arc::new(Mutex::new(HashMap::new()));
+impl Drop for MessageSender {
+    /// Wakes the receiver once the last sender is gone, so it can report the disconnection.
+    fn drop(&mut self) {
+        let mut state = self.queue.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            self.queue.readable.notify_all();
+        }
+    }
+}
+
+/// The receiving half of a subscriber's queue, held by the subscriber.
+#[derive(Debug)]
+pub(crate) struct MessageReceiver {
+    queue: Arc<MessageQueue>,
+}
+
+impl MessageReceiver {
+    /// Takes the oldest message that passes the receive interceptors, waiting at most
+    /// `timeout` for one.
+    pub(crate) fn recv_timeout(&self, timeout: Duration) -> std::result::Result<Arc<AuraMessage>, mpsc::RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(message) = comm::middleware::receive(self.recv_before(deadline)?) {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// Takes the oldest message, waiting until `deadline` for one.
+    fn recv_before(&self, deadline: Instant) -> std::result::Result<Arc<AuraMessage>, mpsc::RecvTimeoutError> {
+        let mut state = self.queue.lock();
+        loop {
+            if let Some(message) = self.pop(&mut state) {
+                return Ok(message);
+            }
+            if state.senders == 0 {
+                return Err(mpsc::RecvTimeoutError::Disconnected);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(mpsc::RecvTimeoutError::Timeout);
+            }
+            state = self
+                .queue
+                .readable
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// Takes the oldest message that passes the receive interceptors without waiting.
+    pub(crate) fn try_recv(&self) -> std::result::Result<Arc<AuraMessage>, mpsc::TryRecvError> {
+        loop {
+            let popped = {
+                let mut state = self.queue.lock();
+                match self.pop(&mut state) {
+                    Some(message) => message,
+                    None if state.senders == 0 => return Err(mpsc::TryRecvError::Disconnected),
+                    None => return Err(mpsc::TryRecvError::Empty),
+                }
+            };
+            if let Some(message) = comm::middleware::receive(popped) {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// Takes every queued message that passes the receive interceptors.
+    pub(crate) fn drain(&self) -> Vec<Arc<AuraMessage>> {
+        let messages: Vec<_> = {
+            let mut state = self.queue.lock();
+            let messages = state.messages.drain(..).collect();
+            self.queue.writable.notify_all();
+            messages
+        };
+        messages.into_iter().filter_map(comm::middleware::receive).collect()
+    }
+
+    /// Returns the number of queued messages.
+    pub(crate) fn len(&self) -> usize {
+        self.queue.lock().messages.len()
+    }
+
+    fn pop(&self, state: &mut QueueState) -> Option<Arc<AuraMessage>> {
+        let message = state.messages.pop_front()?;
+        self.queue.writable.notify_one();
+        Some(message)
+    }
+}
+
+impl Drop for MessageReceiver {
+    /// Disconnects the queue and wakes publishers waiting for room in it.
+    fn drop(&mut self) {
+        let mut state = self.queue.lock();
+        state.receiver_alive = false;
+        state.messages.clear();
+        self.queue.writable.notify_all();
+    }
+}
+
+/// Removes registrations whose `Subscriber` has been dropped.
+pub(crate) fn prune_disconnected(entries: &mut Vec<SubscriberEntry>) {
+    entries.retain(SubscriberEntry::is_connected);
+}
+
+/// Returns `true` if `topic_name` lies under the namespace `prefix` ("/" covers every topic).
+pub(crate) fn prefix_matches(prefix: &str, topic_name: &str) -> bool {
+    prefix == "/" || topic_name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Number of independently locked shards in the message bus.
+const BUS_SHARD_COUNT: usize = 16;
+
+/// Bus state for a single topic.
+#[derive(Debug, Default)]
+pub(crate) struct TopicEntry {
+    pub(crate) subscribers: Vec<SubscriberEntry>,
+    /// Set once a subscriber has registered, so a publish after every subscriber has
+    /// gone (and been pruned) is told apart from one to a topic that never had any.
+    pub(crate) had_subscribers: bool,
+    /// Publishers on the topic, pruned lazily once dropped.
+    pub(crate) publishers: Vec<Weak<comm::publisher::PublicationHandle>>,
+    /// Last message from a transient-local publisher, replayed to new subscribers.
+    pub(crate) latched: Option<Arc<AuraMessage>>,
+    /// Live transient-local publishers; the latched message is released when this
+    /// drops to zero.
+    pub(crate) latching_publishers: usize,
+    /// Message type declared by the typed publishers and subscribers on this topic.
+    pub(crate) message_type: Option<&'static str>,
+    /// Live typed publishers and subscribers; the type is forgotten when this drops to zero.
+    pub(crate) typed_endpoints: usize,
+}
+
+impl TopicEntry {
+    /// Records a typed endpoint of `type_name` on this topic.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::CommunicationError` if the topic already carries another type.
+    pub(crate) fn claim_type(&mut self, topic_name: &str, type_name: &'static str) -> Result<()> {
+        match self.message_type {
+            Some(existing) if existing != type_name => Err(AuraError::CommunicationError(format!(
+                "Type mismatch on topic '{}': it carries '{}', not '{}'.",
+                topic_name, existing, type_name
+            ))),
+            _ => {
+                self.message_type = Some(type_name);
+                self.typed_endpoints += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Releases a typed endpoint recorded by `claim_type`.
+    pub(crate) fn release_type(&mut self) {
+        self.typed_endpoints = self.typed_endpoints.saturating_sub(1);
+        if self.typed_endpoints == 0 {
+            self.message_type = None;
+        }
+    }
+}
+
+/// Topics held by a single bus shard.
+pub(crate) type TopicMap = HashMap<String, TopicEntry>;
+
+/// The in-process message bus of a [`Context`], split into independently locked shards.
+///
+/// Each topic lives in exactly one shard, chosen by hashing its name, so publishing
+/// to topic A only contends with operations on topics that happen to share A's shard
+/// rather than with every publish and subscribe in the process.
+#[derive(Debug)]
+pub(crate) struct MessageBus {
+    shards: Vec<Mutex<TopicMap>>,
+    /// Prefix subscribers, keyed by the namespace whose topics they receive (e.g.,
+    /// "/sensors", or "/" for every topic).
+    prefixes: Mutex<HashMap<String, Vec<SubscriberEntry>>>,
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self {
+            shards: (0..BUS_SHARD_COUNT).map(|_| Mutex::new(HashMap::new())).collect(),
+            prefixes: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl MessageBus {
+    /// Locks the shard responsible for `topic_name`, tracking the hold time (see
+    /// [`lock_shard`]).
+    #[track_caller]
+    pub(crate) fn lock(&self, topic_name: &str) -> BusGuard<'_> {
+        lock_shard(self.shard_for(topic_name))
+    }
+
+    /// Returns the shard responsible for `topic_name`.
+    pub(crate) fn shard_for(&self, topic_name: &str) -> &Mutex<TopicMap> {
+        let mut hasher = DefaultHasher::new();
+        topic_name.hash(&mut hasher);
+        &self.shards[(hasher.finish() as usize) % self.shards.len()]
+    }
+
+    /// Locks the prefix subscriber registrations. Never taken while a shard is held.
+    pub(crate) fn lock_prefixes(&self) -> MutexGuard<'_, HashMap<String, Vec<SubscriberEntry>>> {
+        self.prefixes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Iterates over every shard, for operations that must visit all topics.
+    ///
+    /// Shards are locked one at a time by callers, so a walk over all of them is not
+    /// an atomic snapshot of the whole bus.
+    pub(crate) fn shards(&self) -> impl Iterator<Item = &Mutex<TopicMap>> {
+        self.shards.iter()
+    }
+}
+
+/// In debug builds, holding a bus shard lock longer than this logs a warning.
+#[cfg(debug_assertions)]
+pub(crate) const BUS_LOCK_WARN_THRESHOLD: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Number of bus shard holds that exceeded `BUS_LOCK_WARN_THRESHOLD` (debug builds).
+#[cfg(debug_assertions)]
+pub(crate) static SLOW_BUS_HOLDS: AtomicU64 = AtomicU64::new(0);
+
+/// Locks a bus shard. Every bus critical section should go through this (or
+/// [`MessageBus::lock`]) rather than `Mutex::lock`.
+///
+/// A slow critical section on one shard stalls every publisher whose topic hashes to
+/// it. Bus critical sections should therefore do only bookkeeping, with no logging of
+/// payloads, no user callbacks and no heavy allocation. To catch regressions, debug
+/// builds time each hold and warn, naming the caller, when it exceeds
+/// `BUS_LOCK_WARN_THRESHOLD`.
+///
+/// A shard poisoned by a panic elsewhere is recovered rather than reported: every
+/// topic map update is a single insert, retain or counter change, so no half-applied
+/// state can be observed. The poison is cleared so the warning is logged once.
+#[track_caller]
+pub(crate) fn lock_shard(shard: &Mutex<TopicMap>) -> BusGuard<'_> {
+    let call_site = Location::caller();
+    let guard = shard.lock().unwrap_or_else(|poisoned| {
+        aura_log!(warn, "Message bus shard lock was poisoned by a panicking thread; recovering at {}", call_site);
+        shard.clear_poison();
+        poisoned.into_inner()
+    });
+    BusGuard::new(guard, call_site)
+}
+
+/// A held bus shard lock. Dereferences to the shard's `TopicMap`.
+pub(crate) struct BusGuard<'a> {
+    guard: MutexGuard<'a, TopicMap>,
+    #[cfg(debug_assertions)]
+    acquired_at: std::time::Instant,
+    #[cfg(debug_assertions)]
+    call_site: &'static Location<'static>,
+}
+
+impl<'a> BusGuard<'a> {
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn new(guard: MutexGuard<'a, TopicMap>, call_site: &'static Location<'static>) -> Self {
+        Self {
+            guard,
+            #[cfg(debug_assertions)]
+            acquired_at: std::time::Instant::now(),
+            #[cfg(debug_assertions)]
+            call_site,
+        }
+    }
+}
+
+impl Deref for BusGuard<'_> {
+    type Target = TopicMap;
+
+    fn deref(&self) -> &TopicMap {
+        &self.guard
+    }
+}
+
+impl DerefMut for BusGuard<'_> {
+    fn deref_mut(&mut self) -> &mut TopicMap {
+        &mut self.guard
+    }
+}
+
+impl Drop for BusGuard<'_> {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            let held_for = self.acquired_at.elapsed();
+            if held_for > BUS_LOCK_WARN_THRESHOLD {
+                SLOW_BUS_HOLDS.fetch_add(1, Ordering::Relaxed);
+                crate::aura_log!(
+                    warn,
+                    "Message bus lock held for {:?} (threshold {:?}) at {}",
+                    held_for,
+                    BUS_LOCK_WARN_THRESHOLD,
+                    self.call_site
+                );
+            }
+        }
+    }
 }
 
 // --- Core Initialization & Shutdown ---
 
+/// Guards `init`, so its setup runs exactly once per process.
+static INIT: std::sync::Once = std::sync::Once::new();
+/// Guards `shutdown`, so its teardown runs exactly once per process.
+static SHUTDOWN: std::sync::Once = std::sync::Once::new();
+
+/// Serializes tests that use the global context's bus, so `reset_bus` in one test
+/// cannot detach the subscribers of another running in parallel.
+#[cfg(all(test, any(feature = "test-util", feature = "tcp", feature = "record")))]
+pub(crate) fn global_bus_test_lock() -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Initializes the AuraOS core environment.
 ///
 /// This function should be called once at the beginning of an AuraOS application.
+/// Only the first call does anything; later calls (including concurrent ones, which
+/// wait for the first to finish) are no-ops, so setup such as joining the bus is never
+/// done twice. See [`is_initialized`].
+///
 /// In a real system, this would perform tasks such as:
 /// - Setting up global logging and tracing.
 /// - Initializing the parameter server client.
 /// - Starting core scheduler threads or executors.
 /// - Initializing the AuraComm layer (e.g., network discovery, transport setup).
 pub fn init() {
+    if INIT.is_completed() {
+        crate::aura_log!(debug, "aura_core::init called again; already initialized.");
+        return;
+    }
+    INIT.call_once(init_once);
+}
+
+/// The setup behind [`init`].
+fn init_once() {
     // For now, just a print statement.
     // In a real system, use a proper logging facade like `log` or `tracing`.
     println!("[AuraCore] Initializing AuraOS environment (v{})...", env!("CARGO_PKG_VERSION"));
@@ -71,6 +625,10 @@ pub fn init() {
     // Example: Initialize a global logger (if not handled by an external crate)
     // setup_global_logger();
 
+    // Join the inter-process bus named by `AURA_BUS_ADDR`, if any.
+    #[cfg(feature = "tcp")]
+    comm::transport::join_from_env();
+
     // Example: Connect to a (hypothetical) global parameter service
     // global_parameter_service::connect();
 }
@@ -85,8 +643,28 @@ pub fn init() {
 /// - Flush any buffered logs.
 /// - Disconnect from network services.
 /// - Release any globally held resources.
+///
+/// Only the first call does anything; later calls are no-ops.
 pub fn shutdown() {
+    if SHUTDOWN.is_completed() {
+        crate::aura_log!(debug, "aura_core::shutdown called again; already shut down.");
+        return;
+    }
+    SHUTDOWN.call_once(shutdown_once);
+}
+
+/// Returns `true` once [`init`] has run and until [`shutdown`] is called.
+pub fn is_initialized() -> bool {
+    INIT.is_completed() && !SHUTDOWN.is_completed()
+}
+
+/// The teardown behind [`shutdown`].
+fn shutdown_once() {
     println!("[AuraCore] Shutting down AuraOS environment...");
+    request_shutdown();
+
+    #[cfg(feature = "tcp")]
+    comm::transport::leave();
 
     // Example: Signal all nodes to shutdown
     // node_manager::signal_all_nodes_to_shutdown();
@@ -96,6 +674,54 @@ pub fn shutdown() {
     // global_logger::flush();
 }
 
+// --- Shutdown Signal ---
+
+/// Trips the global context's shutdown signal without tearing anything down.
+///
+/// Loops and background threads that check [`is_shutting_down`] (or block in
+/// [`wait_for_shutdown`]) observe it and wind down. Calling it more than once is harmless.
+/// [`shutdown`] and the handler installed by [`install_signal_handler`] both call this.
+/// Other contexts have their own [`ShutdownToken`].
+pub fn request_shutdown() {
+    Context::global().shutdown_token().request();
+}
+
+/// Returns `true` once shutdown has been requested.
+pub fn is_shutting_down() -> bool {
+    Context::global().shutdown_token().is_requested()
+}
+
+/// Blocks the calling thread until shutdown is requested.
+pub fn wait_for_shutdown() {
+    Context::global().shutdown_token().wait();
+}
+
+/// Blocks until shutdown is requested or `timeout` elapses, whichever comes first.
+///
+/// Handy as an interruptible sleep in periodic loops.
+///
+/// # Returns
+/// `true` if shutdown has been requested.
+pub fn wait_for_shutdown_timeout(timeout: std::time::Duration) -> bool {
+    Context::global().shutdown_token().wait_timeout(timeout)
+}
+
+/// Installs a process-wide SIGINT/SIGTERM (Ctrl-C) handler that calls [`request_shutdown`].
+///
+/// Only one such handler can exist per process, so this should be called once, typically
+/// right after [`init`].
+///
+/// # Returns
+/// `Ok(())`, or `AuraError::InitializationFailed` if a signal handler is already
+/// installed or cannot be registered.
+pub fn install_signal_handler() -> Result<()> {
+    ctrlc::set_handler(|| {
+        println!("\n[AuraCore] Termination signal received, requesting shutdown...");
+        request_shutdown();
+    })
+    .map_err(|e| AuraError::InitializationFailed(format!("Failed to install signal handler: {}", e)))
+}
+
 // --- Utility Macros (Internal to aura_core) ---
 
 /// A simple internal logging macro for `aura_core`.
@@ -106,20 +732,141 @@ pub fn shutdown() {
 /// within this sketch.
 ///
 /// Example: `aura_log!(info, "Node {} created successfully.", node_name);`
+#[macro_export]
 macro_rules! aura_log {
     ($level:ident, $($arg:tt)*) => {
         // Simple println-based logging.
         // `stringify!($level)` converts the identifier `info`, `warn`, etc., to a string.
         // `file!()` and `line!()` give the source location of the log call.
-        println!(
+        $crate::node::logger::write_line(format!(
             "[AuraCore::{}] [{}:{}] {}",
             stringify!($level).to_uppercase(),
             file!(),
             line!(),
             format!($($arg)*)
-        );
+        ));
+    };
+}
+/// Logs through a node's [`Logger`](crate::node::Logger), tagging the line with the
+/// node's fully qualified name.
+///
+/// Example: `node_log!(node.logger(), warn, "Battery at {}%", percent);`
+#[macro_export]
+macro_rules! node_log {
+    ($logger:expr, $level:ident, $($arg:tt)*) => {
+        $logger.log(stringify!($level), file!(), line!(), format_args!($($arg)*))
     };
 }
-// Make the macro available for use *within* the `aura_core` crate (e.g., in submodules).
-// It's not part of the public API unless explicitly re-exported with `pub use`.
-pub(crate) use aura_log;
\ No newline at end of file
+// `#[macro_export]` places the macro at the crate root, so it is available both
+// *within* `aura_core` (as `crate::aura_log`) and to applications (as `aura_core::aura_log!`).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile, Subscriber};
+    use std::thread;
+
+    #[test]
+    fn init_runs_once_and_is_reported() {
+        // No other test initializes or shuts down the process, and shutting down would
+        // trip the global context under every other test, so only init is covered here.
+        assert!(!is_initialized());
+        init();
+        assert!(is_initialized());
+        init();
+        assert!(is_initialized());
+        assert!(!is_shutting_down());
+    }
+
+    #[test]
+    fn sharded_bus_delivers_under_concurrent_load() {
+        const THREADS: usize = 8;
+        const MESSAGES: usize = 500;
+        let context = Context::new();
+        let topics: Vec<String> = (0..THREADS).map(|index| format!("/stress/topic{}", index)).collect();
+        let shards: std::collections::HashSet<*const Mutex<TopicMap>> =
+            topics.iter().map(|topic| context.bus.shard_for(topic) as *const _).collect();
+        assert!(shards.len() > 1, "all test topics hash to one shard");
+
+        let workers: Vec<_> = topics
+            .iter()
+            .map(|topic| {
+                let context = Arc::clone(&context);
+                let topic = topic.clone();
+                thread::spawn(move || {
+                    let subscriber = Subscriber::new_in_context(Arc::clone(&context), &topic, QosProfile::default()).unwrap();
+                    let publisher = Publisher::new_in_context(Arc::clone(&context), &topic, QosProfile::default()).unwrap();
+                    for value in 0..MESSAGES {
+                        publisher.publish(value.to_string()).unwrap();
+                        // Churn registrations on the same shard while publishing.
+                        if value % 50 == 0 {
+                            drop(Subscriber::new_in_context(Arc::clone(&context), &topic, QosProfile::default()).unwrap());
+                        }
+                    }
+                    subscriber.drain().into_iter().map(|message| message.data).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let received = worker.join().unwrap();
+            let expected: Vec<String> = (0..MESSAGES).map(|value| value.to_string()).collect();
+            assert_eq!(received, expected);
+        }
+        // Every subscriber is gone with its thread; the churned ones included.
+        let counts: Vec<usize> = context.list_topics().iter().map(|topic| topic.subscriber_count).collect();
+        assert_eq!(counts, [0; THREADS]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn long_shard_hold_is_reported() {
+        let bus = MessageBus::default();
+        let before = SLOW_BUS_HOLDS.load(Ordering::Relaxed);
+        {
+            let _guard = bus.lock("/slow");
+            thread::sleep(BUS_LOCK_WARN_THRESHOLD * 2);
+        }
+        assert!(SLOW_BUS_HOLDS.load(Ordering::Relaxed) > before);
+    }
+
+    #[test]
+    fn poisoned_shard_is_recovered() {
+        let context = Context::new();
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/poisoned", QosProfile::default()).unwrap();
+        let panicking = Arc::clone(&context);
+        let _ = thread::spawn(move || {
+            let _guard = panicking.bus.lock("/poisoned");
+            panic!("poison the shard");
+        })
+        .join();
+        assert!(context.bus.shard_for("/poisoned").is_poisoned());
+
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/poisoned", QosProfile::default()).unwrap();
+        publisher.publish("still works".to_string()).unwrap();
+        assert_eq!(subscriber.try_recv().unwrap().unwrap().data, "still works");
+        assert!(!context.bus.shard_for("/poisoned").is_poisoned());
+    }
+
+    #[test]
+    fn blocked_publisher_does_not_hold_the_bus() {
+        let context = Context::new();
+        let qos = QosProfile::default().depth(1).overflow(OverflowPolicy::Block).publish_timeout(Duration::from_secs(2));
+        let subscriber = Subscriber::new_in_context(Arc::clone(&context), "/backpressure", qos.clone()).unwrap();
+        let publisher = Publisher::new_in_context(Arc::clone(&context), "/backpressure", qos).unwrap();
+        publisher.publish("first".to_string()).unwrap();
+        let blocked = thread::spawn(move || publisher.publish("second".to_string()));
+        thread::sleep(Duration::from_millis(50));
+        assert!(!blocked.is_finished(), "publish to a full blocking queue returned early");
+
+        // The waiting publisher has released the shard, so the topic stays usable.
+        let started = Instant::now();
+        let late = Subscriber::new_in_context(Arc::clone(&context), "/backpressure", QosProfile::default()).unwrap();
+        assert_eq!(context.list_topics()[0].subscriber_count, 2);
+        assert!(started.elapsed() < Duration::from_millis(500), "bus was held for {:?}", started.elapsed());
+        drop(late);
+
+        assert_eq!(subscriber.recv().unwrap().data, "first");
+        blocked.join().unwrap().unwrap();
+        assert_eq!(subscriber.recv().unwrap().data, "second");
+    }
+}
@@ -0,0 +1,200 @@
+// aura_os/aura_core/src/naming.rs
+
+//! # Naming Rules
+//!
+//! Node names, namespaces and topic names follow a ROS-like grammar:
+//! - A name is one or more segments separated by a single `/`, optionally with a
+//!   leading `/` (absolute name).
+//! - Segments may only contain `[A-Za-z0-9_]` and must not start with a digit.
+//! - Empty segments (`//`) and a trailing `/` are not allowed.
+//!
+//! - A name may be at most `max_name_length` bytes long and have at most
+//!   `max_depth` segments (see [`NamingLimits`]).
+//!
+//! The functions here return a human-readable reason on failure; callers wrap it in
+//! the `AuraError` variant appropriate to their context (`NodeError` for nodes,
+//! `CommunicationError` for topics).
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default for [`NamingLimits::max_name_length`].
+pub const DEFAULT_MAX_NAME_LENGTH: usize = 256;
+/// Default for [`NamingLimits::max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+static MAX_NAME_LENGTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_NAME_LENGTH);
+static MAX_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_DEPTH);
+
+/// Process-wide bounds on names, so pathological names cannot bloat registry keys
+/// and logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamingLimits {
+    /// Maximum length of a full name (topic, namespace or node name), in bytes.
+    pub max_name_length: usize,
+    /// Maximum number of `/`-separated segments in a name (namespace depth).
+    pub max_depth: usize,
+}
+
+impl Default for NamingLimits {
+    fn default() -> Self {
+        Self {
+            max_name_length: DEFAULT_MAX_NAME_LENGTH,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// Replaces the limits enforced by every validation function in this module.
+///
+/// Names validated earlier are not re-checked, so this is best called at startup.
+pub fn set_limits(limits: NamingLimits) {
+    MAX_NAME_LENGTH.store(limits.max_name_length, Ordering::Relaxed);
+    MAX_DEPTH.store(limits.max_depth, Ordering::Relaxed);
+}
+
+/// Returns the limits currently enforced.
+pub fn limits() -> NamingLimits {
+    NamingLimits {
+        max_name_length: MAX_NAME_LENGTH.load(Ordering::Relaxed),
+        max_depth: MAX_DEPTH.load(Ordering::Relaxed),
+    }
+}
+
+/// Validates `name` (relative or absolute) against the naming grammar.
+///
+/// # Returns
+/// `Ok(())` if the name is valid, or `Err(reason)` describing the first violation.
+pub fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("name must not be empty".to_string());
+    }
+    if name.len() > 1 && name.ends_with('/') {
+        return Err("name must not end with '/'".to_string());
+    }
+    let limits = limits();
+    if name.len() > limits.max_name_length {
+        return Err(format!(
+            "name is {} bytes long, exceeding the maximum of {}",
+            name.len(),
+            limits.max_name_length
+        ));
+    }
+
+    let body = name.strip_prefix('/').unwrap_or(name);
+    let depth = body.split('/').count();
+    if depth > limits.max_depth {
+        return Err(format!(
+            "name has {} segments, exceeding the maximum depth of {}",
+            depth, limits.max_depth
+        ));
+    }
+    for segment in body.split('/') {
+        validate_segment(segment)?;
+    }
+    Ok(())
+}
+
+/// Validates a base name, i.e. a single segment without any `/` (e.g., a node name).
+pub fn validate_base_name(name: &str) -> Result<(), String> {
+    if name.contains('/') {
+        return Err(format!("base name '{}' must not contain '/'", name));
+    }
+    validate_name(name)
+}
+
+/// Validates an absolute name (must start with `/`), such as a resolved topic name.
+pub fn validate_absolute_name(name: &str) -> Result<(), String> {
+    if !name.starts_with('/') {
+        return Err(format!("name '{}' must be absolute (start with '/')", name));
+    }
+    validate_name(name)
+}
+
+fn validate_segment(segment: &str) -> Result<(), String> {
+    let first = match segment.chars().next() {
+        Some(c) => c,
+        None => return Err("name must not contain empty segments ('//')".to_string()),
+    };
+    if first.is_ascii_digit() {
+        return Err(format!("segment '{}' must not start with a digit", segment));
+    }
+    if let Some(c) = segment.chars().find(|c| !(c.is_ascii_alphanumeric() || *c == '_')) {
+        return Err(format!(
+            "segment '{}' contains illegal character '{}' (allowed: A-Z, a-z, 0-9, _)",
+            segment, c
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::{Publisher, QosProfile};
+    use crate::node::NodeBuilder;
+    use crate::{AuraError, Context};
+
+    #[test]
+    fn node_names_follow_the_grammar() {
+        let cases: &[(&str, Option<&str>)] = &[
+            ("lidar_driver", None),
+            ("_private", None),
+            ("", Some("must not be empty")),
+            ("a//b", Some("must not contain '/'")),
+            ("driver/", Some("must not contain '/'")),
+            ("lidar-driver", Some("illegal character '-'")),
+            ("2nd_driver", Some("must not start with a digit")),
+        ];
+        for (name, expected) in cases {
+            match (validate_base_name(name), expected) {
+                (Ok(()), None) => {}
+                (Err(reason), Some(expected)) => assert!(reason.contains(expected), "'{}': {}", name, reason),
+                (result, _) => panic!("'{}': unexpected {:?}", name, result),
+            }
+        }
+        let rejected = NodeBuilder::new("2nd_driver").context(Context::new()).build();
+        assert!(matches!(rejected, Err(AuraError::NodeError(_))), "{:?}", rejected);
+    }
+
+    #[test]
+    fn topic_names_follow_the_grammar() {
+        let cases: &[(&str, Option<&str>)] = &[
+            ("/robot1/cmd_vel", None),
+            ("/chatter", None),
+            ("", Some("must be absolute")),
+            ("chatter", Some("must be absolute")),
+            ("/robot1//cmd_vel", Some("empty segments")),
+            ("/robot1/cmd_vel/", Some("must not end with '/'")),
+            ("/robot1/cmd-vel", Some("illegal character '-'")),
+            ("/robot1/2d_pose", Some("must not start with a digit")),
+        ];
+        for (name, expected) in cases {
+            match (validate_absolute_name(name), expected) {
+                (Ok(()), None) => {}
+                (Err(reason), Some(expected)) => assert!(reason.contains(expected), "'{}': {}", name, reason),
+                (result, _) => panic!("'{}': unexpected {:?}", name, result),
+            }
+        }
+        let rejected = Publisher::new_in_context(Context::new(), "/robot1//cmd_vel", QosProfile::default());
+        assert!(matches!(rejected, Err(AuraError::CommunicationError(_))), "{:?}", rejected);
+    }
+
+    #[test]
+    fn names_beyond_the_default_limits_are_rejected() {
+        // Uses the defaults; set_limits is process-wide, so tests leave it alone.
+        let longest = format!("/{}", "a".repeat(DEFAULT_MAX_NAME_LENGTH - 1));
+        validate_absolute_name(&longest).unwrap();
+        let too_long = format!("{}b", longest);
+        let reason = validate_absolute_name(&too_long).unwrap_err();
+        assert!(reason.contains("257 bytes long, exceeding the maximum of 256"), "{}", reason);
+
+        let deepest = "/ns".repeat(DEFAULT_MAX_DEPTH);
+        validate_absolute_name(&deepest).unwrap();
+        let too_deep = format!("{}/ns", deepest);
+        let reason = validate_absolute_name(&too_deep).unwrap_err();
+        assert!(reason.contains("33 segments, exceeding the maximum depth of 32"), "{}", reason);
+
+        let rejected = NodeBuilder::new("driver").namespace(&too_deep).context(Context::new()).build();
+        assert!(matches!(rejected, Err(AuraError::NodeError(_))), "{:?}", rejected);
+    }
+}
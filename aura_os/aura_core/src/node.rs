@@ -1,12 +1,42 @@
 // aura_os/aura_core/src/node.rs
 
-use crate::param::{ParameterManager, ParamValue}; // Assuming ParamValue might be used directly by node for defaults
-use crate::comm::{Publisher, Subscriber}; // For future methods to create these
+use crate::param::{ParamValue, ParameterManager};
+use crate::comm::{AuraMessageTrait, FromAuraMessage, MessageInfo, Publisher, QosProfile, Subscriber, TypedSubscription, DEFAULT_WORK_QUEUE_GROUP}; // For future methods to create these
 use crate::error::{AuraError, Result};
+use crate::naming;
+use crate::Context;
 use crate::aura_log; // Internal logging macro
-use std::sync::Arc;
-use std::thread; // For a conceptual unique ID, will be replaced
-use std::time::SystemTime; // For a conceptual unique ID
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime}; // SystemTime for a conceptual unique ID
+
+pub mod builder;
+pub mod clock;
+pub mod launch;
+pub mod liveliness;
+pub mod logger;
+pub mod registry;
+pub use builder::NodeBuilder;
+pub use clock::{Clock, ManualClock};
+pub use launch::LaunchManager;
+pub use logger::Logger;
+pub use registry::{list_nodes, NodeInfo};
+
+/// How long `spin_until` pauses between checks of its predicate.
+const SPIN_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A resource owned by a `Node` (publisher, subscriber, ...) that can be told to stop.
+///
+/// The node keeps `Weak` references to everything it creates and calls `stop` on
+/// each one still alive when the node is dropped.
+pub trait Stoppable: Send + Sync {
+    /// Stops the resource, releasing any registrations it holds. Must be idempotent.
+    fn stop(&self);
+}
+
+/// The entities of one kind created through a node.
+type OwnedEntities = Mutex<Vec<Weak<dyn Stoppable>>>;
 
 /// Represents a fundamental unit of computation within the AuraOS framework.
 ///
@@ -25,46 +55,120 @@ pub struct Node {
     name: String,
     namespace: String, // Nodes typically operate within a namespace
     unique_id: String, // A unique identifier for this node instance
+    /// Context the node is registered in and creates its publishers and subscribers in.
+    context: Arc<Context>,
     params: Arc<ParameterManager>, // Each node has its own parameter manager instance
+    /// Overrides given to `NodeBuilder::parameter_overrides`, passed on to sub-nodes.
+    parameter_overrides: Vec<(String, ParamValue)>,
+    /// Logger tagged with the fully qualified name.
+    logger: Logger,
+    /// Topic remappings applied by `resolve_topic_name` (source name -> target name).
+    remappings: HashMap<String, String>,
+    /// Publishers created via `create_publisher`, stopped when the node is dropped.
+    publishers: OwnedEntities,
+    /// Subscribers created via `create_subscriber`, stopped when the node is dropped.
+    subscribers: OwnedEntities,
+    /// Most live publishers the node may have at once; see `NodeBuilder::max_publishers`.
+    max_publishers: Option<usize>,
+    /// Most live subscribers the node may have at once; see `NodeBuilder::max_subscribers`.
+    max_subscribers: Option<usize>,
+    /// Heartbeat thread, if enabled with `NodeBuilder::heartbeat_period`.
+    heartbeater: Option<liveliness::Heartbeater>,
+    /// Time source read by `now`; see `NodeBuilder::clock`.
+    clock: Clock,
 
     // In a more complete implementation, a Node would hold:
-    // - `Arc<Context>`: A shared context providing access to core AuraOS services
-    //   (e.g., clock, graph manager, executor handle).
-    // - `Vec<Arc<dyn Stoppable>>`: A list of owned resources like publishers, subscribers,
-    //   timers that need to be managed during the node's lifecycle.
+    // - Executor handles, reached through its `Context`.
+    // - Timers alongside publishers and subscribers in its owned entities.
     // - `LifecycleState`: Current state of the node.
 }
 
+/// Sanitizes a namespace: ensures it starts with '/' and drops a trailing '/'. The
+/// global namespace is represented as the empty string.
+fn clean_namespace(namespace: &str) -> String {
+    if namespace.is_empty() || namespace == "/" {
+        "".to_string()
+    } else if namespace.starts_with('/') {
+        namespace.trim_end_matches('/').to_string()
+    } else {
+        format!("/{}", namespace.trim_end_matches('/'))
+    }
+}
+
 impl Node {
     /// Creates a new AuraOS node with the given name.
     ///
-    /// The node name should be unique within its namespace.
-    /// In a real system, this would involve registration with a central discovery
-    /// mechanism or node manager.
+    /// The node name must be unique within its namespace: the node registers itself in
+    /// the global [`Context`]'s [`registry`] and creation fails while another live node
+    /// has the same fully qualified name. Use [`Node::new_in_context`] to create the node
+    /// in another context.
     ///
     /// # Arguments
     /// * `name`: The desired name for the node (e.g., "lidar_driver", "path_planner").
     /// * `namespace`: The namespace for the node (e.g., "/robot1", "/perception").
-    ///                An empty string typically means the global namespace.
+    ///   An empty string typically means the global namespace.
     ///
     /// # Returns
     /// A `Result` containing the new `Node` or an `AuraError` if creation fails
     /// (e.g., name collision, initialization error).
     pub fn new(name: &str, namespace: &str) -> Result<Self> {
-        // Basic name validation (in reality, more robust validation is needed)
-        if name.is_empty() {
-            return Err(AuraError::NodeError(
-                "Node name cannot be empty.".to_string(),
-            ));
+        NodeBuilder::new(name).namespace(namespace).build()
+    }
+
+    /// Creates a new AuraOS node in `context` instead of the global context.
+    ///
+    /// The node's publishers, subscribers and registry entry all live in `context`, so
+    /// it only collides with, and talks to, nodes of the same context. For further
+    /// options, use [`NodeBuilder::context`].
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Node` or an `AuraError` if creation fails.
+    pub fn new_in_context(context: Arc<Context>, name: &str, namespace: &str) -> Result<Self> {
+        NodeBuilder::new(name).namespace(namespace).context(context).build()
+    }
+
+    /// Creates a new AuraOS node whose topic names are remapped.
+    ///
+    /// Every topic resolved through this node (for publishers and subscribers alike)
+    /// is first looked up in `remappings`, so launch setups can rewire topics without
+    /// recompiling. Keys may be either the name as passed to the node (e.g., "chatter")
+    /// or the fully resolved name (e.g., "/examples/chatter"). A relative target is
+    /// resolved against the node's namespace.
+    ///
+    /// # Arguments
+    /// * `name`: The desired name for the node.
+    /// * `namespace`: The namespace for the node.
+    /// * `remappings`: Map from source topic name to target topic name.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Node` or an `AuraError` if creation fails.
+    pub fn new_with_remappings(
+        name: &str,
+        namespace: &str,
+        remappings: HashMap<String, String>,
+    ) -> Result<Self> {
+        NodeBuilder::new(name).namespace(namespace).remappings(remappings).build()
+    }
+
+    /// Validates the names, registers the node and creates it. All public
+    /// constructors go through `NodeBuilder::build`, which calls this.
+    fn construct(
+        context: Arc<Context>,
+        name: &str,
+        namespace: &str,
+        remappings: HashMap<String, String>,
+    ) -> Result<Self> {
+        // Validate the node name against the naming grammar (single segment, no '/').
+        naming::validate_base_name(name).map_err(|reason| {
+            AuraError::NodeError(format!("Invalid node name '{}': {}.", name, reason))
+        })?;
+        let clean_namespace = clean_namespace(namespace);
+        // ...then validate what remains (e.g., no '//' or illegal characters).
+        if !clean_namespace.is_empty() {
+            naming::validate_absolute_name(&clean_namespace).map_err(|reason| {
+                AuraError::NodeError(format!("Invalid namespace '{}': {}.", namespace, reason))
+            })?;
         }
-        // Sanitize or validate namespace (e.g., ensure it starts with '/')
-        let clean_namespace = if namespace.is_empty() || namespace == "/" {
-            "".to_string() // Represent global namespace as empty for internal logic
-        } else if namespace.starts_with('/') {
-            namespace.trim_end_matches('/').to_string()
-        } else {
-            format!("/{}", namespace.trim_end_matches('/'))
-        };
 
         let fully_qualified_name = if clean_namespace.is_empty() {
             format!("/{}", name)
@@ -85,14 +189,62 @@ impl Node {
                 .as_nanos() // Using nanos for more uniqueness in quick succession
         );
 
+        // Claim the fully qualified name; fails if another live node already has it.
+        context.nodes.register(NodeInfo {
+            name: name.to_string(),
+            namespace: clean_namespace.clone(),
+            unique_id: unique_id.clone(),
+        })?;
+
         Ok(Self {
             name: name.to_string(),
             namespace: clean_namespace,
             unique_id,
+            context,
             params: Arc::new(ParameterManager::new(&fully_qualified_name)),
+            parameter_overrides: Vec::new(),
+            logger: Logger::new(&fully_qualified_name),
+            remappings,
+            publishers: Mutex::new(Vec::new()),
+            subscribers: Mutex::new(Vec::new()),
+            max_publishers: None,
+            max_subscribers: None,
+            heartbeater: None,
+            clock: Clock::default(),
         })
     }
 
+    /// Creates a node in a namespace below this one, e.g. `arm` under `/robot1` gives a
+    /// node in `/robot1/arm`, so its relative topic "cmd" resolves to `/robot1/arm/cmd`.
+    ///
+    /// The sub-node has the same base name, context, clock, parameter overrides,
+    /// parameter strictness and resource limits as this node, but its own parameters,
+    /// publishers and subscribers; it is an independent node that may outlive this one.
+    /// `sub_namespace` may have several segments (e.g. "arm/left").
+    ///
+    /// # Returns
+    /// The new `Node`, or `AuraError::NodeError` if `sub_namespace` is absolute or does
+    /// not follow the naming grammar, or the sub-node's name is already taken.
+    pub fn create_sub_node(&self, sub_namespace: &str) -> Result<Node> {
+        if sub_namespace.starts_with('/') {
+            return Err(AuraError::NodeError(format!(
+                "Invalid sub-namespace '{}': must be relative to the parent node.",
+                sub_namespace
+            )));
+        }
+        naming::validate_name(sub_namespace).map_err(|reason| {
+            AuraError::NodeError(format!("Invalid sub-namespace '{}': {}.", sub_namespace, reason))
+        })?;
+        NodeBuilder::new(&self.name)
+            .namespace(&format!("{}/{}", self.fully_qualified_name(), sub_namespace))
+            .parameter_overrides(self.parameter_overrides.clone())
+            .context(Arc::clone(&self.context))
+            .resource_limits(self.max_publishers, self.max_subscribers)
+            .strict_parameters(self.params.is_strict())
+            .clock(self.clock.clone())
+            .build()
+    }
+
     /// Returns the base name of the node.
     pub fn name(&self) -> &str {
         &self.name
@@ -112,11 +264,34 @@ impl Node {
         }
     }
 
+    /// Returns the node's logger, which tags every line with the fully qualified name.
+    ///
+    /// Use it with [`node_log!`](crate::node_log):
+    /// `aura_core::node_log!(node.logger(), info, "Ready");`
+    pub fn logger(&self) -> &Logger {
+        &self.logger
+    }
+
+    /// Returns the clock this node reads the time from.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the current time of the node's clock.
+    pub fn now(&self) -> SystemTime {
+        self.clock.now()
+    }
+
     /// Returns the unique ID of this node instance.
     pub fn unique_id(&self) -> &str {
         &self.unique_id
     }
 
+    /// Returns the context this node runs in.
+    pub fn context(&self) -> &Arc<Context> {
+        &self.context
+    }
+
     /// Gets a thread-safe, reference-counted pointer to the node's `ParameterManager`.
     /// This allows the node and other parts of the system to safely access and modify
     /// the node's parameters.
@@ -142,6 +317,43 @@ impl Node {
         // For this sketch, it does nothing. The examples will call it in their loops.
     }
 
+    /// Spins the node until `predicate` yields a value or `timeout` elapses.
+    ///
+    /// This is a sleep-poll: each iteration calls [`Node::spin_once`] and then
+    /// `predicate`, sleeping `SPIN_POLL_INTERVAL` between iterations. Since `spin_once`
+    /// does nothing yet (there is no executor), the node processes no work of its own
+    /// while waiting; the value must be produced by another thread, such as a
+    /// subscription thread or a background worker.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let response = node.spin_until(|| response_slot.lock().unwrap().take(), Duration::from_secs(1))?;
+    /// ```
+    ///
+    /// # Returns
+    /// The value, or `AuraError::TimeoutError` if `predicate` kept returning `None`.
+    pub fn spin_until<T, F>(&self, predicate: F, timeout: Duration) -> Result<T>
+    where
+        F: Fn() -> Option<T>,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.spin_once();
+            if let Some(value) = predicate() {
+                return Ok(value);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(AuraError::TimeoutError(format!(
+                    "[{}] Condition not met within {:?} while spinning.",
+                    self.fully_qualified_name(),
+                    timeout
+                )));
+            }
+            thread::sleep(SPIN_POLL_INTERVAL.min(deadline - now));
+        }
+    }
+
     // --- Methods for Creating Communication Primitives (Conceptual) ---
     // These would be the primary way nodes interact with AuraComm.
 
@@ -157,9 +369,23 @@ impl Node {
     pub fn create_publisher(&self, topic_name: &str /*, qos_profile: QosProfile */) -> Result<Publisher> {
         let resolved_topic = self.resolve_topic_name(topic_name);
         aura_log!(info, "[{}] Creating publisher for topic '{}'", self.fully_qualified_name(), resolved_topic);
-        Publisher::new(&resolved_topic)
-        // In a real system, the publisher would be associated with this node's context
-        // and potentially managed by the node for cleanup.
+        let publisher = Publisher::create(Arc::clone(&self.context), &resolved_topic, QosProfile::default(), None)?;
+        // The node tracks the publisher so it can be stopped when the node is dropped.
+        self.adopt_publisher(&publisher)?;
+        Ok(publisher)
+    }
+
+    /// Creates a publisher for a given topic with an explicit QoS profile.
+    /// The topic name will be resolved relative to the node's namespace.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Publisher` or an `AuraError`.
+    pub fn create_publisher_with_qos(&self, topic_name: &str, qos: QosProfile) -> Result<Publisher> {
+        let resolved_topic = self.resolve_topic_name(topic_name);
+        aura_log!(info, "[{}] Creating publisher for topic '{}' with {:?}", self.fully_qualified_name(), resolved_topic, qos);
+        let publisher = Publisher::create(Arc::clone(&self.context), &resolved_topic, qos, None)?;
+        self.adopt_publisher(&publisher)?;
+        Ok(publisher)
     }
 
     /// Creates a subscriber for a given topic.
@@ -175,13 +401,183 @@ impl Node {
     pub fn create_subscriber(&self, topic_name: &str /*, qos_profile: QosProfile, callback: F */) -> Result<Subscriber> {
         let resolved_topic = self.resolve_topic_name(topic_name);
         aura_log!(info, "[{}] Creating subscriber for topic '{}'", self.fully_qualified_name(), resolved_topic);
-        Subscriber::new(&resolved_topic)
-        // In a real system, the subscriber would be associated with this node's context,
-        // manage the callback, and be cleaned up by the node.
+        let subscriber = Subscriber::register(Arc::clone(&self.context), &resolved_topic, None, QosProfile::default(), None)?;
+        // The node tracks the subscriber so its bus registration is removed when the node is dropped.
+        // In a real system, the node would also manage the subscriber's callback.
+        self.adopt_subscriber(&subscriber)?;
+        Ok(subscriber)
     }
 
-    /// Helper to resolve a topic name relative to the node's namespace.
+    /// Creates a subscriber for a given topic with an explicit QoS profile.
+    /// The topic name will be resolved relative to the node's namespace.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Subscriber` or an `AuraError`.
+    pub fn create_subscriber_with_qos(&self, topic_name: &str, qos: QosProfile) -> Result<Subscriber> {
+        let resolved_topic = self.resolve_topic_name(topic_name);
+        aura_log!(info, "[{}] Creating subscriber for topic '{}' with {:?}", self.fully_qualified_name(), resolved_topic, qos);
+        let subscriber = Subscriber::register(Arc::clone(&self.context), &resolved_topic, None, qos, None)?;
+        self.adopt_subscriber(&subscriber)?;
+        Ok(subscriber)
+    }
+
+    /// Subscribes to a topic and calls `callback` with every message converted to `M`
+    /// (with the `json` feature, any deserializable type) and its [`MessageInfo`].
+    ///
+    /// The topic name is resolved relative to the node's namespace, and the subscriber
+    /// declares `M::type_name()` as the topic's type, as with `Subscriber::new_typed`.
+    /// The callback runs on the subscription's own thread. Messages that do not convert
+    /// go to the error callback set with [`TypedSubscription::on_error`] instead of
+    /// stopping the subscription.
+    ///
+    /// # Returns
+    /// The running `TypedSubscription`, which stops when dropped or when the node is
+    /// dropped, `AuraError::CommunicationError` if the topic carries a different type,
+    /// or `AuraError::IoError` if the thread cannot be started.
+    pub fn create_subscriber_typed<M, F>(&self, topic_name: &str, callback: F) -> Result<TypedSubscription>
+    where
+        M: AuraMessageTrait + FromAuraMessage,
+        F: FnMut(M, MessageInfo) + Send + 'static,
+    {
+        let resolved_topic = self.resolve_topic_name(topic_name);
+        aura_log!(info, "[{}] Creating typed subscriber for topic '{}'", self.fully_qualified_name(), resolved_topic);
+        let subscriber = Subscriber::register(
+            Arc::clone(&self.context),
+            &resolved_topic,
+            None,
+            QosProfile::default(),
+            Some(M::type_name()),
+        )?;
+        self.adopt_subscriber(&subscriber)?;
+        TypedSubscription::start(subscriber, callback)
+    }
+
+    /// Creates a work-queue subscriber for a given topic.
+    ///
+    /// All work-queue subscribers on the same topic, from any node, form one group
+    /// (`comm::DEFAULT_WORK_QUEUE_GROUP`) and share its messages round-robin, so each
+    /// message is handled by exactly one of them. Regular subscribers on the topic still
+    /// receive every message. See `Subscriber::new_in_queue_group` for custom groups.
+    ///
+    /// # Arguments
+    /// * `topic_name`: The name of the topic (e.g., "jobs").
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Subscriber` or an `AuraError`.
+    pub fn create_work_queue_subscriber(&self, topic_name: &str) -> Result<Subscriber> {
+        let resolved_topic = self.resolve_topic_name(topic_name);
+        aura_log!(info, "[{}] Creating work-queue subscriber for topic '{}'", self.fully_qualified_name(), resolved_topic);
+        let subscriber = Subscriber::register(
+            Arc::clone(&self.context),
+            &resolved_topic,
+            Some(DEFAULT_WORK_QUEUE_GROUP),
+            QosProfile::default(),
+            None,
+        )?;
+        self.adopt_subscriber(&subscriber)?;
+        Ok(subscriber)
+    }
+
+    /// Creates a subscriber for every topic under a namespace, e.g. "sensors" or
+    /// "/sensors/*"; see `Subscriber::new_prefix`. A relative prefix is resolved
+    /// against the node's namespace like a topic name.
+    ///
+    /// # Returns
+    /// A `Result` containing the new `Subscriber` or an `AuraError`.
+    pub fn create_prefix_subscriber(&self, prefix: &str) -> Result<Subscriber> {
+        let namespace = prefix.strip_suffix("/*").or_else(|| prefix.strip_suffix('/')).unwrap_or(prefix);
+        let resolved_prefix = match namespace {
+            "" | "/" => "/".to_string(),
+            namespace => self.resolve_topic_name(namespace),
+        };
+        aura_log!(info, "[{}] Creating prefix subscriber for topics under '{}'", self.fully_qualified_name(), resolved_prefix);
+        let subscriber = Subscriber::register_prefix(Arc::clone(&self.context), &resolved_prefix, QosProfile::default())?;
+        self.adopt_subscriber(&subscriber)?;
+        Ok(subscriber)
+    }
+
+    /// Returns the number of publishers created through this node that are still alive.
+    pub fn count_publishers(&self) -> usize {
+        Self::live_count(&self.publishers)
+    }
+
+    /// Returns the number of subscribers created through this node that are still alive.
+    pub fn count_subscribers(&self) -> usize {
+        Self::live_count(&self.subscribers)
+    }
+
+    /// Tracks a publisher created through this node and records the node as its owner.
+    ///
+    /// Fails with `AuraError::NodeError` if the node already has `max_publishers` live
+    /// publishers; the caller then drops the new one, which unregisters it again.
+    fn adopt_publisher(&self, publisher: &Publisher) -> Result<()> {
+        self.track(&self.publishers, publisher.stop_handle(), self.max_publishers, "publishers")?;
+        publisher.set_owner(&self.fully_qualified_name());
+        Ok(())
+    }
+
+    /// Tracks a subscriber created through this node and records the node as its owner.
+    /// Fails like `adopt_publisher` once `max_subscribers` is reached.
+    fn adopt_subscriber(&self, subscriber: &Subscriber) -> Result<()> {
+        self.track(&self.subscribers, subscriber.stop_handle(), self.max_subscribers, "subscribers")?;
+        subscriber.set_owner(&self.fully_qualified_name());
+        Ok(())
+    }
+
+    // The entity lists hold only weak references, so a panic elsewhere cannot leave
+    // them logically inconsistent; recovering from a poisoned lock is safe.
+    // The limit is checked under the same lock as the push, so concurrent creations
+    // cannot overshoot it.
+    fn track(&self, entities: &OwnedEntities, handle: Weak<dyn Stoppable>, limit: Option<usize>, kind: &str) -> Result<()> {
+        let mut entities = entities.lock().unwrap_or_else(PoisonError::into_inner);
+        entities.retain(|entity| entity.strong_count() > 0);
+        if let Some(limit) = limit.filter(|limit| entities.len() >= *limit) {
+            return Err(AuraError::NodeError(format!(
+                "[{}] Resource limit exceeded: the node may have at most {} live {}.",
+                self.fully_qualified_name(),
+                limit,
+                kind
+            )));
+        }
+        entities.push(handle);
+        Ok(())
+    }
+
+    fn live_count(entities: &OwnedEntities) -> usize {
+        let mut entities = entities.lock().unwrap_or_else(PoisonError::into_inner);
+        entities.retain(|entity| entity.strong_count() > 0);
+        entities.len()
+    }
+
+    fn stop_all(entities: &OwnedEntities) {
+        let mut entities = entities.lock().unwrap_or_else(PoisonError::into_inner);
+        for entity in entities.drain(..) {
+            if let Some(entity) = entity.upgrade() {
+                entity.stop();
+            }
+        }
+    }
+
+    /// Helper to resolve a topic name relative to the node's namespace,
+    /// applying any remapping configured for the node.
     fn resolve_topic_name(&self, topic_name: &str) -> String {
+        let resolved = self.expand_topic_name(topic_name);
+        match self
+            .remappings
+            .get(topic_name)
+            .or_else(|| self.remappings.get(&resolved))
+        {
+            Some(target) => {
+                let remapped = self.expand_topic_name(target);
+                aura_log!(debug, "[{}] Remapping topic '{}' to '{}'", self.fully_qualified_name(), resolved, remapped);
+                remapped
+            }
+            None => resolved,
+        }
+    }
+
+    /// Expands a topic name against the node's namespace, without remapping.
+    fn expand_topic_name(&self, topic_name: &str) -> String {
         if topic_name.starts_with('/') {
             // Absolute topic name
             topic_name.to_string()
@@ -197,16 +593,100 @@ impl Node {
 
 /// Ensures that node resources are cleaned up when the `Node` instance goes out of scope.
 ///
+/// A heartbeating node sends its final heartbeat. All publishers and subscribers
+/// created through the node that are still alive are stopped: subscribers have their
+/// senders removed from the message bus and publishers refuse further publishes.
+///
 /// In a real system, this `Drop` implementation would be more involved, signaling
-/// the node's shutdown to the AuraOS system, unregistering from discovery services,
-/// and ensuring all owned resources (publishers, subscribers, timers) are properly
-/// stopped and released.
+/// the node's shutdown to the AuraOS system and unregistering from discovery services.
 impl Drop for Node {
     fn drop(&mut self) {
         aura_log!(info, "Node '{}' (ID: {}) is being dropped. Performing cleanup.", self.fully_qualified_name(), self.unique_id);
+        // Stops the heartbeat thread and sends the final "bye" heartbeat.
+        drop(self.heartbeater.take());
+        Self::stop_all(&self.publishers);
+        Self::stop_all(&self.subscribers);
+        self.context.nodes.unregister(&self.fully_qualified_name(), &self.unique_id);
         // Conceptual cleanup:
-        // - Unregister from any central node manager.
-        // - Signal all owned publishers/subscribers/timers to stop.
-        // - Wait for graceful shutdown of owned resources.
+        // - Wait for graceful shutdown of owned resources (e.g., timers).
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn spin_until_sees_predicate_flipped_by_another_thread() {
+        let node = NodeBuilder::new("waiter").context(Context::new()).build().unwrap();
+        let ready = Arc::new(AtomicBool::new(false));
+        let worker = {
+            let ready = Arc::clone(&ready);
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(30));
+                ready.store(true, Ordering::SeqCst);
+            })
+        };
+
+        let value = node.spin_until(|| ready.load(Ordering::SeqCst).then_some(42), Duration::from_secs(2)).unwrap();
+        assert_eq!(value, 42);
+        worker.join().unwrap();
+
+        let result = node.spin_until(|| None::<()>, Duration::from_millis(20));
+        assert!(matches!(result, Err(AuraError::TimeoutError(_))));
+    }
+
+    #[test]
+    fn sub_node_resolves_topics_below_parent() {
+        let context = Context::new();
+        let robot = NodeBuilder::new("robot1").context(Arc::clone(&context)).build().unwrap();
+        let arm = robot.create_sub_node("arm").unwrap();
+        assert_eq!(arm.namespace(), "/robot1/arm");
+        assert_eq!(arm.create_publisher("cmd").unwrap().topic_name(), "/robot1/arm/cmd");
+        assert!(context.find_node(&arm.fully_qualified_name()).is_some());
+
+        let left = robot.create_sub_node("arm/left").unwrap();
+        assert_eq!(left.create_publisher("cmd").unwrap().topic_name(), "/robot1/arm/left/cmd");
+        assert!(robot.create_sub_node("/arm").is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn dropping_node_unregisters_its_endpoints() {
+        let context = Context::new();
+        let node = NodeBuilder::new("owner").context(Arc::clone(&context)).build().unwrap();
+        let publisher = node.create_publisher("chatter").unwrap();
+        let subscriber = node.create_subscriber("chatter").unwrap();
+        assert_eq!((node.count_publishers(), node.count_subscribers()), (1, 1));
+
+        let observer = Publisher::new_in_context(Arc::clone(&context), "/chatter", QosProfile::default()).unwrap();
+        assert_eq!(observer.get_num_subscribers().unwrap(), 1);
+
+        drop(node);
+        assert_eq!(observer.get_num_subscribers().unwrap(), 0);
+        assert!(matches!(publisher.publish("late".to_string()), Err(AuraError::CommunicationError(_))));
+        observer.publish("unheard".to_string()).unwrap();
+        assert!(matches!(subscriber.try_recv(), Err(AuraError::ChannelDisconnected(_))));
+    }
+
+    #[test]
+    fn remappings_apply_to_publishers_and_subscribers() {
+        let node = NodeBuilder::new("driver")
+            .namespace("/robot1")
+            .context(Context::new())
+            .remap("chatter", "/other/chatter")
+            .remap("/robot1/scan", "front_scan")
+            .build()
+            .unwrap();
+
+        let publisher = node.create_publisher("chatter").unwrap();
+        let subscriber = node.create_subscriber("chatter").unwrap();
+        assert_eq!(publisher.topic_name(), "/other/chatter");
+        assert_eq!(subscriber.topic_name(), "/other/chatter");
+        publisher.publish("remapped".to_string()).unwrap();
+        assert_eq!(subscriber.try_recv().unwrap().unwrap().data, "remapped");
+
+        // Fully resolved keys match too, and targets resolve against the namespace.
+        assert_eq!(node.create_subscriber("scan").unwrap().topic_name(), "/robot1/front_scan");
+        assert_eq!(node.create_publisher("odom").unwrap().topic_name(), "/robot1/odom");
+    }
+}
@@ -0,0 +1,267 @@
+// aura_os/aura_core/src/node/builder.rs
+
+use super::liveliness::Heartbeater;
+use super::{clean_namespace, Clock, Node};
+use crate::error::Result;
+use crate::param::{ParamSource, ParamValue};
+use crate::Context;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Step-by-step configuration of a [`Node`], for when `Node::new` is not enough.
+///
+/// Example:
+/// ```ignore
+/// let node = NodeBuilder::new("camera_driver")
+///     .namespace("/robot1")
+///     .remap("image", "image_raw")
+///     .parameter_overrides(vec![("fps".to_string(), ParamValue::Int(15))])
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct NodeBuilder {
+    name: String,
+    namespace: String,
+    remappings: HashMap<String, String>,
+    parameter_overrides: Vec<(String, ParamValue)>,
+    heartbeat_period: Option<Duration>,
+    context: Option<Arc<Context>>,
+    max_publishers: Option<usize>,
+    max_subscribers: Option<usize>,
+    strict_parameters: bool,
+    clock: Clock,
+    /// Names of the nodes this one starts after, as given to `depends_on`; relative
+    /// ones are resolved by `dependencies` against the final namespace.
+    dependencies: Vec<String>,
+}
+
+impl NodeBuilder {
+    /// Starts building a node named `name` in the global namespace.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            namespace: String::new(),
+            remappings: HashMap::new(),
+            parameter_overrides: Vec::new(),
+            heartbeat_period: None,
+            context: None,
+            max_publishers: None,
+            max_subscribers: None,
+            strict_parameters: false,
+            clock: Clock::default(),
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Sets the node's namespace (e.g., "/robot1").
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.namespace = namespace.to_string();
+        self
+    }
+
+    /// Adds a topic remapping; see `Node::new_with_remappings` for how keys are matched.
+    pub fn remap(mut self, from: &str, to: &str) -> Self {
+        self.remappings.insert(from.to_string(), to.to_string());
+        self
+    }
+
+    /// Replaces all topic remappings.
+    pub fn remappings(mut self, remappings: HashMap<String, String>) -> Self {
+        self.remappings = remappings;
+        self
+    }
+
+    /// Sets parameter values that take precedence over the defaults the node's code
+    /// later passes to `declare_parameter`, and over values later loaded from files or
+    /// the environment (see [`ParamSource`]).
+    pub fn parameter_overrides(mut self, overrides: Vec<(String, ParamValue)>) -> Self {
+        self.parameter_overrides = overrides;
+        self
+    }
+
+    /// With `true`, setting a parameter the node has not declared (or received from a
+    /// file, the environment or an override) at runtime fails instead of creating it;
+    /// see [`ParameterManager::set_strict`](crate::param::ParameterManager::set_strict).
+    /// Off by default.
+    pub fn strict_parameters(mut self, strict: bool) -> Self {
+        self.strict_parameters = strict;
+        self
+    }
+
+    /// Sets the clock the node reads the time from (default [`Clock::System`]).
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Makes the node publish a heartbeat every `period` for liveliness monitoring; see
+    /// [`liveliness`](super::liveliness). Nodes do not heartbeat by default.
+    pub fn heartbeat_period(mut self, period: Duration) -> Self {
+        self.heartbeat_period = Some(period);
+        self
+    }
+
+    /// Creates the node in `context` instead of the global context. Its name only has
+    /// to be unique within that context, and its publishers and subscribers only talk
+    /// to others in the same context.
+    pub fn context(mut self, context: Arc<Context>) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Caps how many live publishers the node may have at once, to catch runaway code.
+    /// Once the cap is reached, `create_publisher` and its variants fail with
+    /// `AuraError::NodeError` until one of the node's publishers is dropped. Unlimited
+    /// by default.
+    pub fn max_publishers(mut self, max: usize) -> Self {
+        self.max_publishers = Some(max);
+        self
+    }
+
+    /// Caps how many live subscribers the node may have at once, counting every kind
+    /// created through the node (typed, work-queue and prefix subscribers included); see
+    /// [`NodeBuilder::max_publishers`]. Unlimited by default.
+    pub fn max_subscribers(mut self, max: usize) -> Self {
+        self.max_subscribers = Some(max);
+        self
+    }
+
+    /// Sets both caps at once, `None` meaning unlimited. Used to pass a node's limits on
+    /// to its sub-nodes.
+    pub(crate) fn resource_limits(mut self, max_publishers: Option<usize>, max_subscribers: Option<usize>) -> Self {
+        self.max_publishers = max_publishers;
+        self.max_subscribers = max_subscribers;
+        self
+    }
+
+    /// Declares that this node must start after `node_name`, e.g. a consumer after its
+    /// driver. A relative name is resolved against this node's namespace, whether the
+    /// namespace is set before or after this call.
+    ///
+    /// Only [`LaunchManager`](super::LaunchManager) honours dependencies; `build` ignores
+    /// them.
+    pub fn depends_on(mut self, node_name: &str) -> Self {
+        self.dependencies.push(node_name.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Returns the fully qualified name the node will have.
+    pub fn fully_qualified_name(&self) -> String {
+        format!("{}/{}", clean_namespace(&self.namespace), self.name)
+    }
+
+    /// Returns the fully qualified names of the nodes declared with `depends_on`.
+    pub fn dependencies(&self) -> Vec<String> {
+        let namespace = clean_namespace(&self.namespace);
+        self.dependencies
+            .iter()
+            .map(|name| if name.starts_with('/') { name.clone() } else { format!("{}/{}", namespace, name) })
+            .collect()
+    }
+
+    /// Returns the context the node will be created in.
+    pub(crate) fn target_context(&self) -> Arc<Context> {
+        self.context.clone().unwrap_or_else(Context::global)
+    }
+
+    /// Creates the node.
+    ///
+    /// # Returns
+    /// The new `Node`, or `AuraError::NodeError` if the name or namespace is invalid or
+    /// already taken, the error from applying a parameter override, or the error from
+    /// starting the heartbeat.
+    pub fn build(self) -> Result<Node> {
+        let context = self.target_context();
+        let mut node = Node::construct(context, &self.name, &self.namespace, self.remappings)?;
+        // Overrides outrank files, environment variables and declared defaults,
+        // whenever those are applied.
+        for (name, value) in &self.parameter_overrides {
+            node.params().set_parameter_from(name, value.clone(), ParamSource::Override)?;
+        }
+        node.params().set_strict(self.strict_parameters);
+        node.parameter_overrides = self.parameter_overrides;
+        node.max_publishers = self.max_publishers;
+        node.max_subscribers = self.max_subscribers;
+        node.clock = self.clock;
+        if let Some(period) = self.heartbeat_period {
+            node.heartbeater = Some(Heartbeater::start(node.context(), node.unique_id(), period)?);
+        }
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AuraError;
+
+    #[test]
+    fn publisher_limit_frees_a_slot_on_drop() {
+        let node = NodeBuilder::new("limited").context(Context::new()).max_publishers(2).build().unwrap();
+        let first = node.create_publisher("/limited/a").unwrap();
+        let _second = node.create_publisher("/limited/b").unwrap();
+        assert!(matches!(node.create_publisher("/limited/c"), Err(AuraError::NodeError(_))));
+
+        drop(first);
+        node.create_publisher("/limited/c").unwrap();
+    }
+
+    #[test]
+    fn override_survives_declare_parameter() {
+        let node = NodeBuilder::new("camera")
+            .context(Context::new())
+            .parameter_overrides(vec![("fps".to_string(), ParamValue::Int(15))])
+            .build()
+            .unwrap();
+        node.params().declare_parameter("fps", ParamValue::Int(30)).unwrap();
+        assert_eq!(node.params().get_parameter("fps").unwrap(), ParamValue::Int(15));
+        assert_eq!(node.params().parameter_source("fps"), Some(ParamSource::Override));
+    }
+
+    #[test]
+    fn strict_parameters_reject_undeclared_runtime_sets() {
+        let node = NodeBuilder::new("strict")
+            .context(Context::new())
+            .strict_parameters(true)
+            .parameter_overrides(vec![("gain".to_string(), ParamValue::Float(2.0))])
+            .build()
+            .unwrap();
+        let params = node.params();
+        params.declare_parameter("rate", ParamValue::Int(10)).unwrap();
+        params.set_parameter("rate", ParamValue::Int(20)).unwrap();
+        params.set_parameter("gain", ParamValue::Float(3.0)).unwrap();
+        assert!(matches!(params.set_parameter("raet", ParamValue::Int(20)), Err(AuraError::ParameterNotFound(_))));
+        let batch = vec![("rate".to_string(), ParamValue::Int(5)), ("raet".to_string(), ParamValue::Int(5))];
+        assert!(params.set_parameters_atomically(batch).is_err());
+        assert_eq!(params.get_parameter("rate").unwrap(), ParamValue::Int(20));
+
+        assert!(node.create_sub_node("arm").unwrap().params().is_strict());
+    }
+
+    #[test]
+    fn manual_clock_drives_the_node_and_its_sub_nodes() {
+        use crate::node::ManualClock;
+        use std::time::SystemTime;
+
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let sim_time = ManualClock::new(start);
+        let node = NodeBuilder::new("planner")
+            .context(Context::new())
+            .clock(Clock::Manual(sim_time.clone()))
+            .build()
+            .unwrap();
+        let arm = node.create_sub_node("arm").unwrap();
+        assert_eq!(node.now(), start);
+
+        sim_time.advance(Duration::from_millis(250));
+        assert_eq!(node.now(), start + Duration::from_millis(250));
+        assert_eq!(arm.now(), node.now());
+        sim_time.set(start);
+        assert_eq!(arm.now(), start);
+
+        let wall = NodeBuilder::new("wall").context(Context::new()).build().unwrap();
+        assert!(matches!(wall.clock(), Clock::System));
+        assert!(wall.now() > start);
+    }
+}
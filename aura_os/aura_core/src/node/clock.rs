@@ -0,0 +1,72 @@
+// aura_os/aura_core/src/node/clock.rs
+
+//! Time sources for nodes.
+//!
+//! A node reads the time through its [`Clock`], chosen with
+//! [`NodeBuilder::clock`](super::NodeBuilder::clock). The system clock is the default;
+//! a [`ManualClock`] only moves when it is set or advanced, for simulation and tests:
+//!
+//! ```ignore
+//! let sim_time = ManualClock::new(SystemTime::UNIX_EPOCH);
+//! let node = NodeBuilder::new("planner").clock(Clock::Manual(sim_time.clone())).build()?;
+//! sim_time.advance(Duration::from_millis(100));
+//! assert_eq!(node.now(), SystemTime::UNIX_EPOCH + Duration::from_millis(100));
+//! ```
+//!
+//! Message stamps (`AuraMessage::stamp_ns`) are still read from the system clock.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// Where a node reads the current time from.
+#[derive(Debug, Clone, Default)]
+pub enum Clock {
+    /// The operating system's wall clock.
+    #[default]
+    System,
+    /// A clock driven by the application; see [`ManualClock`].
+    Manual(ManualClock),
+}
+
+impl Clock {
+    /// Returns the current time of this clock.
+    pub fn now(&self) -> SystemTime {
+        match self {
+            Clock::System => SystemTime::now(),
+            Clock::Manual(clock) => clock.now(),
+        }
+    }
+}
+
+/// A clock that only moves when it is set or advanced.
+///
+/// Clones share the same time, so a simulation can keep one and hand clones to the
+/// nodes it drives.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl ManualClock {
+    /// Creates a clock that reads `start` until it is set or advanced.
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Returns the clock's current time.
+    pub fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets the clock to `time`, which may be earlier than the current time.
+    pub fn set(&self, time: SystemTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = time;
+    }
+
+    /// Moves the clock forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
@@ -0,0 +1,217 @@
+// aura_os/aura_core/src/node/launch.rs
+
+//! Bringing up a set of nodes in dependency order.
+//!
+//! Nodes declare what they need with [`NodeBuilder::depends_on`]; a [`LaunchManager`]
+//! collects the builders and creates the nodes so that every node starts after the
+//! nodes it depends on. A dependency may also be a node that is already running in the
+//! same context.
+//!
+//! Nodes have no lifecycle states yet, so a node counts as started once it is built and
+//! registered, and its start-up hook (see [`LaunchManager::add_with`]) has returned.
+//!
+//! Example:
+//! ```ignore
+//! let mut launch = LaunchManager::new();
+//! launch.add(NodeBuilder::new("planner").depends_on("driver"));
+//! launch.add(NodeBuilder::new("driver"));
+//! let nodes = launch.launch()?; // driver, then planner
+//! ```
+
+use super::{Node, NodeBuilder};
+use crate::aura_log;
+use crate::error::{AuraError, Result};
+use std::collections::HashMap;
+
+/// Runs once a node is built, before any node depending on it starts.
+type StartHook = Box<dyn FnOnce(&Node) -> Result<()> + Send>;
+
+/// Creates registered nodes in topological order of their dependencies.
+#[derive(Default)]
+pub struct LaunchManager {
+    entries: Vec<(NodeBuilder, Option<StartHook>)>,
+}
+
+impl std::fmt::Debug for LaunchManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LaunchManager")
+            .field("nodes", &self.entries.iter().map(|(builder, _)| builder.fully_qualified_name()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl LaunchManager {
+    /// Creates an empty launch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a node to launch.
+    pub fn add(&mut self, builder: NodeBuilder) -> &mut Self {
+        self.entries.push((builder, None));
+        self
+    }
+
+    /// Registers a node to launch, with a hook that sets it up (e.g., creates its
+    /// publishers) before the nodes depending on it start.
+    pub fn add_with<F>(&mut self, builder: NodeBuilder, on_start: F) -> &mut Self
+    where
+        F: FnOnce(&Node) -> Result<()> + Send + 'static,
+    {
+        self.entries.push((builder, Some(Box::new(on_start))));
+        self
+    }
+
+    /// Returns the fully qualified names of the registered nodes in the order they would
+    /// start. Among nodes that do not depend on each other, registration order is kept.
+    ///
+    /// # Returns
+    /// The order, or `AuraError::ConfigurationError` if two registered nodes have the same
+    /// name, a dependency is neither registered nor running, or the dependencies form a
+    /// cycle (the error names it, e.g. `/a -> /b -> /a`).
+    pub fn start_order(&self) -> Result<Vec<String>> {
+        Ok(self.plan()?.into_iter().map(|index| self.entries[index].0.fully_qualified_name()).collect())
+    }
+
+    /// Builds every registered node in dependency order, running each node's start-up
+    /// hook before starting its dependents.
+    ///
+    /// # Returns
+    /// The nodes in start order, the errors of [`LaunchManager::start_order`], or the
+    /// first error from building a node or running a hook. Nodes already started are
+    /// dropped again on failure.
+    pub fn launch(self) -> Result<Vec<Node>> {
+        let order = self.plan()?;
+        let mut slots: Vec<Option<(NodeBuilder, Option<StartHook>)>> = self.entries.into_iter().map(Some).collect();
+        let mut nodes = Vec::with_capacity(order.len());
+        for index in order {
+            let Some((builder, on_start)) = slots[index].take() else {
+                continue;
+            };
+            let name = builder.fully_qualified_name();
+            aura_log!(info, "Launching node '{}'", name);
+            let node = builder.build()?;
+            if let Some(on_start) = on_start {
+                on_start(&node).map_err(|e| {
+                    AuraError::NodeError(format!("Start-up of node '{}' failed: {}", name, e))
+                })?;
+            }
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+
+    /// Computes the start order as indices into `entries`, with a depth-first search
+    /// that visits dependencies before dependents.
+    fn plan(&self) -> Result<Vec<usize>> {
+        let mut by_name = HashMap::new();
+        for (index, (builder, _)) in self.entries.iter().enumerate() {
+            if by_name.insert(builder.fully_qualified_name(), index).is_some() {
+                return Err(AuraError::ConfigurationError(format!(
+                    "Node '{}' is registered more than once in the launch.",
+                    builder.fully_qualified_name()
+                )));
+            }
+        }
+
+        let mut state = vec![Visit::New; self.entries.len()];
+        let mut order = Vec::with_capacity(self.entries.len());
+        let mut path = Vec::new();
+        for index in 0..self.entries.len() {
+            self.visit(index, &by_name, &mut state, &mut path, &mut order)?;
+        }
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        index: usize,
+        by_name: &HashMap<String, usize>,
+        state: &mut [Visit],
+        path: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<()> {
+        match state[index] {
+            Visit::Done => return Ok(()),
+            Visit::InProgress => {
+                let start = path.iter().position(|&on_path| on_path == index).unwrap_or(0);
+                let cycle: Vec<String> = path[start..]
+                    .iter()
+                    .chain(std::iter::once(&index))
+                    .map(|&i| self.entries[i].0.fully_qualified_name())
+                    .collect();
+                return Err(AuraError::ConfigurationError(format!(
+                    "Circular node dependency: {}.",
+                    cycle.join(" -> ")
+                )));
+            }
+            Visit::New => {}
+        }
+
+        state[index] = Visit::InProgress;
+        path.push(index);
+        let builder = &self.entries[index].0;
+        for dependency in builder.dependencies() {
+            match by_name.get(&dependency) {
+                Some(&dependency_index) => self.visit(dependency_index, by_name, state, path, order)?,
+                None if builder.target_context().find_node(&dependency).is_some() => {}
+                None => {
+                    return Err(AuraError::ConfigurationError(format!(
+                        "Node '{}' depends on '{}', which is neither part of the launch nor running.",
+                        builder.fully_qualified_name(),
+                        dependency
+                    )));
+                }
+            }
+        }
+        path.pop();
+        state[index] = Visit::Done;
+        order.push(index);
+        Ok(())
+    }
+}
+
+/// Depth-first search state of one registered node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+    use std::sync::Arc;
+
+    #[test]
+    fn nodes_start_after_their_dependencies() {
+        let context = Context::new();
+        let node = |name: &str| NodeBuilder::new(name).context(Arc::clone(&context));
+        let mut launch = LaunchManager::new();
+        // A relative dependency follows a namespace set after `depends_on`.
+        launch.add(node("c").depends_on("b").namespace("/r"));
+        launch.add(node("b").namespace("/r").depends_on("/r/a"));
+        launch.add(node("a").namespace("/r"));
+
+        assert_eq!(launch.start_order().unwrap(), ["/r/a", "/r/b", "/r/c"]);
+        let names: Vec<String> = launch.launch().unwrap().iter().map(|node| node.fully_qualified_name()).collect();
+        assert_eq!(names, ["/r/a", "/r/b", "/r/c"]);
+    }
+
+    #[test]
+    fn dependency_cycle_is_a_configuration_error() {
+        let context = Context::new();
+        let mut launch = LaunchManager::new();
+        launch.add(NodeBuilder::new("a").context(Arc::clone(&context)).depends_on("c"));
+        launch.add(NodeBuilder::new("b").context(Arc::clone(&context)).depends_on("a"));
+        launch.add(NodeBuilder::new("c").context(Arc::clone(&context)).depends_on("b"));
+
+        match launch.launch() {
+            Err(AuraError::ConfigurationError(message)) => assert!(message.contains("/a -> /c -> /b -> /a"), "{}", message),
+            other => panic!("expected a ConfigurationError, got {:?}", other.map(|nodes| nodes.len())),
+        }
+        assert!(context.find_node("/a").is_none());
+    }
+}
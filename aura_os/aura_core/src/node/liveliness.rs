@@ -0,0 +1,313 @@
+// aura_os/aura_core/src/node/liveliness.rs
+
+//! Node heartbeats and a monitor that notices when nodes stop sending them.
+//!
+//! A node built with [`NodeBuilder::heartbeat_period`](super::NodeBuilder::heartbeat_period)
+//! publishes a heartbeat on [`LIVELINESS_TOPIC`] every period from a background thread,
+//! and a final "bye" heartbeat when it is dropped. A [`Monitor`] tracks the heartbeats
+//! and reports each node that either says goodbye or misses too many heartbeats in a row.
+//!
+//! Example:
+//! ```ignore
+//! let _monitor = Monitor::new(3, |unique_id, departure| {
+//!     println!("{} is gone: {:?}", unique_id, departure);
+//! })?;
+//! let node = NodeBuilder::new("driver").heartbeat_period(Duration::from_millis(100)).build()?;
+//! ```
+
+use crate::comm::{Publisher, QosProfile, Subscriber};
+use crate::error::{AuraError, Result};
+use crate::{aura_log, AuraMessage, Context};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Topic all heartbeats are published on.
+pub const LIVELINESS_TOPIC: &str = "/aura/liveliness";
+
+/// How often a `Monitor` checks for overdue nodes when no heartbeat arrives.
+const MONITOR_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A decoded heartbeat message.
+///
+/// On the wire the message data is `"alive <period_ns> <unique_id>"` or
+/// `"bye <unique_id>"`; the publish time is the message's `stamp_ns`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heartbeat {
+    /// Unique id of the node that sent it.
+    pub unique_id: String,
+    /// The sender's heartbeat period, or `None` for a final "bye".
+    pub period: Option<Duration>,
+    /// Publish time in nanoseconds since the UNIX epoch.
+    pub stamp_ns: u64,
+}
+
+impl Heartbeat {
+    /// Decodes a message from [`LIVELINESS_TOPIC`], or returns `None` if it is not a heartbeat.
+    pub fn parse(message: &AuraMessage) -> Option<Heartbeat> {
+        let mut fields = message.data.splitn(3, ' ');
+        let period = match fields.next()? {
+            "alive" => Some(Duration::from_nanos(fields.next()?.parse().ok()?)),
+            "bye" => None,
+            _ => return None,
+        };
+        let unique_id = fields.collect::<Vec<_>>().join(" ");
+        if unique_id.is_empty() {
+            return None;
+        }
+        Some(Heartbeat {
+            unique_id,
+            period,
+            stamp_ns: message.stamp_ns,
+        })
+    }
+
+    fn alive_data(unique_id: &str, period: Duration) -> String {
+        format!("alive {} {}", period.as_nanos(), unique_id)
+    }
+
+    fn bye_data(unique_id: &str) -> String {
+        format!("bye {}", unique_id)
+    }
+}
+
+/// The background thread publishing a node's heartbeats. Dropping it stops the thread
+/// and publishes the "bye" heartbeat.
+#[derive(Debug)]
+pub(crate) struct Heartbeater {
+    unique_id: String,
+    publisher: Arc<Publisher>,
+    /// Dropping the sender wakes the thread and tells it to exit.
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Heartbeater {
+    /// Starts heartbeating for the node `unique_id`, one heartbeat per `period`.
+    pub(crate) fn start(context: &Arc<Context>, unique_id: &str, period: Duration) -> Result<Self> {
+        if period.is_zero() {
+            return Err(AuraError::NodeError(format!(
+                "Heartbeat period of node '{}' must be greater than zero.",
+                unique_id
+            )));
+        }
+        let publisher = Arc::new(Publisher::new_in_context(
+            Arc::clone(context),
+            LIVELINESS_TOPIC,
+            QosProfile::default(),
+        )?);
+        let (stop, stop_rx) = mpsc::channel::<()>();
+
+        let thread_publisher = Arc::clone(&publisher);
+        let data = Heartbeat::alive_data(unique_id, period);
+        let thread = thread::Builder::new()
+            .name("aura-heartbeat".to_string())
+            .spawn(move || loop {
+                if let Err(e) = thread_publisher.publish(data.clone()) {
+                    aura_log!(warn, "Failed to publish heartbeat: {}", e);
+                }
+                match stop_rx.recv_timeout(period) {
+                    Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                    _ => break,
+                }
+            })?;
+
+        Ok(Self {
+            unique_id: unique_id.to_string(),
+            publisher,
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for Heartbeater {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        if let Err(e) = self.publisher.publish(Heartbeat::bye_data(&self.unique_id)) {
+            aura_log!(warn, "Failed to publish final heartbeat of '{}': {}", self.unique_id, e);
+        }
+    }
+}
+
+/// Why a [`Monitor`] stopped considering a node alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Departure {
+    /// The node sent its final heartbeat (it was dropped cleanly).
+    Left,
+    /// The node missed the configured number of heartbeats in a row.
+    Lost,
+}
+
+/// Last heartbeat seen from a node.
+#[derive(Debug, Clone, Copy)]
+struct Lease {
+    last_seen: Instant,
+    period: Duration,
+}
+
+/// Watches [`LIVELINESS_TOPIC`] and reports nodes that go away.
+///
+/// A node counts as alive from its first heartbeat. It is reported, and forgotten, when
+/// it says goodbye ([`Departure::Left`]) or when `missed_heartbeats` of its periods pass
+/// without a heartbeat ([`Departure::Lost`]). The callback runs on the monitor's own
+/// thread. Dropping the monitor stops it.
+#[derive(Debug)]
+pub struct Monitor {
+    alive: Arc<Mutex<HashMap<String, Lease>>>,
+    stop_requested: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Monitor {
+    /// Starts monitoring, calling `on_departure(unique_id, departure)` for every node
+    /// that leaves or misses `missed_heartbeats` heartbeats.
+    ///
+    /// # Returns
+    /// The running monitor, or an `AuraError` if its subscriber or thread cannot be created.
+    pub fn new<F>(missed_heartbeats: u32, on_departure: F) -> Result<Self>
+    where
+        F: Fn(&str, Departure) + Send + 'static,
+    {
+        Self::new_in_context(Context::global(), missed_heartbeats, on_departure)
+    }
+
+    /// Like [`Monitor::new`], but watches the nodes of `context` instead of the global context.
+    pub fn new_in_context<F>(context: Arc<Context>, missed_heartbeats: u32, on_departure: F) -> Result<Self>
+    where
+        F: Fn(&str, Departure) + Send + 'static,
+    {
+        let subscriber = Subscriber::new_in_context(context, LIVELINESS_TOPIC, QosProfile::default())?;
+        let alive = Arc::new(Mutex::new(HashMap::new()));
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let missed_heartbeats = missed_heartbeats.max(1);
+
+        let thread = {
+            let alive = Arc::clone(&alive);
+            let stop_requested = Arc::clone(&stop_requested);
+            thread::Builder::new().name("aura-liveliness".to_string()).spawn(move || {
+                while !stop_requested.load(Ordering::SeqCst) {
+                    let mut departed = Vec::new();
+                    if let Ok(message) = subscriber.recv_timeout(MONITOR_POLL_INTERVAL) {
+                        if let Some(heartbeat) = Heartbeat::parse(&message) {
+                            let mut alive = alive.lock().unwrap_or_else(PoisonError::into_inner);
+                            match heartbeat.period {
+                                Some(period) => {
+                                    alive.insert(heartbeat.unique_id, Lease { last_seen: Instant::now(), period });
+                                }
+                                None => {
+                                    if alive.remove(&heartbeat.unique_id).is_some() {
+                                        departed.push((heartbeat.unique_id, Departure::Left));
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    {
+                        let now = Instant::now();
+                        let mut alive = alive.lock().unwrap_or_else(PoisonError::into_inner);
+                        alive.retain(|unique_id, lease| {
+                            let overdue = now.duration_since(lease.last_seen) >= lease.period * missed_heartbeats;
+                            if overdue {
+                                departed.push((unique_id.clone(), Departure::Lost));
+                            }
+                            !overdue
+                        });
+                    }
+                    // Report without the lock held, so the callback may query the monitor.
+                    for (unique_id, departure) in departed {
+                        aura_log!(info, "Node '{}' is no longer alive ({:?})", unique_id, departure);
+                        on_departure(&unique_id, departure);
+                    }
+                }
+            })?
+        };
+
+        Ok(Self {
+            alive,
+            stop_requested,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the unique ids of the nodes currently considered alive, sorted.
+    pub fn alive_nodes(&self) -> Vec<String> {
+        let alive = self.alive.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut ids: Vec<String> = alive.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Returns `true` if the node with `unique_id` is currently considered alive.
+    pub fn is_alive(&self, unique_id: &str) -> bool {
+        self.alive.lock().unwrap_or_else(PoisonError::into_inner).contains_key(unique_id)
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::NodeBuilder;
+
+    /// Polls `condition` for up to two seconds.
+    fn wait_until(condition: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while !condition() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        true
+    }
+
+    #[test]
+    fn monitor_reports_dropped_and_silent_nodes() {
+        let context = Context::new();
+        let departures = Arc::new(Mutex::new(Vec::new()));
+        let monitor = {
+            let departures = Arc::clone(&departures);
+            Monitor::new_in_context(Arc::clone(&context), 10, move |unique_id, departure| {
+                departures.lock().unwrap().push((unique_id.to_string(), departure));
+            })
+            .unwrap()
+        };
+
+        let node = NodeBuilder::new("driver")
+            .context(Arc::clone(&context))
+            .heartbeat_period(Duration::from_millis(20))
+            .build()
+            .unwrap();
+        let unique_id = node.unique_id().to_string();
+        assert!(wait_until(|| monitor.is_alive(&unique_id)));
+        // Still alive several periods later, as long as it keeps heartbeating.
+        thread::sleep(Duration::from_millis(100));
+        assert!(monitor.is_alive(&unique_id));
+
+        drop(node);
+        assert!(wait_until(|| !monitor.is_alive(&unique_id)));
+        assert_eq!(*departures.lock().unwrap(), [(unique_id, Departure::Left)]);
+
+        // A node that stops heartbeating without saying goodbye is lost.
+        let publisher = Publisher::new_in_context(Arc::clone(&context), LIVELINESS_TOPIC, QosProfile::default()).unwrap();
+        publisher.publish(Heartbeat::alive_data("silent", Duration::from_millis(20))).unwrap();
+        assert!(wait_until(|| monitor.is_alive("silent")));
+        assert!(wait_until(|| !monitor.is_alive("silent")));
+        assert_eq!(departures.lock().unwrap()[1], ("silent".to_string(), Departure::Lost));
+    }
+}
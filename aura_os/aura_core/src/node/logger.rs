@@ -0,0 +1,127 @@
+// aura_os/aura_core/src/node/logger.rs
+
+//! Node-scoped logging.
+//!
+//! Every line logged through a node's [`Logger`] is tagged with the node's fully
+//! qualified name, so the output of many nodes sharing one console can be told apart:
+//!
+//! ```text
+//! [/examples/talker::INFO] [aura_examples/src/talker.rs:98] Publishing: 'Hello Count: 3'
+//! ```
+//!
+//! Log through the [`node_log!`](crate::node_log) macro, which fills in the call site:
+//! ```ignore
+//! aura_core::node_log!(node.logger(), info, "Publishing: '{}'", data);
+//! ```
+//!
+//! `aura_log!` remains the logger for AuraOS's own infrastructure messages.
+
+use std::fmt;
+
+/// A logger that tags every line with the fully qualified name of its node.
+///
+/// Obtained from [`Node::logger`](super::Node::logger). It is cheap to clone, so it can
+/// be moved into threads and callbacks that outlive a borrow of the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logger {
+    scope: String,
+}
+
+impl Logger {
+    pub(crate) fn new(scope: &str) -> Self {
+        Self {
+            scope: scope.to_string(),
+        }
+    }
+
+    /// Returns the name every line is tagged with (the node's fully qualified name).
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Writes one log line. Usually called through [`node_log!`](crate::node_log).
+    pub fn log(&self, level: &str, file: &str, line: u32, message: fmt::Arguments) {
+        write_line(self.format_line(level, file, line, message));
+    }
+
+    /// Formats a log line as `log` would write it.
+    pub fn format_line(&self, level: &str, file: &str, line: u32, message: fmt::Arguments) -> String {
+        format!("[{}::{}] [{}:{}] {}", self.scope, level.to_uppercase(), file, line, message)
+    }
+}
+
+/// Writes a finished log line; both `aura_log!` and [`Logger::log`] end up here.
+///
+/// In this crate's unit tests, a line logged inside `capture_logs` is collected
+/// instead of printed, so tests can assert on what was logged.
+#[doc(hidden)]
+pub fn write_line(line: String) {
+    #[cfg(test)]
+    let Some(line) = capture::record(line) else {
+        return;
+    };
+    println!("{}", line);
+}
+
+#[cfg(test)]
+pub(crate) use capture::capture_logs;
+
+#[cfg(test)]
+mod capture {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+    }
+
+    /// Keeps `line` if the current thread is capturing, otherwise hands it back.
+    pub(super) fn record(line: String) -> Option<String> {
+        CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+            Some(lines) => {
+                lines.push(line);
+                None
+            }
+            None => Some(line),
+        })
+    }
+
+    /// Runs `f` and returns the lines it logged on the current thread. Lines logged
+    /// by other threads are printed as usual.
+    pub(crate) fn capture_logs(f: impl FnOnce()) -> Vec<String> {
+        let outer = CAPTURED.with(|captured| captured.replace(Some(Vec::new())));
+        f();
+        CAPTURED.with(|captured| captured.replace(outer)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::NodeBuilder;
+    use crate::Context;
+
+    #[test]
+    fn node_log_tags_lines_with_the_node_name() {
+        let node = NodeBuilder::new("talker").namespace("/examples").context(Context::new()).build().unwrap();
+        let lines = capture_logs(|| {
+            crate::node_log!(node.logger(), warn, "Battery at {}%", 12);
+        });
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("[/examples/talker::WARN] [aura_core/src/node/logger.rs:"), "{}", lines[0]);
+        assert!(lines[0].ends_with("] Battery at 12%"), "{}", lines[0]);
+    }
+
+    #[test]
+    fn infrastructure_lines_are_captured_too() {
+        let lines = capture_logs(|| {
+            crate::aura_log!(info, "bus ready");
+        });
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("[AuraCore::INFO] "), "{}", lines[0]);
+        assert!(lines[0].ends_with(" bus ready"), "{}", lines[0]);
+
+        // Nothing is captured outside the closure.
+        crate::aura_log!(info, "not captured");
+        assert!(capture_logs(|| ()).is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+// aura_os/aura_core/src/node/registry.rs
+
+//! Registry of live nodes, for discovery and name-collision detection.
+//!
+//! Every `Node` registers itself under its fully qualified name in its
+//! [`Context`](crate::Context) on creation and unregisters when dropped. Like the
+//! message bus, this only sees nodes in the current process. The free functions here
+//! query the global context.
+
+use crate::error::{AuraError, Result};
+use crate::Context;
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+/// A snapshot of one registered node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// The node's base name (e.g., "talker_node").
+    pub name: String,
+    /// The node's namespace, empty for the global namespace.
+    pub namespace: String,
+    /// The node's unique ID.
+    pub unique_id: String,
+}
+
+impl NodeInfo {
+    /// Returns the fully qualified name (e.g., "/examples/talker_node").
+    pub fn fully_qualified_name(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
+}
+
+/// Live nodes keyed by fully qualified name. The map only holds plain data, so a
+/// poisoned lock is recovered rather than propagated.
+#[derive(Debug, Default)]
+pub(crate) struct NodeRegistry {
+    nodes: RwLock<HashMap<String, NodeInfo>>,
+}
+
+impl NodeRegistry {
+    /// Returns every live node, sorted by fully qualified name.
+    pub(crate) fn list(&self) -> Vec<NodeInfo> {
+        let registry = self.nodes.read().unwrap_or_else(PoisonError::into_inner);
+        let mut nodes: Vec<NodeInfo> = registry.values().cloned().collect();
+        nodes.sort_by_key(NodeInfo::fully_qualified_name);
+        nodes
+    }
+
+    /// Looks up a live node by fully qualified name.
+    pub(crate) fn find(&self, fully_qualified_name: &str) -> Option<NodeInfo> {
+        let registry = self.nodes.read().unwrap_or_else(PoisonError::into_inner);
+        registry.get(fully_qualified_name).cloned()
+    }
+
+    /// Registers a node, failing with `AuraError::NodeError` if the name is taken.
+    pub(crate) fn register(&self, info: NodeInfo) -> Result<()> {
+        let mut registry = self.nodes.write().unwrap_or_else(PoisonError::into_inner);
+        let fully_qualified_name = info.fully_qualified_name();
+        if let Some(existing) = registry.get(&fully_qualified_name) {
+            return Err(AuraError::NodeError(format!(
+                "A node named '{}' already exists (ID: {}).",
+                fully_qualified_name, existing.unique_id
+            )));
+        }
+        registry.insert(fully_qualified_name, info);
+        Ok(())
+    }
+
+    /// Removes a node's registration, if it is still the one with `unique_id`.
+    pub(crate) fn unregister(&self, fully_qualified_name: &str, unique_id: &str) {
+        let mut registry = self.nodes.write().unwrap_or_else(PoisonError::into_inner);
+        if registry.get(fully_qualified_name).is_some_and(|info| info.unique_id == unique_id) {
+            registry.remove(fully_qualified_name);
+        }
+    }
+}
+
+/// Returns every live node in the global context, sorted by fully qualified name.
+pub fn list_nodes() -> Vec<NodeInfo> {
+    Context::global().list_nodes()
+}
+
+/// Looks up a live node in the global context by fully qualified name.
+pub fn find_node(fully_qualified_name: &str) -> Option<NodeInfo> {
+    Context::global().find_node(fully_qualified_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::NodeBuilder;
+    use crate::{AuraError, Context};
+    use std::sync::Arc;
+
+    #[test]
+    fn registry_follows_node_lifetimes() {
+        let context = Context::new();
+        let driver = NodeBuilder::new("driver").namespace("/robot1").context(Arc::clone(&context)).build().unwrap();
+        let camera = NodeBuilder::new("camera").namespace("/robot1").context(Arc::clone(&context)).build().unwrap();
+
+        let names: Vec<String> = context.list_nodes().iter().map(|info| info.fully_qualified_name()).collect();
+        assert_eq!(names, ["/robot1/camera", "/robot1/driver"]);
+        let found = context.find_node("/robot1/driver").unwrap();
+        assert_eq!((found.name.as_str(), found.namespace.as_str()), ("driver", "/robot1"));
+        assert_eq!(found.unique_id, driver.unique_id());
+
+        // A second node with the same name is refused while the first is alive.
+        let duplicate = NodeBuilder::new("driver").namespace("/robot1").context(Arc::clone(&context)).build();
+        assert!(matches!(duplicate, Err(AuraError::NodeError(_))), "{:?}", duplicate);
+
+        drop(driver);
+        assert_eq!(context.find_node("/robot1/driver"), None);
+        assert_eq!(context.list_nodes().len(), 1);
+        drop(camera);
+        assert!(context.list_nodes().is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+// aura_os/aura_core/src/param/descriptor.rs
+
+use super::ParamValue;
+use crate::error::{AuraError, Result};
+
+/// An inclusive numeric range `[min, max]` for `Int` and `Float` parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl NumericRange {
+    /// Creates an inclusive range `[min, max]`.
+    pub fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if `value` lies within the range (bounds included).
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.min && value <= self.max
+    }
+}
+
+/// Metadata describing a parameter and the values it accepts.
+///
+/// Descriptors are attached with `ParameterManager::declare_parameter_with_descriptor`
+/// and enforced by every subsequent `set_parameter`. Parameters declared without one
+/// behave as if they had `ParameterDescriptor::default()` (no constraints).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParameterDescriptor {
+    /// Human-readable explanation of what the parameter controls.
+    pub description: String,
+    /// If `true`, the parameter keeps its declared value and cannot be set or undeclared.
+    pub read_only: bool,
+    /// Allowed numeric range; only `Int` and `Float` values can satisfy it.
+    pub range: Option<NumericRange>,
+    /// If set, the value must equal one of these.
+    pub choices: Option<Vec<ParamValue>>,
+    /// If `true`, the parameter may be `ParamValue::Null` ("unset") regardless of its
+    /// range and choices. Declaring a parameter with a `Null` default sets this.
+    pub optional: bool,
+}
+
+impl ParameterDescriptor {
+    /// Creates a descriptor with the given description and no constraints.
+    pub fn new(description: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns this descriptor with the given inclusive numeric range.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some(NumericRange::new(min, max));
+        self
+    }
+
+    /// Returns this descriptor with the given set of allowed values.
+    pub fn with_choices(mut self, choices: Vec<ParamValue>) -> Self {
+        self.choices = Some(choices);
+        self
+    }
+
+    /// Returns this descriptor marked as optional, so the parameter may be unset.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Returns this descriptor marked as read-only.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Checks `value` against the range and choices constraints.
+    ///
+    /// Read-only enforcement is left to the `ParameterManager`, since the declared
+    /// default of a read-only parameter must still be accepted. `ParamValue::Null`
+    /// means "unset" and passes only if the descriptor is `optional`.
+    ///
+    /// # Returns
+    /// `Ok(())` if the value is acceptable, or `AuraError::ParameterConfigurationError`
+    /// naming the parameter and the violated constraint.
+    pub fn validate(&self, name: &str, value: &ParamValue) -> Result<()> {
+        if value.is_null() {
+            if self.optional {
+                return Ok(());
+            }
+            return Err(AuraError::ParameterConfigurationError(format!(
+                "Parameter '{}' is not optional and cannot be set to null.",
+                name
+            )));
+        }
+
+        if let Some(range) = &self.range {
+            let numeric = match value {
+                ParamValue::Int(i) => Some(*i as f64),
+                ParamValue::Float(f) => Some(*f),
+                _ => None,
+            };
+            match numeric {
+                Some(n) if range.contains(n) => {}
+                Some(n) => {
+                    return Err(AuraError::ParameterConfigurationError(format!(
+                        "Value {} for parameter '{}' is outside the allowed range [{}, {}].",
+                        n, name, range.min, range.max
+                    )))
+                }
+                None => {
+                    return Err(AuraError::ParameterConfigurationError(format!(
+                        "Parameter '{}' has a numeric range but was given non-numeric value {:?}.",
+                        name, value
+                    )))
+                }
+            }
+        }
+
+        if let Some(choices) = &self.choices {
+            if !choices.contains(value) {
+                return Err(AuraError::ParameterConfigurationError(format!(
+                    "Value {:?} for parameter '{}' is not one of the allowed choices {:?}.",
+                    value, name, choices
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::param::ParameterManager;
+
+    fn rejected(result: Result<()>) -> bool {
+        matches!(result, Err(AuraError::ParameterConfigurationError(_)))
+    }
+
+    #[test]
+    fn range_is_inclusive_and_numeric_only() {
+        let rate = ParameterDescriptor::new("Publish rate in Hz").with_range(0.1, 100.0);
+        assert!(rate.validate("rate", &ParamValue::Float(0.1)).is_ok());
+        assert!(rate.validate("rate", &ParamValue::Int(100)).is_ok());
+        assert!(rejected(rate.validate("rate", &ParamValue::Float(0.0))));
+        assert!(rejected(rate.validate("rate", &ParamValue::Float(-5.0))));
+        assert!(rejected(rate.validate("rate", &ParamValue::Int(101))));
+        assert!(rejected(rate.validate("rate", &ParamValue::String("10".to_string()))));
+        assert!(rejected(rate.validate("rate", &ParamValue::Null)));
+        assert!(rate.optional().validate("rate", &ParamValue::Null).is_ok());
+    }
+
+    #[test]
+    fn choices_must_match_exactly() {
+        let modes = vec![ParamValue::String("auto".to_string()), ParamValue::String("manual".to_string())];
+        let mode = ParameterDescriptor::new("Drive mode").with_choices(modes);
+        assert!(mode.validate("mode", &ParamValue::String("auto".to_string())).is_ok());
+        assert!(rejected(mode.validate("mode", &ParamValue::String("Auto".to_string()))));
+        assert!(rejected(mode.validate("mode", &ParamValue::Int(1))));
+    }
+
+    #[test]
+    fn read_only_is_enforced_by_the_manager() {
+        let serial = ParameterDescriptor::new("Sensor serial").read_only();
+        // The descriptor itself accepts any value, so the declared default passes.
+        assert!(serial.validate("serial", &ParamValue::String("B02".to_string())).is_ok());
+
+        let params = ParameterManager::new("lidar");
+        params.declare_parameter_with_descriptor("serial", ParamValue::String("A17".to_string()), serial).unwrap();
+        assert!(params.get_descriptor("serial").unwrap().read_only);
+        let result = params.set_parameter("serial", ParamValue::String("B02".to_string()));
+        assert!(rejected(result));
+        assert_eq!(params.get_parameter("serial").unwrap(), ParamValue::String("A17".to_string()));
+    }
+}
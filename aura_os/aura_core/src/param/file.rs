@@ -0,0 +1,310 @@
+// aura_os/aura_core/src/param/file.rs
+
+//! Loading parameters from, and dumping them to, configuration files.
+//!
+//! The format follows the file extension: `.json` (feature `json`), `.yaml`/`.yml`
+//! (feature `yaml`) or `.toml` (feature `toml`). Nested tables map to dotted parameter
+//! names, so
+//!
+//! ```yaml
+//! camera:
+//!   fps: 30
+//!   exposure: 0.5
+//! ```
+//!
+//! holds the parameters `camera.fps` and `camera.exposure`. Scalars keep their type:
+//! integers load as `Int`, numbers with a fraction or exponent as `Float`. TOML has no
+//! null, so `Null` parameters cannot be dumped to it.
+//!
+//! This module is only available with at least one of those features enabled.
+
+use super::{ParamSource, ParamValue, ParameterManager};
+use crate::error::{AuraError, Result};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// A parsed configuration document: scalar leaves inside nested tables.
+#[derive(Debug)]
+enum ParamTree {
+    Leaf(ParamValue),
+    Table(BTreeMap<String, ParamTree>),
+}
+
+impl ParamTree {
+    /// Builds the nested form of dotted `(name, value)` pairs.
+    fn from_flat(params: Vec<(String, ParamValue)>) -> Result<ParamTree> {
+        let mut root = BTreeMap::new();
+        for (name, value) in params {
+            let mut segments: Vec<&str> = name.split('.').collect();
+            let leaf_key = segments.pop().unwrap_or_default();
+            let mut table = &mut root;
+            for segment in segments {
+                let node = table
+                    .entry(segment.to_string())
+                    .or_insert_with(|| ParamTree::Table(BTreeMap::new()));
+                table = match node {
+                    ParamTree::Table(child) => child,
+                    ParamTree::Leaf(_) => return Err(nesting_conflict(&name)),
+                };
+            }
+            if table.insert(leaf_key.to_string(), ParamTree::Leaf(value)).is_some() {
+                return Err(nesting_conflict(&name));
+            }
+        }
+        Ok(ParamTree::Table(root))
+    }
+
+    /// Flattens the tree into dotted `(name, value)` pairs.
+    fn flatten(self, prefix: &str, out: &mut Vec<(String, ParamValue)>) {
+        match self {
+            ParamTree::Leaf(value) => out.push((prefix.to_string(), value)),
+            ParamTree::Table(entries) => {
+                for (key, child) in entries {
+                    let name = if prefix.is_empty() { key } else { format!("{}.{}", prefix, key) };
+                    child.flatten(&name, out);
+                }
+            }
+        }
+    }
+}
+
+fn nesting_conflict(name: &str) -> AuraError {
+    AuraError::ParameterConfigurationError(format!(
+        "Parameter '{}' clashes with another parameter used as its table (or vice versa).",
+        name
+    ))
+}
+
+impl Serialize for ParamTree {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            ParamTree::Leaf(ParamValue::String(s)) => serializer.serialize_str(s),
+            ParamTree::Leaf(ParamValue::Int(i)) => serializer.serialize_i64(*i),
+            ParamTree::Leaf(ParamValue::Float(f)) => serializer.serialize_f64(*f),
+            ParamTree::Leaf(ParamValue::Bool(b)) => serializer.serialize_bool(*b),
+            ParamTree::Leaf(ParamValue::Null) => serializer.serialize_none(),
+            ParamTree::Table(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, child) in entries {
+                    map.serialize_entry(key, child)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ParamTree {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(ParamTreeVisitor)
+    }
+}
+
+struct ParamTreeVisitor;
+
+impl<'de> Visitor<'de> for ParamTreeVisitor {
+    type Value = ParamTree;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a parameter value or a table of parameters")
+    }
+
+    fn visit_bool<E>(self, b: bool) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::Bool(b)))
+    }
+
+    fn visit_i64<E>(self, i: i64) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::Int(i)))
+    }
+
+    fn visit_u64<E: de::Error>(self, u: u64) -> std::result::Result<ParamTree, E> {
+        i64::try_from(u)
+            .map(|i| ParamTree::Leaf(ParamValue::Int(i)))
+            .map_err(|_| E::custom(format!("integer {} is too large for an Int parameter", u)))
+    }
+
+    fn visit_f64<E>(self, f: f64) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::Float(f)))
+    }
+
+    fn visit_str<E>(self, s: &str) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::String(s.to_string())))
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::Null))
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<ParamTree, E> {
+        Ok(ParamTree::Leaf(ParamValue::Null))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> std::result::Result<ParamTree, A::Error> {
+        let mut entries = BTreeMap::new();
+        while let Some((key, value)) = access.next_entry::<String, ParamTree>()? {
+            entries.insert(key, value);
+        }
+        Ok(ParamTree::Table(entries))
+    }
+}
+
+/// The file formats, chosen by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl Format {
+    fn of(path: &Path) -> Result<Format> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let (format, feature, enabled) = match extension.to_ascii_lowercase().as_str() {
+            "json" => (Format::Json, "json", cfg!(feature = "json")),
+            "yaml" | "yml" => (Format::Yaml, "yaml", cfg!(feature = "yaml")),
+            "toml" => (Format::Toml, "toml", cfg!(feature = "toml")),
+            _ => {
+                return Err(AuraError::ConfigurationError(format!(
+                    "Cannot tell the format of '{}': expected a .json, .yaml, .yml or .toml extension.",
+                    path.display()
+                )))
+            }
+        };
+        if !enabled {
+            return Err(AuraError::ConfigurationError(format!(
+                "Reading or writing '{}' needs aura_core's `{}` feature.",
+                path.display(),
+                feature
+            )));
+        }
+        Ok(format)
+    }
+
+    fn parse(self, text: &str) -> Result<ParamTree> {
+        match self {
+            #[cfg(feature = "json")]
+            Format::Json => Ok(serde_json::from_str(text)?),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => Ok(serde_yaml::from_str(text)?),
+            #[cfg(feature = "toml")]
+            Format::Toml => Ok(toml::from_str(text)?),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("Format::of only returns enabled formats"),
+        }
+    }
+
+    fn render(self, tree: &ParamTree) -> Result<String> {
+        match self {
+            #[cfg(feature = "json")]
+            Format::Json => Ok(serde_json::to_string_pretty(tree)?),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => Ok(serde_yaml::to_string(tree)?),
+            #[cfg(feature = "toml")]
+            Format::Toml => toml::to_string(tree).map_err(|e| AuraError::ConfigurationError(format!("TOML: {}", e))),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("Format::of only returns enabled formats"),
+        }
+    }
+}
+
+impl ParameterManager {
+    /// Sets every parameter in the configuration file at `path`.
+    ///
+    /// The format is chosen by extension (see the [module docs](self)). All values are
+    /// applied with [`ParameterManager::set_parameters_atomically_from`] as
+    /// `ParamSource::File`, so descriptor constraints apply, nothing is set if any value
+    /// is rejected, and parameters already set by a higher-ranked source (environment,
+    /// overrides, runtime changes) keep their values.
+    ///
+    /// # Returns
+    /// The number of parameters set, `AuraError::IoError` if the file cannot be read,
+    /// or a configuration/serialization error if it cannot be parsed.
+    pub fn load_from_file<P: AsRef<Path>>(&self, path: P) -> Result<usize> {
+        self.load_from_file_as(path, ParamSource::File)
+    }
+
+    /// Like [`ParameterManager::load_from_file`], but the values count as coming from
+    /// `source`, e.g. `ParamSource::Runtime` for a file loaded on request while running.
+    pub fn load_from_file_as<P: AsRef<Path>>(&self, path: P, source: ParamSource) -> Result<usize> {
+        let path = path.as_ref();
+        let format = Format::of(path)?;
+        let text = std::fs::read_to_string(path)?;
+        let tree = format.parse(&text)?;
+        if !matches!(tree, ParamTree::Table(_)) {
+            return Err(AuraError::ConfigurationError(format!(
+                "Parameter file '{}' must contain a table at the top level.",
+                path.display()
+            )));
+        }
+
+        let mut updates = Vec::new();
+        tree.flatten("", &mut updates);
+        self.set_parameters_atomically_from(updates, source)
+    }
+
+    /// Writes every current parameter to the configuration file at `path`, replacing it.
+    ///
+    /// The format is chosen by extension (see the [module docs](self)), and dotted names
+    /// are expanded into nested tables, so the file loads back with
+    /// [`ParameterManager::load_from_file`] to the same parameters.
+    ///
+    /// # Returns
+    /// `Ok(())`, `AuraError::IoError` if the file cannot be written, or a
+    /// configuration/serialization error if the parameters cannot be represented (e.g.
+    /// `a` and `a.b` both set, or a `Null` dumped to TOML).
+    pub fn dump_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let format = Format::of(path)?;
+        let tree = ParamTree::from_flat(self.list_parameters("")?)?;
+        std::fs::write(path, format.render(&tree)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Dumps a manager holding every value type under nested names to a file with
+    /// `extension`, loads it into a fresh manager and compares the listings.
+    fn round_trip(extension: &str, with_null: bool) {
+        let params = ParameterManager::new("dumped");
+        params.declare_parameter("camera.name", ParamValue::String("front".to_string())).unwrap();
+        params.declare_parameter("camera.fps", ParamValue::Int(30)).unwrap();
+        params.declare_parameter("camera.exposure", ParamValue::Float(1.0)).unwrap();
+        params.declare_parameter("camera.lens.auto_focus", ParamValue::Bool(true)).unwrap();
+        params.declare_parameter("gain", ParamValue::Float(-0.25)).unwrap();
+        if with_null {
+            params.declare_parameter("calibration_file", ParamValue::Null).unwrap();
+        }
+        let path = std::env::temp_dir().join(format!("aura_dump_{}.{}", std::process::id(), extension));
+        params.dump_to_file(&path).unwrap();
+
+        let loaded = ParameterManager::new("loaded");
+        loaded.load_from_file(&path).unwrap();
+        assert_eq!(loaded.list_parameters("").unwrap(), params.list_parameters("").unwrap());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_dump_loads_back() {
+        round_trip("json", true);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_dump_loads_back() {
+        round_trip("yaml", true);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_dump_loads_back() {
+        // TOML has no null.
+        round_trip("toml", false);
+    }
+}
@@ -0,0 +1,129 @@
+// aura_os/aura_core/src/param/journal.rs
+
+//! Write-ahead journal of parameter changes, for restoring the last-known parameter
+//! state after a crash.
+//!
+//! The journal is a newline-delimited JSON file of [`JournalEntry`] records, appended
+//! to before each change is applied. Compaction rewrites it as a snapshot: one entry
+//! per current parameter. This module is only available with the `journal` feature.
+
+use super::ParamValue;
+use crate::error::{AuraError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// One journaled parameter change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Time of the change in nanoseconds since the UNIX epoch.
+    pub timestamp_ns: u64,
+    /// The parameter name.
+    pub name: String,
+    /// The new value, or `None` if the parameter was undeclared.
+    pub value: Option<ParamValue>,
+}
+
+impl JournalEntry {
+    fn now(name: &str, value: Option<&ParamValue>) -> Self {
+        Self {
+            timestamp_ns: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as u64,
+            name: name.to_string(),
+            value: value.cloned(),
+        }
+    }
+}
+
+/// An open journal file that changes are appended to.
+#[derive(Debug)]
+pub(crate) struct Journal {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl Journal {
+    /// Opens (or creates) the journal at `path` for appending.
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Appends entries for `changes` and flushes them, so they are on disk before the
+    /// changes are applied.
+    pub(crate) fn append<'a, I>(&mut self, changes: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (&'a str, Option<&'a ParamValue>)>,
+    {
+        for (name, value) in changes {
+            let line = serde_json::to_string(&JournalEntry::now(name, value))?;
+            writeln!(self.writer, "{}", line)?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Replaces the journal contents with a snapshot of `values`.
+    ///
+    /// The snapshot is written to a temporary file and renamed over the journal, so a
+    /// crash during compaction leaves either the old journal or the new snapshot.
+    pub(crate) fn compact(&mut self, values: &HashMap<String, ParamValue>) -> Result<()> {
+        let mut temp_name = self.path.clone().into_os_string();
+        temp_name.push(".compact");
+        let temp_path = PathBuf::from(temp_name);
+
+        let mut names: Vec<&String> = values.keys().collect();
+        names.sort();
+        {
+            let mut snapshot = BufWriter::new(File::create(&temp_path)?);
+            for name in names {
+                let line = serde_json::to_string(&JournalEntry::now(name, values.get(name)))?;
+                writeln!(snapshot, "{}", line)?;
+            }
+            snapshot.flush()?;
+        }
+        fs::rename(&temp_path, &self.path)?;
+
+        *self = Self::open(&self.path)?;
+        Ok(())
+    }
+}
+
+/// Reads every entry of the journal at `path`, in the order they were written.
+///
+/// # Returns
+/// The entries, `AuraError::IoError` if the file cannot be read, or
+/// `AuraError::SerializationError` if a line is not a valid entry. A torn final line
+/// (from a crash mid-write) is ignored.
+pub(crate) fn read_entries(path: &Path) -> Result<Vec<JournalEntry>> {
+    let lines: Vec<String> = BufReader::new(File::open(path)?).lines().collect::<std::io::Result<_>>()?;
+    let last_index = lines.len().saturating_sub(1);
+
+    let mut entries = Vec::with_capacity(lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push(entry),
+            Err(_) if index == last_index => break,
+            Err(e) => {
+                return Err(AuraError::SerializationError(format!(
+                    "Invalid journal entry on line {} of '{}': {}",
+                    index + 1,
+                    path.display(),
+                    e
+                )))
+            }
+        }
+    }
+    Ok(entries)
+}
@@ -3,7 +3,27 @@
 use crate::error::{AuraError, Result};
 use crate::aura_log; // Internal logging macro
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock}; // RwLock for efficient read-heavy access to parameters
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard}; // RwLock for efficient read-heavy access to parameters
+
+pub mod descriptor;
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+pub mod file;
+#[cfg(feature = "journal")]
+pub mod journal;
+pub mod snapshot;
+pub mod source;
+pub mod units;
+pub use descriptor::{NumericRange, ParameterDescriptor};
+#[cfg(feature = "journal")]
+pub use journal::JournalEntry;
+pub use snapshot::ParameterSnapshot;
+pub use source::ParamSource;
+pub use units::{Dimension, UnitConverter};
+
+lazy_static::lazy_static! {
+    /// Shared converter with the built-in units, used by `ParameterManager::get_f64_as`.
+    static ref BUILTIN_UNITS: UnitConverter = UnitConverter::default();
+}
 
 /// Represents the possible types of values a parameter can hold.
 ///
@@ -11,14 +31,22 @@ use std::sync::{Arc, RwLock}; // RwLock for efficient read-heavy access to param
 /// - `Vec<ParamValue>` for lists/arrays of parameters.
 /// - `HashMap<String, ParamValue>` for nested parameter structures/dictionaries.
 /// - Byte arrays for arbitrary binary data.
+///
 /// It would also likely be backed by a serialization framework (e.g., `serde`)
 /// to allow easy conversion to/from common configuration file formats (YAML, JSON, TOML).
+///
+/// With the `serde` feature the enum uses serde's externally-tagged representation,
+/// e.g. `{"Float":1.0}` in JSON, so every variant (including `Null`) round-trips.
 #[derive(Debug, Clone, PartialEq)] // PartialEq for easy comparison in tests and logic
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParamValue {
     String(String),
     Int(i64),
     Float(f64),
     Bool(bool),
+    /// A declared parameter that currently has no value (e.g., an optional file path).
+    /// All `as_*` getters return `None` for it.
+    Null,
     // Future extensions:
     // StringArray(Vec<String>),
     // IntArray(Vec<i64>),
@@ -48,6 +76,11 @@ impl ParamValue {
         if let ParamValue::Bool(b) = self { Some(*b) } else { None }
     }
 
+    /// Returns `true` if this is `ParamValue::Null` (present but unset).
+    pub fn is_null(&self) -> bool {
+        matches!(self, ParamValue::Null)
+    }
+
     // Example of a more robust getter that returns a Result for better error handling
     pub fn get_string(&self) -> Result<&String> {
         match self {
@@ -58,16 +91,200 @@ impl ParamValue {
             ))),
         }
     }
-    // Similar `get_i64()`, `get_f64()`, `get_bool()` methods could be added.
+
+    pub fn get_i64(&self) -> Result<i64> {
+        match self {
+            ParamValue::Int(i) => Ok(*i),
+            _ => Err(AuraError::ParameterConfigurationError(format!(
+                "Expected Int, found {:?}",
+                self
+            ))),
+        }
+    }
+
+    /// Like the other getters, but an `Int` is also accepted and widened to `f64`, since
+    /// configuration files often write `5` where `5.0` is meant. Integers beyond 2^53
+    /// lose precision in the conversion.
+    pub fn get_f64(&self) -> Result<f64> {
+        match self {
+            ParamValue::Float(f) => Ok(*f),
+            ParamValue::Int(i) => Ok(*i as f64),
+            _ => Err(AuraError::ParameterConfigurationError(format!(
+                "Expected Float, found {:?}",
+                self
+            ))),
+        }
+    }
+
+    pub fn get_bool(&self) -> Result<bool> {
+        match self {
+            ParamValue::Bool(b) => Ok(*b),
+            _ => Err(AuraError::ParameterConfigurationError(format!(
+                "Expected Bool, found {:?}",
+                self
+            ))),
+        }
+    }
+
+    /// Parses a raw string (e.g., from an environment variable), inferring its type.
+    ///
+    /// Tries, in order: `Bool` ("true"/"false", case-insensitive), `Int`, `Float`,
+    /// and falls back to `String`.
+    pub fn parse_inferred(raw: &str) -> ParamValue {
+        let trimmed = raw.trim();
+        if trimmed.eq_ignore_ascii_case("true") {
+            ParamValue::Bool(true)
+        } else if trimmed.eq_ignore_ascii_case("false") {
+            ParamValue::Bool(false)
+        } else if let Ok(i) = trimmed.parse::<i64>() {
+            ParamValue::Int(i)
+        } else if let Ok(f) = trimmed.parse::<f64>() {
+            ParamValue::Float(f)
+        } else {
+            ParamValue::String(raw.to_string())
+        }
+    }
+
+    /// Parses a raw string as the same variant as `template`.
+    ///
+    /// A `Null` template carries no type, so the type is inferred as in
+    /// [`ParamValue::parse_inferred`].
+    ///
+    /// # Returns
+    /// The parsed value, or `AuraError::ParameterConfigurationError` if `raw` cannot be
+    /// parsed as that type.
+    pub fn parse_as(raw: &str, template: &ParamValue) -> Result<ParamValue> {
+        let trimmed = raw.trim();
+        let parsed = match template {
+            ParamValue::String(_) => Some(ParamValue::String(raw.to_string())),
+            ParamValue::Int(_) => trimmed.parse().ok().map(ParamValue::Int),
+            ParamValue::Float(_) => trimmed.parse().ok().map(ParamValue::Float),
+            ParamValue::Bool(_) => match trimmed.to_ascii_lowercase().as_str() {
+                "true" => Some(ParamValue::Bool(true)),
+                "false" => Some(ParamValue::Bool(false)),
+                _ => None,
+            },
+            ParamValue::Null => Some(ParamValue::parse_inferred(raw)),
+        };
+        parsed.ok_or_else(|| AuraError::ParameterConfigurationError(format!(
+            "Cannot parse '{}' as the type of {:?}",
+            raw, template
+        )))
+    }
+
+    /// Encodes the value as message data for `Publisher::publish_value`: a type tag,
+    /// a colon and the value, e.g. `"f64:3.14"`, `"i64:-2"`, `"bool:true"` or
+    /// `"str:hello"`, and `"null"` for `Null`. Floats use Rust's shortest round-trip
+    /// formatting, so decoding gives back the same `f64` bits (NaN aside).
+    pub fn to_message_data(&self) -> String {
+        match self {
+            ParamValue::String(s) => format!("str:{}", s),
+            ParamValue::Int(i) => format!("i64:{}", i),
+            ParamValue::Float(f) => format!("f64:{:?}", f),
+            ParamValue::Bool(b) => format!("bool:{}", b),
+            ParamValue::Null => "null".to_string(),
+        }
+    }
+
+    /// Decodes message data written by [`ParamValue::to_message_data`].
+    ///
+    /// # Returns
+    /// The value, or `AuraError::SerializationError` if the tag is unknown or the value
+    /// does not parse as the tagged type.
+    pub fn from_message_data(data: &str) -> Result<ParamValue> {
+        let malformed = || AuraError::SerializationError(format!("Malformed parameter value payload '{}'", data));
+        if data == "null" {
+            return Ok(ParamValue::Null);
+        }
+        let (tag, value) = data.split_once(':').ok_or_else(malformed)?;
+        match tag {
+            "str" => Ok(ParamValue::String(value.to_string())),
+            "i64" => value.parse().map(ParamValue::Int).map_err(|_| malformed()),
+            "f64" => value.parse().map(ParamValue::Float).map_err(|_| malformed()),
+            "bool" => value.parse().map(ParamValue::Bool).map_err(|_| malformed()),
+            _ => Err(malformed()),
+        }
+    }
+}
+
+/// Conversion from a `ParamValue` into a concrete Rust type, used by
+/// `ParameterManager::get_parameter_as`.
+///
+/// Conversions are strict: the only implicit conversion is `Int` to `f64`, which is
+/// lossless for realistic parameter values. `Option<T>` maps `Null` to `None`.
+pub trait FromParamValue: Sized {
+    /// Name of the expected type, used in mismatch errors.
+    const TYPE_NAME: &'static str;
+
+    /// Converts `value`, or returns `None` if it holds a different type.
+    fn from_param_value(value: &ParamValue) -> Option<Self>;
+}
+
+impl FromParamValue for String {
+    const TYPE_NAME: &'static str = "String";
+
+    fn from_param_value(value: &ParamValue) -> Option<Self> {
+        value.as_string().cloned()
+    }
+}
+
+impl FromParamValue for i64 {
+    const TYPE_NAME: &'static str = "Int";
+
+    fn from_param_value(value: &ParamValue) -> Option<Self> {
+        value.as_i64()
+    }
+}
+
+impl FromParamValue for f64 {
+    const TYPE_NAME: &'static str = "Float";
+
+    fn from_param_value(value: &ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Float(f) => Some(*f),
+            ParamValue::Int(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+}
+
+impl FromParamValue for bool {
+    const TYPE_NAME: &'static str = "Bool";
+
+    fn from_param_value(value: &ParamValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl<T: FromParamValue> FromParamValue for Option<T> {
+    const TYPE_NAME: &'static str = T::TYPE_NAME;
+
+    fn from_param_value(value: &ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Null => Some(None),
+            other => T::from_param_value(other).map(Some),
+        }
+    }
 }
 
 /// Manages parameters for a specific scope (e.g., a node or a global context).
 ///
 /// This `ParameterManager` provides an API to declare, set, and get parameters.
+///
+/// A manager created with [`ParameterManager::with_parent`] inherits the parameters of
+/// its parent: lookups that miss locally fall back to the parent (and its parent, and so
+/// on), while writes always go to the local scope, shadowing the parent's value. This
+/// lets global defaults be shared across nodes.
+///
+/// Every value remembers its [`ParamSource`], and values are applied by precedence
+/// rather than by order: `NodeBuilder`/command-line overrides beat environment variables,
+/// which beat configuration files, which beat declared defaults. Declaring a parameter
+/// that already has a value is therefore a no-op, and loading a file after the
+/// environment does not undo the environment's overrides.
+///
 /// In a complete AuraOS system:
 /// - It would interact with a distributed parameter server or load parameters
 ///   from configuration files (YAML, TOML, JSON).
-/// - It would support parameter descriptors (defining type, range, description).
 /// - It would allow registering callbacks for parameter change events.
 /// - It would integrate with the `aura` CLI for `aura param list/get/set/load` commands.
 #[derive(Debug)]
@@ -78,7 +295,68 @@ pub struct ParameterManager {
     /// The internal storage for parameters.
     /// `Arc<RwLock<...>>` allows multiple readers or one writer, suitable for
     /// parameters that are read frequently and written less often.
-    parameters: Arc<RwLock<HashMap<String, ParamValue>>>,
+    parameters: Arc<RwLock<ParameterStore>>,
+    /// Scope to fall back to for parameters not set locally.
+    parent: Option<Arc<ParameterManager>>,
+}
+
+/// Parameter values and their descriptors, guarded together by one lock so a value
+/// is always validated against the descriptor in force when it is written.
+#[derive(Debug, Default)]
+struct ParameterStore {
+    values: HashMap<String, ParamValue>,
+    /// Source of each entry in `values`.
+    sources: HashMap<String, ParamSource>,
+    /// Only parameters declared with a descriptor have an entry here.
+    descriptors: HashMap<String, ParameterDescriptor>,
+    /// If set, runtime writes may only change parameters that already have a value.
+    strict: bool,
+    /// Write-ahead journal of changes, if enabled.
+    #[cfg(feature = "journal")]
+    journal: Option<journal::Journal>,
+}
+
+impl ParameterStore {
+    /// Sets `name` to `value`, recording where it came from.
+    fn insert(&mut self, name: String, value: ParamValue, source: ParamSource) {
+        self.sources.insert(name.clone(), source);
+        self.values.insert(name, value);
+    }
+
+    /// Sets `name` to the declared default `value` unless it already has a value.
+    fn insert_default(&mut self, name: &str, value: ParamValue) {
+        if let std::collections::hash_map::Entry::Vacant(entry) = self.values.entry(name.to_string()) {
+            entry.insert(value);
+            self.sources.insert(name.to_string(), ParamSource::Default);
+        }
+    }
+
+    /// Removes `name`'s value and source.
+    fn remove(&mut self, name: &str) -> Option<ParamValue> {
+        self.sources.remove(name);
+        self.values.remove(name)
+    }
+
+    /// Checks that a write of `name` from `source` is allowed under strict parameters.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::ParameterNotFound` for a runtime write of a parameter
+    /// without a value while strict.
+    fn check_declared(&self, scope_name: &str, name: &str, source: ParamSource) -> Result<()> {
+        if self.strict && source == ParamSource::Runtime && !self.values.contains_key(name) {
+            return Err(AuraError::ParameterNotFound(format!(
+                "[{}] Parameter '{}' is not declared, and strict parameters reject setting undeclared ones.",
+                scope_name, name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the source of `name`'s value if it ranks above `source`, so that a value
+    /// from `source` must not replace it.
+    fn outranking_source(&self, name: &str, source: ParamSource) -> Option<ParamSource> {
+        self.sources.get(name).copied().filter(|current| *current > source)
+    }
 }
 
 impl ParameterManager {
@@ -87,7 +365,87 @@ impl ParameterManager {
         aura_log!(debug, "Initializing ParameterManager for scope: '{}'", scope_name);
         Self {
             scope_name: scope_name.to_string(),
-            parameters: Arc::new(RwLock::new(HashMap::new())),
+            parameters: Arc::new(RwLock::new(ParameterStore::default())),
+            parent: None,
+        }
+    }
+
+    /// Creates a `ParameterManager` for `scope_name` that inherits from `parent`.
+    ///
+    /// Reads (`get_parameter` and friends, `has_parameter`, `list_parameters`) fall back
+    /// to the parent for names not set locally. Writes stay local. Declaring a parameter
+    /// the parent has takes the parent's value as the local one, so shared defaults win
+    /// over the defaults in code, like any existing value does.
+    pub fn with_parent(scope_name: &str, parent: Arc<ParameterManager>) -> Self {
+        aura_log!(debug, "Initializing ParameterManager for scope '{}' with parent '{}'", scope_name, parent.scope_name);
+        Self {
+            parent: Some(parent),
+            ..Self::new(scope_name)
+        }
+    }
+
+    /// Turns strict parameters on or off (off by default).
+    ///
+    /// While strict, runtime writes (`set_parameter`, `set_parameters_atomically`) of a
+    /// parameter that has no value in this manager fail with
+    /// `AuraError::ParameterNotFound`, so a mistyped name is reported instead of
+    /// silently creating a new parameter. Parameters get a value by being declared or
+    /// from a file, the environment or an override; those sources are not restricted,
+    /// since they are usually applied before the code declares its parameters.
+    pub fn set_strict(&self, strict: bool) {
+        self.write_params().strict = strict;
+    }
+
+    /// Returns `true` if strict parameters are on; see [`ParameterManager::set_strict`].
+    pub fn is_strict(&self) -> bool {
+        self.read_params().strict
+    }
+
+    /// Returns the manager this one inherits from, if any.
+    pub fn parent(&self) -> Option<&Arc<ParameterManager>> {
+        self.parent.as_ref()
+    }
+
+    /// Read-locks the parameter store, recovering it if a panicking thread poisoned
+    /// the lock.
+    ///
+    /// Updates are validated before they touch the store and each write is a plain map
+    /// insert or removal, so a panic elsewhere cannot leave a half-applied value behind.
+    /// The poison is cleared so the warning is logged once per panic.
+    fn read_params(&self) -> RwLockReadGuard<'_, ParameterStore> {
+        self.parameters.read().unwrap_or_else(|poisoned| {
+            self.recover_poisoned();
+            poisoned.into_inner()
+        })
+    }
+
+    /// Write-locks the parameter store; see [`ParameterManager::read_params`].
+    fn write_params(&self) -> RwLockWriteGuard<'_, ParameterStore> {
+        self.parameters.write().unwrap_or_else(|poisoned| {
+            self.recover_poisoned();
+            poisoned.into_inner()
+        })
+    }
+
+    fn recover_poisoned(&self) {
+        aura_log!(warn, "[{}] Parameter lock was poisoned by a panicking thread; recovering", self.scope_name);
+        self.parameters.clear_poison();
+    }
+
+    /// Looks `name` up locally, then along the parent chain, without logging.
+    fn lookup(&self, name: &str) -> Result<Option<ParamValue>> {
+        let local = self.read_params().values.get(name).cloned();
+        match local {
+            Some(value) => Ok(Some(value)),
+            None => self.inherited(name),
+        }
+    }
+
+    /// Returns the value `name` inherits from the parent chain, if any.
+    fn inherited(&self, name: &str) -> Result<Option<ParamValue>> {
+        match &self.parent {
+            Some(parent) => parent.lookup(name),
+            None => Ok(None),
         }
     }
 
@@ -102,7 +460,7 @@ impl ParameterManager {
     /// * `default_value`: The `ParamValue` to use if the parameter is not already set.
     ///
     /// # Returns
-    /// `Ok(())` if successful, or an `AuraError` if declaration fails (e.g., lock poisoning).
+    /// `Ok(())` if successful, once the parameter is declared.
     pub fn declare_parameter(&self, name: &str, default_value: ParamValue) -> Result<()> {
         aura_log!(
             info,
@@ -111,15 +469,53 @@ impl ParameterManager {
             name,
             default_value
         );
-        let mut params_writer = self
-            .parameters
-            .write()
-            .map_err(|_| AuraError::ParameterConfigurationError(
-                format!("[{}] Failed to acquire write lock for parameters.", self.scope_name)
-            ))?;
-
-        // `entry(key).or_insert(value)` is an idiomatic way to insert if not present.
-        params_writer.entry(name.to_string()).or_insert(default_value);
+        // An inherited value takes the place of the default.
+        let default_value = self.inherited(name)?.unwrap_or(default_value);
+        let mut params_writer = self.write_params();
+
+        params_writer.insert_default(name, default_value);
+        Ok(())
+    }
+
+    /// Declares a parameter with a default value and a descriptor constraining it.
+    ///
+    /// The descriptor is enforced by every later `set_parameter`: values outside its
+    /// range or not among its choices are rejected, and read-only parameters cannot be
+    /// changed at all. As with `declare_parameter`, an already-present value is kept,
+    /// but it must satisfy the descriptor too. A `Null` default makes the descriptor
+    /// `optional`, since the parameter starts out unset.
+    ///
+    /// # Arguments
+    /// * `name`: The name of the parameter.
+    /// * `default_value`: The `ParamValue` to use if the parameter is not already set.
+    /// * `descriptor`: The `ParameterDescriptor` describing and constraining the parameter.
+    ///
+    /// # Returns
+    /// `Ok(())` if successful, or `AuraError::ParameterConfigurationError` if the default
+    /// (or existing) value violates the descriptor.
+    pub fn declare_parameter_with_descriptor(
+        &self,
+        name: &str,
+        default_value: ParamValue,
+        descriptor: ParameterDescriptor,
+    ) -> Result<()> {
+        aura_log!(
+            info,
+            "[{}] Declaring parameter '{}' with default: {:?} and descriptor: {:?}",
+            self.scope_name,
+            name,
+            default_value,
+            descriptor
+        );
+        let default_value = self.inherited(name)?.unwrap_or(default_value);
+        let descriptor = if default_value.is_null() { descriptor.optional() } else { descriptor };
+        let mut params_writer = self.write_params();
+
+        let effective_value = params_writer.values.get(name).unwrap_or(&default_value);
+        descriptor.validate(name, effective_value)?;
+
+        params_writer.insert_default(name, default_value);
+        params_writer.descriptors.insert(name.to_string(), descriptor);
         Ok(())
     }
 
@@ -134,26 +530,132 @@ impl ParameterManager {
     /// * `name`: The name of the parameter.
     /// * `value`: The new `ParamValue` for the parameter.
     ///
+    /// If the parameter was declared with a descriptor, the value is validated against
+    /// it first.
+    ///
+    /// The value counts as `ParamSource::Runtime`, so it always applies.
+    ///
     /// # Returns
-    /// `Ok(())` if successful, or an `AuraError`.
+    /// `Ok(())` if successful, or `AuraError::ParameterConfigurationError` if the
+    /// parameter is read-only or the value violates its descriptor.
     pub fn set_parameter(&self, name: &str, value: ParamValue) -> Result<()> {
+        self.set_parameter_from(name, value, ParamSource::Runtime).map(|_| ())
+    }
+
+    /// Like [`ParameterManager::set_parameter`], but for a value from `source`, which is
+    /// ignored if the parameter's current value comes from a higher-ranked source.
+    ///
+    /// # Returns
+    /// `Ok(true)` if the value was set, `Ok(false)` if it was outranked, or the same
+    /// errors as `set_parameter`.
+    pub fn set_parameter_from(&self, name: &str, value: ParamValue, source: ParamSource) -> Result<bool> {
         aura_log!(
             info,
-            "[{}] Setting parameter '{}' to: {:?}",
+            "[{}] Setting parameter '{}' to: {:?} (from {:?})",
             self.scope_name,
             name,
-            value
+            value,
+            source
         );
-        let mut params_writer = self
-            .parameters
-            .write()
-            .map_err(|_| AuraError::ParameterConfigurationError(
-                format!("[{}] Failed to acquire write lock for parameters.", self.scope_name)
-            ))?;
-
-        params_writer.insert(name.to_string(), value);
+        let mut params_writer = self.write_params();
+
+        if let Some(current) = params_writer.outranking_source(name, source) {
+            aura_log!(debug, "[{}] Keeping parameter '{}': its {:?} value outranks {:?}", self.scope_name, name, current, source);
+            return Ok(false);
+        }
+        params_writer.check_declared(&self.scope_name, name, source)?;
+
+        if let Some(descriptor) = params_writer.descriptors.get(name) {
+            if descriptor.read_only {
+                return Err(AuraError::ParameterConfigurationError(format!(
+                    "[{}] Parameter '{}' is read-only.",
+                    self.scope_name, name
+                )));
+            }
+            descriptor.validate(name, &value)?;
+        }
+
+        #[cfg(feature = "journal")]
+        if let Some(journal) = params_writer.journal.as_mut() {
+            journal.append([(name, Some(&value))])?;
+        }
+        params_writer.insert(name.to_string(), value, source);
         // In a real system, this might trigger parameter change callbacks.
-        Ok(())
+        Ok(true)
+    }
+
+    /// Sets several parameters as a single atomic update.
+    ///
+    /// Every update is validated against its parameter's descriptor (read-only flag,
+    /// range and choices) before anything is written. If any update is rejected, none
+    /// are applied and the error for the first offending key is returned. Otherwise all
+    /// values are written under one write lock, so readers never observe a partially
+    /// applied batch.
+    ///
+    /// # Arguments
+    /// * `updates`: `(name, value)` pairs to apply, in order.
+    ///
+    /// The values count as `ParamSource::Runtime`, so they always apply.
+    ///
+    /// # Returns
+    /// `Ok(())` if all updates were applied, or the `AuraError` for the first rejected one.
+    pub fn set_parameters_atomically(&self, updates: Vec<(String, ParamValue)>) -> Result<()> {
+        self.set_parameters_atomically_from(updates, ParamSource::Runtime).map(|_| ())
+    }
+
+    /// Like [`ParameterManager::set_parameters_atomically`], but for values from
+    /// `source`. Updates of parameters whose current value comes from a higher-ranked
+    /// source are left out; the rest are validated and applied atomically.
+    ///
+    /// # Returns
+    /// The number of updates applied, or the `AuraError` for the first rejected one.
+    pub fn set_parameters_atomically_from(&self, updates: Vec<(String, ParamValue)>, source: ParamSource) -> Result<usize> {
+        aura_log!(
+            info,
+            "[{}] Atomically setting {} parameter(s) from {:?}: {:?}",
+            self.scope_name,
+            updates.len(),
+            source,
+            updates
+        );
+        let mut params_writer = self.write_params();
+
+        let updates: Vec<(String, ParamValue)> = updates
+            .into_iter()
+            .filter(|(name, _)| match params_writer.outranking_source(name, source) {
+                Some(current) => {
+                    aura_log!(debug, "[{}] Keeping parameter '{}': its {:?} value outranks {:?}", self.scope_name, name, current, source);
+                    false
+                }
+                None => true,
+            })
+            .collect();
+
+        // Validate everything first so a rejected update leaves the store untouched.
+        for (name, value) in &updates {
+            params_writer.check_declared(&self.scope_name, name, source)?;
+            if let Some(descriptor) = params_writer.descriptors.get(name) {
+                if descriptor.read_only {
+                    return Err(AuraError::ParameterConfigurationError(format!(
+                        "[{}] Parameter '{}' is read-only; no parameters were changed.",
+                        self.scope_name, name
+                    )));
+                }
+                // The validation error names the offending key.
+                descriptor.validate(name, value)?;
+            }
+        }
+
+        #[cfg(feature = "journal")]
+        if let Some(journal) = params_writer.journal.as_mut() {
+            journal.append(updates.iter().map(|(name, value)| (name.as_str(), Some(value))))?;
+        }
+        let applied = updates.len();
+        for (name, value) in updates {
+            params_writer.insert(name, value, source);
+        }
+        // In a real system, parameter change callbacks would fire here, after all writes.
+        Ok(applied)
     }
 
     /// Gets the value of a parameter.
@@ -162,20 +664,13 @@ impl ParameterManager {
     /// * `name`: The name of the parameter to retrieve.
     ///
     /// # Returns
-    /// A `Result` containing the `ParamValue` if found, or an `AuraError::ParameterNotFound`
-    /// if the parameter does not exist.
+    /// A `Result` containing the `ParamValue` if found (locally or, failing that, in the
+    /// parent chain), or an `AuraError::ParameterNotFound` if the parameter does not exist.
     pub fn get_parameter(&self, name: &str) -> Result<ParamValue> {
-        let params_reader = self
-            .parameters
-            .read()
-            .map_err(|_| AuraError::ParameterConfigurationError(
-                format!("[{}] Failed to acquire read lock for parameters.", self.scope_name)
-            ))?;
-
-        match params_reader.get(name) {
+        match self.lookup(name)? {
             Some(value) => {
                 aura_log!(trace, "[{}] Getting parameter '{}': {:?}", self.scope_name, name, value);
-                Ok(value.clone()) // Clone the value to return ownership
+                Ok(value)
             }
             None => {
                 aura_log!(warn, "[{}] Parameter '{}' not found.", self.scope_name, name);
@@ -187,20 +682,680 @@ impl ParameterManager {
         }
     }
 
-    /// Checks if a parameter exists.
+    /// Lists the parameters whose names start with `prefix` (`""` for all), sorted by name.
+    /// Inherited parameters are included unless shadowed locally.
+    ///
+    /// # Returns
+    /// The `(name, value)` pairs.
+    pub fn list_parameters(&self, prefix: &str) -> Result<Vec<(String, ParamValue)>> {
+        let mut listed: HashMap<String, ParamValue> = match &self.parent {
+            Some(parent) => parent.list_parameters(prefix)?.into_iter().collect(),
+            None => HashMap::new(),
+        };
+        let params_reader = self.read_params();
+        listed.extend(
+            params_reader
+                .values
+                .iter()
+                .filter(|(name, _)| name.starts_with(prefix))
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        drop(params_reader);
+
+        let mut listed: Vec<(String, ParamValue)> = listed.into_iter().collect();
+        listed.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(listed)
+    }
+
+    /// Gets the value of a parameter converted to `T`.
+    ///
+    /// Example: `params.get_parameter_as::<f64>("publish_rate_hz")?`
+    ///
+    /// # Returns
+    /// The converted value, `AuraError::ParameterNotFound` if the parameter does not
+    /// exist, or `AuraError::ParameterConfigurationError` if it holds a different type.
+    pub fn get_parameter_as<T: FromParamValue>(&self, name: &str) -> Result<T> {
+        let value = self.get_parameter(name)?;
+        T::from_param_value(&value).ok_or_else(|| AuraError::ParameterConfigurationError(format!(
+            "[{}] Parameter '{}' is not a {}: found {:?}.",
+            self.scope_name, name, T::TYPE_NAME, value
+        )))
+    }
+
+    /// Gets the value of a parameter converted to `T`, or `default` if it is missing.
+    ///
+    /// A type mismatch also yields `default`, but is logged as a warning, since it
+    /// usually indicates a configuration mistake.
+    pub fn get_parameter_or<T: FromParamValue>(&self, name: &str, default: T) -> T {
+        match self.get_parameter_as(name) {
+            Ok(value) => value,
+            Err(AuraError::ParameterNotFound(_)) => default,
+            Err(e) => {
+                aura_log!(warn, "{} Using the default instead.", e);
+                default
+            }
+        }
+    }
+
+    /// Checks if a parameter exists, locally or in the parent chain.
     pub fn has_parameter(&self, name: &str) -> Result<bool> {
-        let params_reader = self
-            .parameters
-            .read()
-            .map_err(|_| AuraError::ParameterConfigurationError(
-                format!("[{}] Failed to acquire read lock for parameters.", self.scope_name)
-            ))?;
-        Ok(params_reader.contains_key(name))
+        Ok(self.lookup(name)?.is_some())
+    }
+
+    /// Checks whether a parameter is declared but currently unset (`ParamValue::Null`).
+    ///
+    /// # Returns
+    /// `Ok(true)` if the parameter holds `Null`, `Ok(false)` if it holds a value, or
+    /// `AuraError::ParameterNotFound` if it has not been declared.
+    pub fn is_null(&self, name: &str) -> Result<bool> {
+        match self.lookup(name)? {
+            Some(value) => Ok(value.is_null()),
+            None => Err(AuraError::ParameterNotFound(format!(
+                "[{}] Parameter '{}' not found.",
+                self.scope_name, name
+            ))),
+        }
+    }
+
+    /// Gets the descriptor of a parameter.
+    ///
+    /// # Returns
+    /// The descriptor the parameter was declared with, `ParameterDescriptor::default()`
+    /// if it was declared without one, or `AuraError::ParameterNotFound` if the
+    /// parameter does not exist.
+    pub fn get_descriptor(&self, name: &str) -> Result<ParameterDescriptor> {
+        let params_reader = self.read_params();
+
+        if !params_reader.values.contains_key(name) {
+            drop(params_reader);
+            return match &self.parent {
+                Some(parent) => parent.get_descriptor(name),
+                None => Err(AuraError::ParameterNotFound(format!(
+                    "[{}] Parameter '{}' not found.",
+                    self.scope_name, name
+                ))),
+            };
+        }
+        Ok(params_reader.descriptors.get(name).cloned().unwrap_or_default())
+    }
+
+    /// Removes a parameter, returning its last value.
+    ///
+    /// Useful when reconfiguring a node (e.g., switching sensor modes) leaves some
+    /// parameters no longer applicable. After this call `get_parameter` reports the
+    /// parameter as not found (or returns its inherited value, if a parent has one)
+    /// until it is declared or set again.
+    ///
+    /// # Returns
+    /// The removed `ParamValue`, `AuraError::ParameterNotFound` if the parameter
+    /// did not exist, or `AuraError::ParameterConfigurationError` if it is read-only.
+    pub fn undeclare_parameter(&self, name: &str) -> Result<ParamValue> {
+        let mut params_writer = self.write_params();
+
+        if params_writer.descriptors.get(name).is_some_and(|d| d.read_only) {
+            return Err(AuraError::ParameterConfigurationError(format!(
+                "[{}] Cannot undeclare read-only parameter '{}'.",
+                self.scope_name, name
+            )));
+        }
+
+        #[cfg(feature = "journal")]
+        if params_writer.values.contains_key(name) {
+            if let Some(journal) = params_writer.journal.as_mut() {
+                journal.append([(name, None)])?;
+            }
+        }
+
+        match params_writer.remove(name) {
+            Some(old_value) => {
+                params_writer.descriptors.remove(name);
+                aura_log!(info, "[{}] Undeclared parameter '{}' (was: {:?})", self.scope_name, name, old_value);
+                Ok(old_value)
+            }
+            None => Err(AuraError::ParameterNotFound(format!(
+                "[{}] Cannot undeclare parameter '{}': not found.",
+                self.scope_name, name
+            ))),
+        }
+    }
+
+    /// Returns where the current value of `name` came from, looking along the parent
+    /// chain like `get_parameter`, or `None` if the parameter does not exist.
+    pub fn parameter_source(&self, name: &str) -> Option<ParamSource> {
+        let local = self.read_params().sources.get(name).copied();
+        local.or_else(|| self.parent.as_ref()?.parameter_source(name))
+    }
+
+    /// Captures every current parameter value; see [`ParameterManager::restore`].
+    ///
+    /// # Returns
+    /// The snapshot of this manager's own values.
+    pub fn snapshot(&self) -> Result<ParameterSnapshot> {
+        let params_reader = self.read_params();
+        Ok(ParameterSnapshot {
+            values: params_reader.values.clone(),
+            sources: params_reader.sources.clone(),
+        })
+    }
+
+    /// Puts the parameter values back to `snapshot` as a single atomic update.
+    ///
+    /// Only parameters that differ from the snapshot are touched: values that changed
+    /// are written back with the source they had in the snapshot, and parameters set
+    /// since the snapshot was taken are undeclared (losing their descriptors, as with
+    /// `undeclare_parameter`). Sources are put back for unchanged values too, so a
+    /// restored value ranks as it did before. As with `set_parameters_atomically`, every
+    /// change is checked against the current descriptors first and nothing is applied if
+    /// one is rejected.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::ParameterConfigurationError` if a changed parameter is
+    /// read-only or a value violates its descriptor.
+    pub fn restore(&self, snapshot: &ParameterSnapshot) -> Result<()> {
+        self.restore_changes(snapshot).map(|_| ())
+    }
+
+    /// Like [`ParameterManager::restore`], but reports what changed.
+    ///
+    /// # Returns
+    /// The names of the parameters whose value changed or that were undeclared, sorted,
+    /// or the errors of `restore`.
+    pub fn restore_changes(&self, snapshot: &ParameterSnapshot) -> Result<Vec<String>> {
+        let mut params_writer = self.write_params();
+
+        // `None` undeclares a parameter the snapshot does not have.
+        let mut changes: Vec<(&str, Option<&ParamValue>)> = snapshot
+            .values
+            .iter()
+            .filter(|(name, value)| params_writer.values.get(*name) != Some(value))
+            .map(|(name, value)| (name.as_str(), Some(value)))
+            .chain(
+                params_writer
+                    .values
+                    .keys()
+                    .filter(|name| !snapshot.values.contains_key(*name))
+                    .map(|name| (name.as_str(), None)),
+            )
+            .collect();
+        changes.sort_by(|a, b| a.0.cmp(b.0));
+
+        // Validate everything first so a rejected change leaves the store untouched.
+        for (name, value) in &changes {
+            if let Some(descriptor) = params_writer.descriptors.get(*name) {
+                if descriptor.read_only {
+                    return Err(AuraError::ParameterConfigurationError(format!(
+                        "[{}] Parameter '{}' is read-only; no parameters were restored.",
+                        self.scope_name, name
+                    )));
+                }
+                if let Some(value) = value {
+                    descriptor.validate(name, value)?;
+                }
+            }
+        }
+
+        // Release the borrow of the store before writing to it.
+        let changes: Vec<(String, Option<ParamValue>)> = changes
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.cloned()))
+            .collect();
+        #[cfg(feature = "journal")]
+        if let Some(journal) = params_writer.journal.as_mut() {
+            journal.append(changes.iter().map(|(name, value)| (name.as_str(), value.as_ref())))?;
+        }
+        let mut changed = Vec::with_capacity(changes.len());
+        for (name, value) in changes {
+            match value {
+                Some(value) => {
+                    let source = snapshot.source(&name).unwrap_or(ParamSource::Runtime);
+                    params_writer.insert(name.clone(), value, source);
+                }
+                None => {
+                    params_writer.remove(&name);
+                    params_writer.descriptors.remove(&name);
+                }
+            }
+            changed.push(name);
+        }
+        for name in snapshot.values.keys() {
+            if let Some(source) = snapshot.source(name) {
+                params_writer.sources.insert(name.clone(), source);
+            }
+        }
+        aura_log!(info, "[{}] Restored parameter snapshot; changed: {:?}", self.scope_name, changed);
+        // In a real system, parameter change callbacks would fire here, for `changed` only.
+        Ok(changed)
+    }
+
+    /// Starts journaling every subsequent change (set, atomic set, undeclare) to `path`.
+    ///
+    /// Each change is appended and flushed before it is applied. An existing journal is
+    /// appended to, so call [`ParameterManager::recover_from_journal`] first to restore
+    /// its state. Declared defaults are not journaled, since they come from code.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `AuraError::IoError` if the journal cannot be opened.
+    #[cfg(feature = "journal")]
+    pub fn enable_journal<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let journal = journal::Journal::open(path.as_ref())?;
+        let mut params_writer = self.write_params();
+        aura_log!(info, "[{}] Journaling parameter changes to '{}'", self.scope_name, path.as_ref().display());
+        params_writer.journal = Some(journal);
+        Ok(())
+    }
+
+    /// Restores parameter values by replaying the journal at `path`.
+    ///
+    /// Entries are applied in order, directly to the store: the journal records state
+    /// that was already accepted, so descriptors and read-only flags are not re-checked.
+    /// Recovered values are not re-journaled. Call this before declaring parameters so
+    /// the recovered values take precedence over declared defaults.
+    ///
+    /// # Returns
+    /// The number of entries replayed, or `AuraError::IoError` /
+    /// `AuraError::SerializationError` if the journal cannot be read.
+    #[cfg(feature = "journal")]
+    pub fn recover_from_journal<P: AsRef<std::path::Path>>(&self, path: P) -> Result<usize> {
+        let entries = journal::read_entries(path.as_ref())?;
+        let mut params_writer = self.write_params();
+
+        for entry in &entries {
+            match &entry.value {
+                Some(value) => {
+                    params_writer.insert(entry.name.clone(), value.clone(), ParamSource::Runtime);
+                }
+                None => {
+                    params_writer.remove(&entry.name);
+                }
+            }
+        }
+        aura_log!(info, "[{}] Recovered {} journal entries from '{}'", self.scope_name, entries.len(), path.as_ref().display());
+        Ok(entries.len())
+    }
+
+    /// Rewrites the active journal as a snapshot of the current values, one entry per
+    /// parameter, so it stops growing with every change.
+    ///
+    /// # Returns
+    /// `Ok(())`, `AuraError::ConfigurationError` if journaling is not enabled, or
+    /// `AuraError::IoError` if the snapshot cannot be written.
+    #[cfg(feature = "journal")]
+    pub fn compact_journal(&self) -> Result<()> {
+        let mut params_writer = self.write_params();
+        let store = &mut *params_writer;
+        match store.journal.as_mut() {
+            Some(journal) => journal.compact(&store.values),
+            None => Err(AuraError::ConfigurationError(format!(
+                "[{}] Cannot compact the parameter journal: journaling is not enabled.",
+                self.scope_name
+            ))),
+        }
+    }
+
+    /// Applies parameter overrides from environment variables.
+    ///
+    /// Every variable whose name starts with `prefix` (e.g., `AURA_PARAM_`) is turned into
+    /// a parameter name by stripping the prefix, lowercasing, and converting `__` into `.`
+    /// for nesting (so `AURA_PARAM_CAMERA__FPS` sets `camera.fps`). Values are parsed with
+    /// [`ParamValue::parse_inferred`], except for parameters declared with a descriptor,
+    /// whose current value pins the type. Values go through `set_parameter_from` as
+    /// `ParamSource::Environment`, so descriptor constraints apply, and the variables
+    /// take precedence over file values and defaults (whichever is applied first) but not
+    /// over overrides or runtime changes.
+    ///
+    /// # Returns
+    /// The number of parameters applied (outranked ones are not counted), or the first
+    /// `AuraError` encountered. Variables whose name or value is not valid UTF-8 are
+    /// skipped.
+    pub fn load_from_env(&self, prefix: &str) -> Result<usize> {
+        let mut overrides: Vec<(String, String)> = std::env::vars_os()
+            .filter_map(|(key, raw)| {
+                let key = key.into_string().ok()?;
+                let stripped = key.strip_prefix(prefix)?;
+                if stripped.is_empty() {
+                    return None;
+                }
+                let Ok(raw) = raw.into_string() else {
+                    aura_log!(warn, "[{}] Ignoring environment variable '{}': its value is not valid UTF-8.", self.scope_name, key);
+                    return None;
+                };
+                Some((stripped.to_lowercase().replace("__", "."), raw))
+            })
+            .collect();
+        // Apply in a deterministic order.
+        overrides.sort();
+
+        let mut applied = 0;
+        for (name, raw) in overrides {
+            let pinned_type = {
+                let params_reader = self.read_params();
+                if params_reader.descriptors.contains_key(&name) {
+                    params_reader.values.get(&name).cloned()
+                } else {
+                    None
+                }
+            };
+
+            let value = match pinned_type {
+                Some(template) => ParamValue::parse_as(&raw, &template)?,
+                None => ParamValue::parse_inferred(&raw),
+            };
+            aura_log!(debug, "[{}] Applying environment override '{}' = {:?}", self.scope_name, name, value);
+            if self.set_parameter_from(&name, value, ParamSource::Environment)? {
+                applied += 1;
+            }
+        }
+        Ok(applied)
+    }
+
+    /// Gets a numeric parameter and converts it between units.
+    ///
+    /// The parameter may be stored as either a `Float` or an `Int`. Conversion uses the
+    /// built-in units of [`UnitConverter::default`] (angle, length, velocity and angular
+    /// velocity), e.g. `get_f64_as("max_steer", "deg", "rad")`.
+    ///
+    /// # Returns
+    /// The converted value, `AuraError::ParameterNotFound` if the parameter does not exist,
+    /// or `AuraError::ParameterConfigurationError` if it is not numeric or the units are
+    /// unknown or incompatible.
+    pub fn get_f64_as(&self, name: &str, from_unit: &str, to_unit: &str) -> Result<f64> {
+        let value = match self.get_parameter(name)? {
+            ParamValue::Float(f) => f,
+            ParamValue::Int(i) => i as f64,
+            other => {
+                return Err(AuraError::ParameterConfigurationError(format!(
+                    "[{}] Parameter '{}' is not numeric: {:?}",
+                    self.scope_name, name, other
+                )))
+            }
+        };
+        BUILTIN_UNITS.convert(value, from_unit, to_unit)
     }
 
     // --- Future Enhancements ---
-    // - `load_from_file(path: &Path, format: ConfigFormat) -> Result<()>`
-    // - `get_parameter_with_descriptor(name: &str) -> Result<(ParamValue, ParameterDescriptor)>`
     // - `add_on_parameter_changed_callback(names: Vec<String>, callback: Arc<dyn Fn(Vec<ParameterEvent>)>)`
-    // - `list_parameters(prefix: &str) -> Result<Vec<(String, ParamValue)>>`
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atomic_batch_with_invalid_entry_applies_nothing() {
+        let params = ParameterManager::new("batch");
+        params.declare_parameter_with_descriptor("speed", ParamValue::Float(1.0), ParameterDescriptor::new("m/s").with_range(0.0, 2.0)).unwrap();
+        params.declare_parameter("name", ParamValue::String("rover".to_string())).unwrap();
+
+        let batch = vec![
+            ("name".to_string(), ParamValue::String("scout".to_string())),
+            ("speed".to_string(), ParamValue::Float(5.0)),
+            ("fresh".to_string(), ParamValue::Bool(true)),
+        ];
+        match params.set_parameters_atomically(batch) {
+            Err(AuraError::ParameterConfigurationError(message)) => assert!(message.contains("speed"), "{}", message),
+            other => panic!("expected a ParameterConfigurationError, got {:?}", other),
+        }
+        assert_eq!(params.get_parameter("name").unwrap(), ParamValue::String("rover".to_string()));
+        assert_eq!(params.get_parameter("speed").unwrap(), ParamValue::Float(1.0));
+        assert!(!params.has_parameter("fresh").unwrap());
+
+        let batch = vec![("name".to_string(), ParamValue::String("scout".to_string())), ("speed".to_string(), ParamValue::Float(1.5))];
+        params.set_parameters_atomically(batch).unwrap();
+        assert_eq!(params.get_parameter("speed").unwrap(), ParamValue::Float(1.5));
+    }
+
+    #[test]
+    fn null_parameter_becomes_set() {
+        let params = ParameterManager::new("calibration");
+        params.declare_parameter("calibration_file", ParamValue::Null).unwrap();
+        assert!(params.is_null("calibration_file").unwrap());
+        assert_eq!(params.get_parameter_as::<Option<String>>("calibration_file").unwrap(), None);
+
+        params.set_parameter("calibration_file", ParamValue::String("cam.yaml".to_string())).unwrap();
+        assert!(!params.is_null("calibration_file").unwrap());
+    }
+
+    #[test]
+    fn null_passes_descriptor_only_when_optional() {
+        let params = ParameterManager::new("rates");
+        let rate = ParameterDescriptor::new("Publish rate").with_range(0.1, 100.0);
+        params.declare_parameter_with_descriptor("rate_hz", ParamValue::Float(1.0), rate.clone()).unwrap();
+        assert!(params.set_parameter("rate_hz", ParamValue::Null).is_err());
+        assert_eq!(params.get_parameter("rate_hz").unwrap(), ParamValue::Float(1.0));
+
+        // Declared null: may be unset again after getting a value.
+        params.declare_parameter_with_descriptor("limit_hz", ParamValue::Null, rate).unwrap();
+        params.set_parameter("limit_hz", ParamValue::Float(5.0)).unwrap();
+        assert!(params.set_parameter("limit_hz", ParamValue::Float(500.0)).is_err());
+        params.set_parameter("limit_hz", ParamValue::Null).unwrap();
+        assert!(params.is_null("limit_hz").unwrap());
+    }
+
+    #[test]
+    fn restore_reports_only_changed_keys_and_keeps_sources() {
+        let params = ParameterManager::new("tuning");
+        params.declare_parameter("kp", ParamValue::Float(1.0)).unwrap();
+        params.declare_parameter("ki", ParamValue::Float(0.1)).unwrap();
+        params.declare_parameter("kd", ParamValue::Float(0.0)).unwrap();
+        params.set_parameter_from("mode", ParamValue::String("pid".to_string()), ParamSource::File).unwrap();
+        let snapshot = params.snapshot().unwrap();
+
+        params.set_parameter("kp", ParamValue::Float(2.0)).unwrap();
+        params.set_parameter("mode", ParamValue::String("pd".to_string())).unwrap();
+        params.set_parameter("kd", ParamValue::Float(0.0)).unwrap();
+        params.set_parameter("trial", ParamValue::Int(3)).unwrap();
+
+        let changed = params.restore_changes(&snapshot).unwrap();
+        assert_eq!(changed, ["kp", "mode", "trial"]);
+        assert_eq!(params.get_parameter("kp").unwrap(), ParamValue::Float(1.0));
+        assert_eq!(params.get_parameter("mode").unwrap(), ParamValue::String("pid".to_string()));
+        assert!(!params.has_parameter("trial").unwrap());
+        assert_eq!(params.parameter_source("kp"), Some(ParamSource::Default));
+        assert_eq!(params.parameter_source("kd"), Some(ParamSource::Default));
+        assert_eq!(params.parameter_source("mode"), Some(ParamSource::File));
+
+        // Restoring again changes nothing.
+        assert!(params.restore_changes(&snapshot).unwrap().is_empty());
+        params.restore(&snapshot).unwrap();
+    }
+
+    #[test]
+    fn poisoned_parameter_lock_is_recovered() {
+        let params = Arc::new(ParameterManager::new("poisoned"));
+        params.declare_parameter("speed", ParamValue::Float(1.0)).unwrap();
+        let panicking = Arc::clone(&params);
+        let result = std::thread::spawn(move || {
+            let _store = panicking.write_params();
+            panic!("panicking while holding the parameter lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(params.parameters.is_poisoned());
+
+        assert_eq!(params.get_parameter("speed").unwrap(), ParamValue::Float(1.0));
+        assert!(!params.parameters.is_poisoned());
+        params.set_parameter("speed", ParamValue::Float(2.0)).unwrap();
+        assert_eq!(params.get_parameter("speed").unwrap(), ParamValue::Float(2.0));
+    }
+
+    #[test]
+    fn environment_outranks_default_but_not_override() {
+        std::env::set_var("AURA_TEST_ENV_RANK_MAX_SPEED", "2.5");
+        std::env::set_var("AURA_TEST_ENV_RANK_MODE", "auto");
+        let params = ParameterManager::new("env_rank");
+        params.declare_parameter("max_speed", ParamValue::Float(1.0)).unwrap();
+        params.set_parameter_from("mode", ParamValue::String("manual".to_string()), ParamSource::Override).unwrap();
+
+        assert_eq!(params.load_from_env("AURA_TEST_ENV_RANK_").unwrap(), 1);
+        assert_eq!(params.get_parameter("max_speed").unwrap(), ParamValue::Float(2.5));
+        assert_eq!(params.get_parameter("mode").unwrap(), ParamValue::String("manual".to_string()));
+
+        std::env::remove_var("AURA_TEST_ENV_RANK_MAX_SPEED");
+        std::env::remove_var("AURA_TEST_ENV_RANK_MODE");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn every_value_variant_round_trips_through_json() {
+        let values = [
+            (ParamValue::String("front".to_string()), r#"{"String":"front"}"#),
+            (ParamValue::Int(-3), r#"{"Int":-3}"#),
+            (ParamValue::Float(1.0), r#"{"Float":1.0}"#),
+            (ParamValue::Bool(true), r#"{"Bool":true}"#),
+            (ParamValue::Null, r#""Null""#),
+        ];
+        for (value, json) in values {
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<ParamValue>(json).unwrap(), value);
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn environment_outranks_file() {
+        let path = std::env::temp_dir().join(format!("aura_env_over_file_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"camera": {"fps": 15, "name": "front"}}"#).unwrap();
+        std::env::set_var("AURA_TEST_ENV_OVER_FILE_CAMERA__FPS", "30");
+
+        // Environment first, then the file: the file must not overwrite the variable.
+        let params = ParameterManager::new("env_first");
+        assert_eq!(params.load_from_env("AURA_TEST_ENV_OVER_FILE_").unwrap(), 1);
+        params.load_from_file(&path).unwrap();
+        assert_eq!(params.get_parameter("camera.fps").unwrap(), ParamValue::Int(30));
+        assert_eq!(params.get_parameter("camera.name").unwrap(), ParamValue::String("front".to_string()));
+
+        // File first, then the environment.
+        let params = ParameterManager::new("file_first");
+        params.load_from_file(&path).unwrap();
+        params.load_from_env("AURA_TEST_ENV_OVER_FILE_").unwrap();
+        assert_eq!(params.get_parameter("camera.fps").unwrap(), ParamValue::Int(30));
+        assert_eq!(params.parameter_source("camera.fps"), Some(ParamSource::Environment));
+
+        std::env::remove_var("AURA_TEST_ENV_OVER_FILE_CAMERA__FPS");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(feature = "journal")]
+    #[test]
+    fn journal_recovers_values_before_and_after_compaction() {
+        let path = std::env::temp_dir().join(format!("aura_journal_recovery_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let params = ParameterManager::new("journaled");
+        params.declare_parameter("gain", ParamValue::Float(1.0)).unwrap();
+        params.enable_journal(&path).unwrap();
+        params.set_parameter("gain", ParamValue::Float(2.5)).unwrap();
+        params
+            .set_parameters_atomically(vec![("mode".to_string(), ParamValue::String("auto".to_string())), ("retries".to_string(), ParamValue::Int(3))])
+            .unwrap();
+        params.set_parameter("scratch", ParamValue::Bool(true)).unwrap();
+        params.undeclare_parameter("scratch").unwrap();
+        let expected = params.list_parameters("").unwrap();
+
+        let recovered = ParameterManager::new("journaled");
+        assert_eq!(recovered.recover_from_journal(&path).unwrap(), 5);
+        assert_eq!(recovered.list_parameters("").unwrap(), expected);
+
+        // Compaction leaves one entry per parameter.
+        params.compact_journal().unwrap();
+        let recovered = ParameterManager::new("journaled");
+        assert_eq!(recovered.recover_from_journal(&path).unwrap(), expected.len());
+        assert_eq!(recovered.list_parameters("").unwrap(), expected);
+
+        // A torn final line, as left by a crash mid-write, is skipped.
+        let mut journal = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        std::io::Write::write_all(&mut journal, b"{\"name\":\"gain\",\"val").unwrap();
+        drop(journal);
+        let recovered = ParameterManager::new("journaled");
+        assert_eq!(recovered.recover_from_journal(&path).unwrap(), expected.len());
+        assert_eq!(recovered.get_parameter("gain").unwrap(), ParamValue::Float(2.5));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn typed_getters_convert_or_report_mismatch() {
+        let params = ParameterManager::new("typed");
+        params.declare_parameter("rate", ParamValue::Int(5)).unwrap();
+        params.declare_parameter("frame", ParamValue::String("base_link".to_string())).unwrap();
+
+        assert_eq!(params.get_parameter_as::<i64>("rate").unwrap(), 5);
+        assert_eq!(params.get_parameter_as::<f64>("rate").unwrap(), 5.0);
+        assert_eq!(params.get_parameter_as::<String>("frame").unwrap(), "base_link");
+        assert!(matches!(
+            params.get_parameter_as::<i64>("frame"),
+            Err(AuraError::ParameterConfigurationError(_))
+        ));
+        assert!(matches!(params.get_parameter_as::<i64>("missing"), Err(AuraError::ParameterNotFound(_))));
+
+        assert_eq!(params.get_parameter_or("rate", 10i64), 5);
+        assert_eq!(params.get_parameter_or("missing", 10i64), 10);
+        assert_eq!(params.get_parameter_or("frame", 10i64), 10);
+    }
+
+    #[test]
+    fn value_getters_are_strict_except_int_to_float() {
+        assert_eq!(ParamValue::Int(7).get_i64().unwrap(), 7);
+        assert_eq!(ParamValue::Float(0.5).get_f64().unwrap(), 0.5);
+        assert_eq!(ParamValue::Int(5).get_f64().unwrap(), 5.0);
+        assert!(ParamValue::Bool(true).get_bool().unwrap());
+
+        for result in [
+            ParamValue::Float(7.0).get_i64().map(|_| ()),
+            ParamValue::String("7".to_string()).get_i64().map(|_| ()),
+            ParamValue::Bool(true).get_f64().map(|_| ()),
+            ParamValue::Int(1).get_bool().map(|_| ()),
+        ] {
+            assert!(matches!(result, Err(AuraError::ParameterConfigurationError(_))));
+        }
+        let error = ParamValue::Bool(false).get_i64().unwrap_err();
+        assert!(error.to_string().contains("Expected Int, found Bool(false)"), "{}", error);
+    }
+
+    #[test]
+    fn child_reads_through_to_parent_and_shadows_locally() {
+        let global = Arc::new(ParameterManager::new("global"));
+        global.set_parameter("robot_name", ParamValue::String("atlas".to_string())).unwrap();
+        global.set_parameter("max_speed", ParamValue::Float(1.5)).unwrap();
+        global.set_parameter("frame", ParamValue::String("map".to_string())).unwrap();
+        let child = ParameterManager::with_parent("driver", Arc::clone(&global));
+
+        assert_eq!(child.get_parameter("robot_name").unwrap(), ParamValue::String("atlas".to_string()));
+        assert!(child.has_parameter("robot_name").unwrap());
+
+        child.set_parameter("max_speed", ParamValue::Float(0.5)).unwrap();
+        assert_eq!(child.get_parameter("max_speed").unwrap(), ParamValue::Float(0.5));
+        assert_eq!(global.get_parameter("max_speed").unwrap(), ParamValue::Float(1.5));
+
+        // The parent's value wins over the default in code.
+        child.declare_parameter("frame", ParamValue::String("odom".to_string())).unwrap();
+        assert_eq!(child.get_parameter("frame").unwrap(), ParamValue::String("map".to_string()));
+
+        child.set_parameter("wheel_radius", ParamValue::Float(0.1)).unwrap();
+        assert!(!global.has_parameter("wheel_radius").unwrap());
+        let listed: Vec<String> = child.list_parameters("").unwrap().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(listed, ["frame", "max_speed", "robot_name", "wheel_radius"]);
+        assert_eq!(child.list_parameters("max").unwrap(), [("max_speed".to_string(), ParamValue::Float(0.5))]);
+    }
+
+    #[test]
+    fn undeclare_removes_local_value_and_respects_read_only() {
+        let global = Arc::new(ParameterManager::new("global"));
+        global.set_parameter("frame", ParamValue::String("map".to_string())).unwrap();
+        let params = ParameterManager::with_parent("mapper", Arc::clone(&global));
+        params.declare_parameter("resolution", ParamValue::Float(0.05)).unwrap();
+        params.set_parameter("frame", ParamValue::String("odom".to_string())).unwrap();
+        let serial = ParameterDescriptor::new("Sensor serial").read_only();
+        params.declare_parameter_with_descriptor("serial", ParamValue::String("A17".to_string()), serial).unwrap();
+
+        assert_eq!(params.undeclare_parameter("resolution").unwrap(), ParamValue::Float(0.05));
+        assert!(matches!(params.get_parameter("resolution"), Err(AuraError::ParameterNotFound(_))));
+        assert!(matches!(params.undeclare_parameter("resolution"), Err(AuraError::ParameterNotFound(_))));
+
+        assert!(matches!(params.undeclare_parameter("serial"), Err(AuraError::ParameterConfigurationError(_))));
+        assert_eq!(params.get_parameter("serial").unwrap(), ParamValue::String("A17".to_string()));
+
+        // The parent's value shows through once the local one is gone.
+        assert_eq!(params.undeclare_parameter("frame").unwrap(), ParamValue::String("odom".to_string()));
+        assert_eq!(params.get_parameter("frame").unwrap(), ParamValue::String("map".to_string()));
+    }
+}
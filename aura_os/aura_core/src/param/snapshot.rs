@@ -0,0 +1,90 @@
+// aura_os/aura_core/src/param/snapshot.rs
+
+use super::{ParamSource, ParamValue};
+use std::collections::HashMap;
+
+/// A copy of every parameter value of a `ParameterManager` at one point in time, with
+/// the source each value came from.
+///
+/// Taken with `ParameterManager::snapshot` and put back with `ParameterManager::restore`,
+/// e.g. to roll back a set of trial values while tuning. Descriptors are not part of the
+/// snapshot. With the `serde` feature a snapshot can be saved and loaded like any other
+/// serializable value; it is stored as a map from name to value and one from name to
+/// source. A value without a source is restored as `ParamSource::Runtime`.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterSnapshot {
+    pub(crate) values: HashMap<String, ParamValue>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) sources: HashMap<String, ParamSource>,
+}
+
+impl ParameterSnapshot {
+    /// Returns the value `name` had when the snapshot was taken.
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.values.get(name)
+    }
+
+    /// Returns where the value of `name` came from when the snapshot was taken.
+    pub fn source(&self, name: &str) -> Option<ParamSource> {
+        self.values.contains_key(name).then(|| self.sources.get(name).copied().unwrap_or(ParamSource::Runtime))
+    }
+
+    /// Returns the names of the captured parameters, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.values.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns the number of captured parameters.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no parameters were set when the snapshot was taken.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::param::{ParameterDescriptor, ParameterManager};
+    use crate::AuraError;
+
+    #[test]
+    fn snapshot_captures_values_and_restore_puts_them_back() {
+        let params = ParameterManager::new("arm");
+        params.declare_parameter("speed", ParamValue::Float(0.5)).unwrap();
+        params.set_parameter_from("tool", ParamValue::String("gripper".to_string()), ParamSource::File).unwrap();
+        let snapshot = params.snapshot().unwrap();
+        assert_eq!(snapshot.names(), ["speed", "tool"]);
+        assert_eq!(snapshot.get("speed"), Some(&ParamValue::Float(0.5)));
+        assert_eq!(snapshot.source("tool"), Some(ParamSource::File));
+        assert_eq!(snapshot.source("missing"), None);
+
+        params.set_parameter("speed", ParamValue::Float(2.0)).unwrap();
+        params.set_parameter("payload", ParamValue::Int(3)).unwrap();
+        params.restore(&snapshot).unwrap();
+        assert_eq!(params.snapshot().unwrap(), snapshot);
+    }
+
+    #[test]
+    fn read_only_parameter_blocks_the_whole_restore() {
+        let params = ParameterManager::new("arm");
+        params.declare_parameter("speed", ParamValue::Float(0.5)).unwrap();
+        let snapshot = params.snapshot().unwrap();
+
+        params.set_parameter("speed", ParamValue::Float(2.0)).unwrap();
+        let serial = ParameterDescriptor::new("Serial number").read_only();
+        params.declare_parameter_with_descriptor("serial", ParamValue::String("A17".to_string()), serial).unwrap();
+        let before = params.snapshot().unwrap();
+
+        // Restoring would undeclare the read-only parameter, so nothing is restored.
+        assert!(matches!(params.restore(&snapshot), Err(AuraError::ParameterConfigurationError(_))));
+        assert_eq!(params.snapshot().unwrap(), before);
+        assert_eq!(params.get_parameter("speed").unwrap(), ParamValue::Float(2.0));
+    }
+}
@@ -0,0 +1,24 @@
+// aura_os/aura_core/src/param/source.rs
+
+/// Where a parameter's current value came from, in increasing order of precedence.
+///
+/// Each `ParameterManager` remembers the source of every value it holds and refuses to
+/// let a lower-ranked source replace a higher-ranked one, so the outcome does not depend
+/// on the order in which files, environment variables and overrides are applied. A
+/// source may always replace its own values. Query it with
+/// `ParameterManager::parameter_source`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamSource {
+    /// The default passed to `declare_parameter` (or inherited from a parent manager).
+    Default,
+    /// A configuration file loaded with `load_from_file`.
+    File,
+    /// An environment variable applied by `load_from_env`.
+    Environment,
+    /// A command-line or `NodeBuilder::parameter_overrides` override.
+    Override,
+    /// An explicit `set_parameter` (or atomic set or journal recovery) while
+    /// running, e.g. `aura param set`. Always applies.
+    Runtime,
+}
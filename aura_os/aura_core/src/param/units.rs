@@ -0,0 +1,127 @@
+// aura_os/aura_core/src/param/units.rs
+
+use crate::error::{AuraError, Result};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// The physical dimension a unit measures.
+///
+/// Conversions are only permitted between units of the same dimension; asking to
+/// convert a length into an angle is rejected rather than silently producing nonsense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    Angle,
+    Length,
+    Velocity,
+    AngularVelocity,
+}
+
+/// A registry of units, each described by its dimension and its scale factor
+/// relative to the SI base unit of that dimension (rad, m, m/s, rad/s).
+///
+/// `UnitConverter::default()` comes pre-populated with the units robotics code
+/// commonly deals with. Additional units can be added with [`UnitConverter::register`].
+#[derive(Debug, Clone)]
+pub struct UnitConverter {
+    /// Maps a unit symbol (e.g., "deg", "km/h") to its dimension and SI factor.
+    units: HashMap<String, (Dimension, f64)>,
+}
+
+impl UnitConverter {
+    /// Creates an empty converter with no registered units.
+    pub fn new() -> Self {
+        Self { units: HashMap::new() }
+    }
+
+    /// Registers (or replaces) a unit.
+    ///
+    /// # Arguments
+    /// * `unit`: The unit symbol used when converting (e.g., "ft").
+    /// * `dimension`: The `Dimension` the unit measures.
+    /// * `to_si_factor`: Multiplier that converts a value in `unit` to the SI base unit
+    ///   (e.g., `0.3048` for feet to metres).
+    pub fn register(&mut self, unit: &str, dimension: Dimension, to_si_factor: f64) {
+        self.units.insert(unit.to_string(), (dimension, to_si_factor));
+    }
+
+    /// Returns the dimension of a registered unit, if known.
+    pub fn dimension_of(&self, unit: &str) -> Option<Dimension> {
+        self.units.get(unit).map(|(dimension, _)| *dimension)
+    }
+
+    /// Converts `value` from `from_unit` to `to_unit`.
+    ///
+    /// # Returns
+    /// The converted value, or `AuraError::ParameterConfigurationError` if either unit
+    /// is unknown or the two units measure different dimensions.
+    pub fn convert(&self, value: f64, from_unit: &str, to_unit: &str) -> Result<f64> {
+        let (from_dimension, from_factor) = self.lookup(from_unit)?;
+        let (to_dimension, to_factor) = self.lookup(to_unit)?;
+
+        if from_dimension != to_dimension {
+            return Err(AuraError::ParameterConfigurationError(format!(
+                "Cannot convert from '{}' ({:?}) to '{}' ({:?}): incompatible units.",
+                from_unit, from_dimension, to_unit, to_dimension
+            )));
+        }
+        Ok(value * from_factor / to_factor)
+    }
+
+    fn lookup(&self, unit: &str) -> Result<(Dimension, f64)> {
+        self.units.get(unit).copied().ok_or_else(|| {
+            AuraError::ParameterConfigurationError(format!("Unknown unit '{}'.", unit))
+        })
+    }
+}
+
+impl Default for UnitConverter {
+    /// Creates a converter with built-in angle, length, velocity and angular velocity units.
+    fn default() -> Self {
+        let mut converter = Self::new();
+
+        // Angle (SI: radians)
+        converter.register("rad", Dimension::Angle, 1.0);
+        converter.register("deg", Dimension::Angle, PI / 180.0);
+        converter.register("rev", Dimension::Angle, 2.0 * PI);
+
+        // Length (SI: metres)
+        converter.register("m", Dimension::Length, 1.0);
+        converter.register("mm", Dimension::Length, 0.001);
+        converter.register("cm", Dimension::Length, 0.01);
+        converter.register("km", Dimension::Length, 1000.0);
+        converter.register("in", Dimension::Length, 0.0254);
+        converter.register("ft", Dimension::Length, 0.3048);
+
+        // Velocity (SI: metres per second)
+        converter.register("m/s", Dimension::Velocity, 1.0);
+        converter.register("km/h", Dimension::Velocity, 1000.0 / 3600.0);
+        converter.register("mph", Dimension::Velocity, 0.44704);
+
+        // Angular velocity (SI: radians per second)
+        converter.register("rad/s", Dimension::AngularVelocity, 1.0);
+        converter.register("deg/s", Dimension::AngularVelocity, PI / 180.0);
+        converter.register("rpm", Dimension::AngularVelocity, 2.0 * PI / 60.0);
+
+        converter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_degrees_to_radians() {
+        let converter = UnitConverter::default();
+        assert!((converter.convert(180.0, "deg", "rad").unwrap() - PI).abs() < 1e-12);
+        assert!((converter.convert(PI / 2.0, "rad", "deg").unwrap() - 90.0).abs() < 1e-12);
+        assert!((converter.convert(36.0, "km/h", "m/s").unwrap() - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_conversion_between_dimensions() {
+        let converter = UnitConverter::default();
+        assert!(matches!(converter.convert(1.0, "m", "rad"), Err(AuraError::ParameterConfigurationError(_))));
+        assert!(matches!(converter.convert(1.0, "m", "furlong"), Err(AuraError::ParameterConfigurationError(_))));
+    }
+}
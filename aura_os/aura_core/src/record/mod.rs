@@ -0,0 +1,28 @@
+// aura_os/aura_core/src/record/mod.rs
+
+//! # AuraOS Recording (`record`) Module
+//!
+//! Captures bus traffic to disk for debugging and later replay, in the spirit of
+//! ROS bags. A bag is a newline-delimited JSON file where each line is one
+//! [`RecordedMessage`].
+//!
+//! This module is only available with the `record` feature enabled.
+
+pub mod player;
+pub mod recorder;
+
+pub use player::{PlaybackHandle, Player};
+pub use recorder::Recorder;
+
+use serde::{Deserialize, Serialize};
+
+/// One line of a bag file: a message and the wall-clock time it was recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedMessage {
+    /// Recording time in nanoseconds since the UNIX epoch. Non-decreasing within a bag.
+    pub timestamp_ns: u64,
+    /// The topic the message was published on.
+    pub topic: String,
+    /// The message payload.
+    pub data: String,
+}
@@ -0,0 +1,239 @@
+// aura_os/aura_core/src/record/player.rs
+
+use super::RecordedMessage;
+use crate::comm::Publisher;
+use crate::aura_log;
+use crate::error::{AuraError, Result};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Longest single sleep while waiting for the next message, so a stop request is
+/// noticed promptly even when messages are far apart.
+const MAX_SLEEP_SLICE: Duration = Duration::from_millis(50);
+
+/// Replays a bag written by [`Recorder`](super::Recorder) onto the message bus.
+///
+/// Each message is republished on its original topic, spaced according to the
+/// recorded timestamps divided by the playback rate. Messages on topics nobody
+/// subscribes to are published harmlessly.
+///
+/// Example:
+/// ```ignore
+/// let player = Player::new("session.bag")?.with_rate(2.0);
+/// player.play()?; // Blocks until the bag is exhausted, at twice the recorded speed.
+/// ```
+#[derive(Debug, Clone)]
+pub struct Player {
+    path: PathBuf,
+    messages: Vec<RecordedMessage>,
+    rate: f64,
+    loop_playback: bool,
+}
+
+impl Player {
+    /// Loads the bag at `path` for playback at the recorded speed, without looping.
+    ///
+    /// # Returns
+    /// The `Player`, `AuraError::IoError` if the file cannot be read, or
+    /// `AuraError::SerializationError` if a line is not a valid recorded message.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let reader = BufReader::new(File::open(&path)?);
+
+        let mut messages = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: RecordedMessage = serde_json::from_str(&line).map_err(|e| {
+                AuraError::SerializationError(format!(
+                    "Invalid record on line {} of '{}': {}",
+                    index + 1,
+                    path.display(),
+                    e
+                ))
+            })?;
+            messages.push(record);
+        }
+        aura_log!(info, "Loaded {} message(s) from bag '{}'", messages.len(), path.display());
+
+        Ok(Self {
+            path,
+            messages,
+            rate: 1.0,
+            loop_playback: false,
+        })
+    }
+
+    /// Sets the playback speed multiplier (2.0 plays twice as fast).
+    ///
+    /// A rate of zero or less disables the delays entirely and replays as fast as possible.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Enables continuous replay: once the bag is exhausted it starts over.
+    ///
+    /// A looping `play()` never returns on its own, so use `play_async()` and stop the
+    /// returned handle instead.
+    pub fn with_loop_playback(mut self, loop_playback: bool) -> Self {
+        self.loop_playback = loop_playback;
+        self
+    }
+
+    /// Returns the recorded messages in playback order.
+    pub fn messages(&self) -> &[RecordedMessage] {
+        &self.messages
+    }
+
+    /// Replays the bag, blocking until it is exhausted.
+    pub fn play(&self) -> Result<()> {
+        self.play_until(&AtomicBool::new(false))
+    }
+
+    /// Replays the bag on a background thread.
+    ///
+    /// # Returns
+    /// A `PlaybackHandle` to stop the playback early or wait for it to finish.
+    pub fn play_async(self) -> Result<PlaybackHandle> {
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop_requested);
+        let thread = thread::Builder::new()
+            .name("aura-player".to_string())
+            .spawn(move || self.play_until(&thread_stop))?;
+
+        Ok(PlaybackHandle {
+            stop_requested,
+            thread: Some(thread),
+        })
+    }
+
+    fn play_until(&self, stop_requested: &AtomicBool) -> Result<()> {
+        let mut publishers: HashMap<String, Publisher> = HashMap::new();
+
+        loop {
+            let Some(first) = self.messages.first() else {
+                return Ok(()); // Empty bag.
+            };
+            let start = Instant::now();
+
+            for record in &self.messages {
+                if stop_requested.load(Ordering::SeqCst) {
+                    return Ok(());
+                }
+                if self.rate > 0.0 {
+                    let offset_ns = record.timestamp_ns.saturating_sub(first.timestamp_ns);
+                    let due = start + Duration::from_nanos(offset_ns).div_f64(self.rate);
+                    if !sleep_until(due, stop_requested) {
+                        return Ok(());
+                    }
+                }
+
+                if !publishers.contains_key(&record.topic) {
+                    publishers.insert(record.topic.clone(), Publisher::new(&record.topic)?);
+                }
+                publishers[&record.topic].publish(record.data.clone())?;
+            }
+
+            if !self.loop_playback {
+                aura_log!(info, "Finished playing bag '{}'", self.path.display());
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Sleeps until `due`, waking periodically to check for a stop request.
+/// Returns `false` if playback should stop.
+fn sleep_until(due: Instant, stop_requested: &AtomicBool) -> bool {
+    loop {
+        if stop_requested.load(Ordering::SeqCst) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= due {
+            return true;
+        }
+        thread::sleep((due - now).min(MAX_SLEEP_SLICE));
+    }
+}
+
+/// Handle to a playback started with [`Player::play_async`].
+#[derive(Debug)]
+pub struct PlaybackHandle {
+    stop_requested: Arc<AtomicBool>,
+    thread: Option<JoinHandle<Result<()>>>,
+}
+
+impl PlaybackHandle {
+    /// Asks the playback to stop after the message currently being published.
+    pub fn stop(&self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` once the playback thread has finished.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Waits for the playback to finish and returns its result.
+    pub fn join(mut self) -> Result<()> {
+        match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .map_err(|_| AuraError::Other("Bag playback thread panicked.".to_string()))?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for PlaybackHandle {
+    /// Dropping the handle stops the playback and waits for the thread to exit.
+    fn drop(&mut self) {
+        self.stop();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::Subscriber;
+    use crate::record::Recorder;
+
+    #[test]
+    fn replays_recorded_sequence_in_order() {
+        let _bus = crate::global_bus_test_lock();
+        let path = std::env::temp_dir().join(format!("aura_player_test_{}.bag", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let topic = "/test/player/cmd";
+        let sent: Vec<String> = (0..5).map(|step| format!("step {}", step)).collect();
+        {
+            let mut recorder = Recorder::new(&path, vec![topic.to_string()]).unwrap();
+            let publisher = Publisher::new(topic).unwrap();
+            for data in &sent {
+                publisher.publish(data.clone()).unwrap();
+            }
+            recorder.stop().unwrap();
+        }
+
+        let listener = Subscriber::new(topic).unwrap();
+        let player = Player::new(&path).unwrap().with_rate(0.0);
+        assert_eq!(player.messages().len(), sent.len());
+        player.play_async().unwrap().join().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let replayed: Vec<String> = listener.drain().into_iter().map(|message| message.data).collect();
+        assert_eq!(replayed, sent);
+    }
+}
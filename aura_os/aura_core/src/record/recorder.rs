@@ -0,0 +1,208 @@
+// aura_os/aura_core/src/record/recorder.rs
+
+use super::RecordedMessage;
+use crate::comm::Subscriber;
+use crate::error::{AuraError, Result};
+use crate::{aura_log, AuraMessage};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// How long a topic thread blocks on its subscriber before re-checking the stop flag.
+const RECV_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Records every message on a set of topics to a newline-delimited JSON bag file.
+///
+/// Each listed topic gets its own `Subscriber` and receiving thread, so recording
+/// never blocks publishers. Received messages are stamped and handed to a single
+/// writer thread, which appends them to the file in arrival order. Compressed payloads
+/// (see [`comm::compression`](crate::comm::compression)) are recorded compressed.
+///
+/// Recording starts as soon as `Recorder::new` returns and continues until
+/// [`Recorder::stop`] is called or the recorder is dropped.
+#[derive(Debug)]
+pub struct Recorder {
+    path: PathBuf,
+    running: Arc<AtomicBool>,
+    topic_threads: Vec<JoinHandle<()>>,
+    writer_thread: Option<JoinHandle<Result<()>>>,
+}
+
+impl Recorder {
+    /// Creates a recorder appending to `path` and starts recording `topics`.
+    ///
+    /// # Arguments
+    /// * `path`: The bag file to append to (created if missing).
+    /// * `topics`: Fully qualified names of the topics to record.
+    ///
+    /// # Returns
+    /// The running `Recorder`, `AuraError::IoError` if the file cannot be opened, or
+    /// any error from creating the subscribers.
+    pub fn new<P: AsRef<Path>>(path: P, topics: Vec<String>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        aura_log!(info, "Recording topics {:?} to '{}'", topics, path.display());
+
+        // Subscribe to everything up front so messages published right after `new`
+        // returns are captured.
+        let subscribers = topics
+            .iter()
+            .map(|topic| Subscriber::new(topic))
+            .collect::<Result<Vec<_>>>()?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let (record_tx, record_rx) = mpsc::channel::<(SystemTime, AuraMessage)>();
+
+        let writer_thread = thread::Builder::new()
+            .name("aura-recorder-writer".to_string())
+            .spawn(move || write_records(file, record_rx))?;
+
+        let mut topic_threads = Vec::with_capacity(subscribers.len());
+        for subscriber in subscribers {
+            let running = Arc::clone(&running);
+            let record_tx = record_tx.clone();
+            let handle = thread::Builder::new()
+                .name(format!("aura-recorder{}", subscriber.topic_name()))
+                .spawn(move || forward_messages(subscriber, running, record_tx))?;
+            topic_threads.push(handle);
+        }
+
+        Ok(Self {
+            path,
+            running,
+            topic_threads,
+            writer_thread: Some(writer_thread),
+        })
+    }
+
+    /// Returns the path of the bag file being written.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stops recording, writes out any messages already received, and closes the file.
+    ///
+    /// Calling `stop` more than once is harmless.
+    ///
+    /// # Returns
+    /// `Ok(())` once everything is flushed, or the first I/O or serialization error the
+    /// writer encountered.
+    pub fn stop(&mut self) -> Result<()> {
+        self.running.store(false, Ordering::SeqCst);
+        for handle in self.topic_threads.drain(..) {
+            if handle.join().is_err() {
+                aura_log!(warn, "A recorder topic thread for '{}' panicked.", self.path.display());
+            }
+        }
+
+        // All senders are gone once the topic threads have exited, so the writer
+        // drains the remaining records and returns.
+        match self.writer_thread.take() {
+            Some(handle) => handle.join().map_err(|_| {
+                AuraError::Other(format!("Recorder writer thread for '{}' panicked.", self.path.display()))
+            })?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            aura_log!(error, "Failed to stop recorder for '{}': {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Receives messages for one topic and hands them, stamped, to the writer.
+fn forward_messages(
+    subscriber: Subscriber,
+    running: Arc<AtomicBool>,
+    record_tx: mpsc::Sender<(SystemTime, AuraMessage)>,
+) {
+    while running.load(Ordering::SeqCst) {
+        match subscriber.recv_timeout_raw(RECV_POLL_INTERVAL) {
+            Ok(message) => {
+                if record_tx.send((SystemTime::now(), Arc::unwrap_or_clone(message))).is_err() {
+                    return; // Writer is gone.
+                }
+            }
+            Err(AuraError::TimeoutError(_)) => continue,
+            Err(_) => return, // Disconnected.
+        }
+    }
+    // Don't lose messages that were already queued when recording stopped.
+    for message in subscriber.drain_raw() {
+        if record_tx.send((SystemTime::now(), Arc::unwrap_or_clone(message))).is_err() {
+            return;
+        }
+    }
+}
+
+/// Appends records to the bag file until every sender has been dropped.
+fn write_records(file: File, record_rx: mpsc::Receiver<(SystemTime, AuraMessage)>) -> Result<()> {
+    let mut writer = BufWriter::new(file);
+    let mut last_timestamp_ns = 0u64;
+
+    for (received_at, message) in record_rx {
+        let timestamp_ns = received_at
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        // Records from different topic threads can arrive with slightly out-of-order
+        // stamps; clamp so timestamps never go backwards within the bag.
+        last_timestamp_ns = last_timestamp_ns.max(timestamp_ns);
+
+        let record = RecordedMessage {
+            timestamp_ns: last_timestamp_ns,
+            topic: message.topic,
+            data: message.data,
+        };
+        let line = serde_json::to_string(&record)
+            .map_err(|e| AuraError::SerializationError(format!("Failed to encode recorded message: {}", e)))?;
+        writeln!(writer, "{}", line)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::Publisher;
+
+    #[test]
+    fn records_published_messages_to_file() {
+        let _bus = crate::global_bus_test_lock();
+        let path = std::env::temp_dir().join(format!("aura_recorder_test_{}.bag", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let topics = vec!["/test/recorder/odom".to_string(), "/test/recorder/scan".to_string()];
+        let mut recorder = Recorder::new(&path, topics).unwrap();
+        let odom = Publisher::new("/test/recorder/odom").unwrap();
+        let scan = Publisher::new("/test/recorder/scan").unwrap();
+        for value in ["1", "2", "3"] {
+            odom.publish(value.to_string()).unwrap();
+        }
+        scan.publish("far".to_string()).unwrap();
+        recorder.stop().unwrap();
+
+        let records: Vec<RecordedMessage> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(records.len(), 4);
+        assert!(records.windows(2).all(|pair| pair[0].timestamp_ns <= pair[1].timestamp_ns));
+        let on = |topic: &str| -> Vec<&str> {
+            records.iter().filter(|record| record.topic == topic).map(|record| record.data.as_str()).collect()
+        };
+        assert_eq!(on("/test/recorder/odom"), ["1", "2", "3"]);
+        assert_eq!(on("/test/recorder/scan"), ["far"]);
+    }
+}
@@ -4,6 +4,7 @@
 // Import necessary items from our aura_core crate
 use aura_core::node::Node;
 use aura_core::comm::Subscriber; // We'll use the simplified Subscriber
+use aura_core::AuraError;
 use aura_core::Result as AuraResult; // Use the AuraError Result type alias
 
 // Standard library imports
@@ -29,7 +30,8 @@ fn main() -> AuraResult<()> {
 
     // 3. Create a Subscriber.
     // Nodes use subscribers to receive messages from topics.
-    // We subscribe to the same topic the talker is publishing to.
+    // We subscribe to the same topic the talker is publishing to. Run both with the
+    // same `AURA_BUS_ADDR` (e.g. `127.0.0.1:7447`) so `init()` connects their buses.
     // The topic name "/examples/chatter" is an absolute topic name.
     // We could also use `listener_node.create_subscriber("chatter")?`
     let chatter_subscriber: Subscriber = listener_node.create_subscriber("chatter")?;
@@ -44,47 +46,45 @@ fn main() -> AuraResult<()> {
     // In a real AuraOS application with an executor, this would typically be a callback
     // function registered with the subscriber. For this sketch, we poll manually.
 
-    let running = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
-    let r = running.clone();
-    ctrlc::set_handler(move || {
-        println!("\n[AuraListenerExample] CTRL-C received, signaling shutdown...");
-        r.store(false, std::sync::atomic::Ordering::SeqCst);
-    }).expect("Error setting Ctrl-C handler");
+    // Route CTRL-C / SIGTERM to AuraOS's global shutdown signal.
+    aura_core::install_signal_handler()?;
 
     let mut messages_received_count = 0;
 
-    while running.load(std::sync::atomic::Ordering::SeqCst) {
+    while !aura_core::is_shutting_down() {
         // Conceptually, allow the node to perform any internal processing.
         listener_node.spin_once();
 
         // Try to receive a message with a timeout.
         // This prevents the loop from blocking indefinitely if no messages are coming
-        // and allows the `running` flag to be checked periodically.
+        // and allows the shutdown signal to be checked periodically.
         match chatter_subscriber.recv_timeout(Duration::from_millis(100)) {
             Ok(aura_message) => {
                 // A message was successfully received.
-                aura_core::aura_log!(info,
-                    "[{}] Received on topic '{}': \"{}\"",
-                    listener_node.fully_qualified_name(),
+                aura_core::node_log!(listener_node.logger(), info,
+                    "Received on topic '{}': \"{}\"",
                     aura_message.topic, // AuraMessage struct contains the topic
                     aura_message.data   // And the actual data
                 );
                 messages_received_count += 1;
             }
-            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+            Err(AuraError::TimeoutError(_)) => {
                 // No message received within the timeout. This is normal.
-                // Continue the loop to check the `running` flag and try again.
+                // Continue the loop to check the shutdown signal and try again.
             }
-            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            Err(AuraError::ChannelDisconnected(_)) => {
                 // The channel is disconnected. This likely means all publishers
                 // (our talker, in this case) have been dropped.
-                aura_core::aura_log!(warn,
-                    "[{}] Subscription channel for topic '{}' disconnected. Assuming no more messages.",
-                    listener_node.fully_qualified_name(),
+                aura_core::node_log!(listener_node.logger(), warn,
+                    "Subscription channel for topic '{}' disconnected. Assuming no more messages.",
                     chatter_subscriber.topic_name()
                 );
                 break; // Exit the loop.
             }
+            Err(e) => {
+                // Any other error is unexpected; log it and keep listening.
+                aura_core::node_log!(listener_node.logger(), error, "Failed to receive: {}", e);
+            }
         }
 
         // For this example, let's add a condition to stop if we've received a certain number
@@ -92,12 +92,15 @@ fn main() -> AuraResult<()> {
         // This is just to make the example terminate naturally if not interrupted by CTRL-C.
         // if messages_received_count >= 10 && std::env::var("CI").is_err() {
         //     aura_core::aura_log!(info, "Listener example reached message limit. Signaling shutdown.");
-        //     running.store(false, std::sync::atomic::Ordering::SeqCst);
+        //     aura_core::request_shutdown();
         // }
     }
 
     // 5. Shutdown AuraOS gracefully.
-    println!("[AuraListenerExample] Loop finished. Shutting down AuraOS...");
+    println!(
+        "[AuraListenerExample] Loop finished after receiving {} message(s). Shutting down AuraOS...",
+        messages_received_count
+    );
     aura_core::shutdown();
 
     println!("[AuraListenerExample] Exited cleanly.");